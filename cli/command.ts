@@ -17,6 +17,7 @@ import {
   getOfferDataInfo,
   makeOffer,
   cancelOffer,
+  refundOffer,
   acceptOffer,
   getAllOffersForNFT,
   getAllAuctions,
@@ -26,6 +27,7 @@ import {
   placeBid,
   createAuction,
   updateFee,
+  updatePricingMode,
   addTreasury,
   removeTreasury,
   initUserPool,
@@ -39,6 +41,14 @@ import {
   cancelAuctionPnft,
   claimAuctionPnft,
   acceptOfferPNft,
+  migrateGlobalPool,
+  migrateUserData,
+  reconcileEscrowBalance,
+  migrateSellData,
+  migrateOfferData,
+  migrateAuctionData,
+  auditEscrow,
+  pNftDelistExpired,
 } from "./scripts";
 
 dotenv.config({ path: __dirname + "/../.env" });
@@ -88,6 +98,26 @@ programCommand("update_fee")
     await updateFee(parseInt(sol_fee));
   });
 
+programCommand("update_pricing_mode")
+  .option(
+    "-m, --mode <number>",
+    "marketplace pricing mode, 1: fee on top, 2: fee inclusive"
+  )
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, mode } = cmd.opts();
+
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+
+    if (mode === undefined || isNaN(parseInt(mode))) {
+      console.log("Error Pricing Mode Input");
+      return;
+    }
+
+    await updatePricingMode(parseInt(mode));
+  });
+
 programCommand("add_treasury")
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .option("-a, --address <string>", "team treasury account pubkey")
@@ -262,12 +292,30 @@ programCommand("pdelist")
     await pNftDelist(new PublicKey(address));
   });
 
+programCommand("pdelist_expired")
+  .option("-a, --address <string>", "nft mint pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address } = cmd.opts();
+
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+
+    if (address === undefined) {
+      console.log("Error Mint input");
+      return;
+    }
+
+    await pNftDelistExpired(new PublicKey(address));
+  });
+
 programCommand("set_price")
   .option("-a, --address <string>", "nft mint pubkey")
   .option("-p, --price_sol <number>", "new sell price")
+  .option("-x, --expires_at <number>", "listing expiry as unix timestamp")
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
-    const { env, address, price_sol } = cmd.opts();
+    const { env, address, price_sol, expires_at } = cmd.opts();
 
     console.log("Solana config: ", env);
     await setClusterConfig(env);
@@ -281,9 +329,15 @@ programCommand("set_price")
       return;
     }
 
+    if (expires_at !== undefined && isNaN(parseInt(expires_at))) {
+      console.log("Error Expiry input");
+      return;
+    }
+
     await setPrice(
       new PublicKey(address),
-      parseFloat(price_sol) * LAMPORTS_PER_SOL
+      parseFloat(price_sol) * LAMPORTS_PER_SOL,
+      expires_at === undefined ? null : parseInt(expires_at)
     );
   });
 
@@ -324,9 +378,10 @@ programCommand("purchase_pnft")
 programCommand("make_offer")
   .option("-a, --address <string>", "nft mint pubkey")
   .option("-p, --price <number>", "offer price")
+  .option("-x, --expires_at <number>", "offer expiry as unix timestamp")
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
-    const { env, address, price } = cmd.opts();
+    const { env, address, price, expires_at } = cmd.opts();
 
     console.log("Solana config: ", env);
     await setClusterConfig(env);
@@ -340,9 +395,15 @@ programCommand("make_offer")
       return;
     }
 
+    if (expires_at !== undefined && isNaN(parseInt(expires_at))) {
+      console.log("Error Expiry input");
+      return;
+    }
+
     await makeOffer(
       new PublicKey(address),
-      parseFloat(price) * LAMPORTS_PER_SOL
+      parseFloat(price) * LAMPORTS_PER_SOL,
+      expires_at === undefined ? null : parseInt(expires_at)
     );
   });
 
@@ -363,6 +424,28 @@ programCommand("cancel_offer")
     await cancelOffer(new PublicKey(address));
   });
 
+programCommand("refund_offer")
+  .option("-a, --address <string>", "nft mint pubkey")
+  .option("-b, --buyer <string>", "buyer address pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address, buyer } = cmd.opts();
+
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+
+    if (address === undefined) {
+      console.log("Error Mint input");
+      return;
+    }
+    if (buyer === undefined) {
+      console.log("Error Buyer input");
+      return;
+    }
+
+    await refundOffer(new PublicKey(address), new PublicKey(buyer));
+  });
+
 programCommand("accept_offer")
   .option("-a, --address <string>", "nft mint pubkey")
   .option("-b, --buyer <string>", "buyer address")
@@ -461,10 +544,20 @@ programCommand("pcreate_auction")
   .option("-m, --min_increase <number>", "min increase amount")
   .option("-d, --duration <number>", "duration by second")
   .option("-r, --reserve <number>", "reserved auction flag")
+  .option("-w, --extension_window <number>", "bid extension window by second")
+  .option("-l, --extension_length <number>", "bid extension length by second")
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
-    const { env, address, start_price, min_increase, duration, reserve } =
-      cmd.opts();
+    const {
+      env,
+      address,
+      start_price,
+      min_increase,
+      duration,
+      reserve,
+      extension_window,
+      extension_length,
+    } = cmd.opts();
 
     console.log("Solana config: ", env);
     await setClusterConfig(env);
@@ -493,13 +586,22 @@ programCommand("pcreate_auction")
       console.log("Error Reserve Flag input");
       return;
     }
+    if (
+      (extension_window !== undefined && isNaN(parseInt(extension_window))) ||
+      (extension_length !== undefined && isNaN(parseInt(extension_length)))
+    ) {
+      console.log("Error Bid Extension input");
+      return;
+    }
 
     await createAuctionPNft(
       new PublicKey(address),
       parseFloat(start_price) * LAMPORTS_PER_SOL,
       parseFloat(min_increase) * LAMPORTS_PER_SOL,
       parseInt(duration),
-      parseInt(reserve) == 1
+      parseInt(reserve) == 1,
+      extension_window === undefined ? 0 : parseInt(extension_window),
+      extension_length === undefined ? 0 : parseInt(extension_length)
     );
   });
 
@@ -724,6 +826,109 @@ programCommand("init")
     await initProject();
   });
 
+programCommand("migrate_global")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+
+    await migrateGlobalPool();
+  });
+
+programCommand("migrate_user")
+  .option("-a, --address <string>", "user address pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+    if (address === undefined) {
+      console.log("Error User Address input");
+      return;
+    }
+    await migrateUserData(new PublicKey(address));
+  });
+
+programCommand("reconcile_escrow")
+  .option("-a, --address <string>", "user address pubkey")
+  .option("-s, --sol <number>", "deducted sol amount")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address, sol } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+    if (address === undefined) {
+      console.log("Error User Address input");
+      return;
+    }
+    if (sol === undefined || isNaN(parseFloat(sol))) {
+      console.log("Error Sol Amount input");
+      return;
+    }
+    await reconcileEscrowBalance(
+      new PublicKey(address),
+      parseFloat(sol) * LAMPORTS_PER_SOL
+    );
+  });
+
+programCommand("migrate_sell_data")
+  .option("-a, --address <string>", "nft mint pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+    if (address === undefined) {
+      console.log("Error Mint input");
+      return;
+    }
+    await migrateSellData(new PublicKey(address));
+  });
+
+programCommand("migrate_offer_data")
+  .option("-a, --address <string>", "nft mint pubkey")
+  .option("-b, --buyer <string>", "buyer address pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address, buyer } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+    if (address === undefined) {
+      console.log("Error Mint input");
+      return;
+    }
+    if (buyer === undefined) {
+      console.log("Error Buyer input");
+      return;
+    }
+    await migrateOfferData(new PublicKey(address), new PublicKey(buyer));
+  });
+
+programCommand("migrate_auction_data")
+  .option("-a, --address <string>", "nft mint pubkey")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env, address } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+    if (address === undefined) {
+      console.log("Error Mint input");
+      return;
+    }
+    await migrateAuctionData(new PublicKey(address));
+  });
+
+programCommand("audit_escrow")
+  // eslint-disable-next-line @typescript-eslint/no-unused-vars
+  .action(async (directory, cmd) => {
+    const { env } = cmd.opts();
+    console.log("Solana config: ", env);
+    await setClusterConfig(env);
+
+    await auditEscrow();
+  });
+
 programCommand("init_user")
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
   .action(async (directory, cmd) => {
@@ -13,6 +13,7 @@ import {
   MARKETPLACE_PROGRAM_ID,
   OfferData,
   OFFER_DATA_SEED,
+  PRICING_MODE_DEFAULT,
   SellData,
   SELL_DATA_SEED,
   UserData,
@@ -24,6 +25,7 @@ import {
   createAddTreasuryTx,
   createCancelAuctionTx,
   createCancelOfferTx,
+  createRefundOfferTx,
   createClaimAuctionTx,
   createCreateAuctionTx,
   createDelistNftTx,
@@ -45,6 +47,7 @@ import {
   createTransferFromVaultTx,
   createTransferTx,
   createUpdateFeeTx,
+  createUpdatePricingModeTx,
   createUpdateReserveTx,
   createWithdrawTx,
   getAllListedNFTs,
@@ -58,6 +61,14 @@ import {
   createCreateAuctionPnftTx,
   createCancelAuctionPnftTx,
   createClaimAuctionPnftTx,
+  createMigrateGlobalPoolTx,
+  createMigrateUserDataTx,
+  createReconcileEscrowBalanceTx,
+  createMigrateSellDataTx,
+  createMigrateOfferDataTx,
+  createMigrateAuctionDataTx,
+  createAuditEscrowTx,
+  createDelistExpiredPNftTx,
 } from "../lib/scripts";
 import { isInitializedUser } from "../lib/utils";
 
@@ -198,6 +209,24 @@ export const updateFee = async (solFee: number) => {
   console.log("Your transaction signature", txId);
 };
 
+export const updatePricingMode = async (pricingMode: number) => {
+  console.log(pricingMode);
+  const tx = await createUpdatePricingModeTx(
+    payer.publicKey,
+    program,
+    pricingMode
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
 export const addTreasury = async (treasury: PublicKey, rate: number) => {
   console.log(treasury.toBase58(), rate);
 
@@ -233,6 +262,131 @@ export const removeTreasury = async (treasury: PublicKey) => {
   console.log("Your transaction signature", txId);
 };
 
+export const migrateGlobalPool = async () => {
+  const tx = await createMigrateGlobalPoolTx(payer.publicKey, program);
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const migrateUserData = async (userAddress: PublicKey) => {
+  console.log(userAddress.toBase58());
+
+  const tx = await createMigrateUserDataTx(
+    payer.publicKey,
+    userAddress,
+    program
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const reconcileEscrowBalance = async (
+  userAddress: PublicKey,
+  deduction: number
+) => {
+  console.log(userAddress.toBase58(), deduction);
+
+  const tx = await createReconcileEscrowBalanceTx(
+    payer.publicKey,
+    userAddress,
+    deduction,
+    program
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const migrateSellData = async (mint: PublicKey) => {
+  console.log(mint.toBase58());
+
+  const tx = await createMigrateSellDataTx(payer.publicKey, mint, program);
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const migrateOfferData = async (mint: PublicKey, buyer: PublicKey) => {
+  console.log(mint.toBase58(), buyer.toBase58());
+
+  const tx = await createMigrateOfferDataTx(
+    payer.publicKey,
+    mint,
+    buyer,
+    program
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const migrateAuctionData = async (mint: PublicKey) => {
+  console.log(mint.toBase58());
+
+  const tx = await createMigrateAuctionDataTx(
+    payer.publicKey,
+    mint,
+    program,
+    solConnection
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const auditEscrow = async () => {
+  const tx = await createAuditEscrowTx(program);
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  const simulatieTx = await solConnection.simulateTransaction(tx);
+  console.log("tx =====>", simulatieTx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
 export const depositEscrow = async (sol: number) => {
   let userAddress = payer.publicKey;
   console.log(userAddress.toBase58(), sol);
@@ -477,8 +631,34 @@ export const pNftDelist = async (mint: PublicKey) => {
   console.log("Your transaction signature", txId);
 };
 
-export const setPrice = async (mint: PublicKey, newPrice: number) => {
-  console.log(mint.toBase58(), newPrice);
+export const pNftDelistExpired = async (mint: PublicKey) => {
+  console.log(mint.toBase58());
+
+  const tx = await createDelistExpiredPNftTx(
+    mint,
+    payer.publicKey,
+    program,
+    solConnection
+  );
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  const simulatieTx = await solConnection.simulateTransaction(tx);
+  console.log("tx =====>", simulatieTx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
+export const setPrice = async (
+  mint: PublicKey,
+  newPrice: number,
+  expiresAt: number | null = null
+) => {
+  console.log(mint.toBase58(), newPrice, expiresAt);
 
   if (!(await isInitializedUser(payer.publicKey, solConnection))) {
     console.log(
@@ -487,7 +667,13 @@ export const setPrice = async (mint: PublicKey, newPrice: number) => {
     await initUserPool();
   }
 
-  const tx = await createSetPriceTx(mint, payer.publicKey, newPrice, program);
+  const tx = await createSetPriceTx(
+    mint,
+    payer.publicKey,
+    newPrice,
+    program,
+    expiresAt
+  );
   const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
   tx.feePayer = payer.publicKey;
   tx.recentBlockhash = blockhash;
@@ -574,8 +760,12 @@ export const initOfferData = async (mint: PublicKey) => {
   console.log("Your transaction signature", txId);
 };
 
-export const makeOffer = async (mint: PublicKey, price: number) => {
-  console.log(mint.toBase58(), price);
+export const makeOffer = async (
+  mint: PublicKey,
+  price: number,
+  expiresAt: number | null = null
+) => {
+  console.log(mint.toBase58(), price, expiresAt);
 
   if (!(await isInitializedUser(payer.publicKey, solConnection))) {
     console.log(
@@ -595,7 +785,13 @@ export const makeOffer = async (mint: PublicKey, price: number) => {
     await initOfferData(mint);
   }
 
-  const tx = await createMakeOfferTx(mint, payer.publicKey, price, program);
+  const tx = await createMakeOfferTx(
+    mint,
+    payer.publicKey,
+    price,
+    program,
+    expiresAt
+  );
   const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
   tx.feePayer = payer.publicKey;
   tx.recentBlockhash = blockhash;
@@ -631,6 +827,21 @@ export const cancelOffer = async (mint: PublicKey) => {
   console.log("Your transaction signature", txId);
 };
 
+export const refundOffer = async (mint: PublicKey, buyer: PublicKey) => {
+  console.log(mint.toBase58(), buyer.toBase58());
+
+  const tx = await createRefundOfferTx(mint, payer.publicKey, buyer, program);
+  const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
+  tx.feePayer = payer.publicKey;
+  tx.recentBlockhash = blockhash;
+  payer.signTransaction(tx);
+  let txId = await solConnection.sendTransaction(tx, [
+    (payer as NodeWallet).payer,
+  ]);
+  await solConnection.confirmTransaction(txId, "confirmed");
+  console.log("Your transaction signature", txId);
+};
+
 export const acceptOffer = async (mint: PublicKey, buyer: PublicKey) => {
   console.log(mint.toBase58(), buyer.toBase58());
 
@@ -758,9 +969,19 @@ export const createAuctionPNft = async (
   startPrice: number,
   minIncrease: number,
   duration: number,
-  reserved: boolean
+  reserved: boolean,
+  extensionWindow: number = 0,
+  extensionLength: number = 0
 ) => {
-  console.log(mint.toBase58(), startPrice, minIncrease, duration, reserved);
+  console.log(
+    mint.toBase58(),
+    startPrice,
+    minIncrease,
+    duration,
+    reserved,
+    extensionWindow,
+    extensionLength
+  );
 
   if (!(await isInitializedUser(payer.publicKey, solConnection))) {
     console.log(
@@ -799,7 +1020,10 @@ export const createAuctionPNft = async (
     duration,
     reserved,
     program,
-    solConnection
+    solConnection,
+    PRICING_MODE_DEFAULT,
+    extensionWindow,
+    extensionLength
   );
   const { blockhash } = await solConnection.getRecentBlockhash("confirmed");
   tx.feePayer = payer.publicKey;
@@ -991,6 +1215,16 @@ export const getNFTPoolInfo = async (mint: PublicKey) => {
     priceSol: nftData.priceSol.toNumber(),
     listedDate: nftData.listedDate.toNumber(),
     active: nftData.active.toNumber(),
+    paymentMint: nftData.paymentMint.toBase58(),
+    expiresAt: nftData.expiresAt.toNumber(),
+    collectionSource: nftData.collectionSource.toNumber(),
+    merkleTree: nftData.merkleTree.toBase58(),
+    leafNonce: nftData.leafNonce.toNumber(),
+    reservedBuyer: nftData.reservedBuyer.toBase58(),
+    dutchFloorPrice: nftData.dutchFloorPrice.toNumber(),
+    dutchDuration: nftData.dutchDuration.toNumber(),
+    dutchStep: nftData.dutchStep.toNumber(),
+    pricingMode: nftData.pricingMode.toNumber(),
   };
 };
 
@@ -1025,6 +1259,11 @@ export const getAuctionDataInfo = async (mint: PublicKey) => {
     highestBid: auctionData.highestBid.toNumber(),
     duration: auctionData.duration.toNumber(),
     status: auctionData.status.toNumber(),
+    pricingMode: auctionData.pricingMode.toNumber(),
+    extensionWindow: auctionData.extensionWindow.toNumber(),
+    extensionLength: auctionData.extensionLength.toNumber(),
+    endDate: auctionData.endDate.toNumber(),
+    reservePrice: auctionData.reservePrice.toNumber(),
   };
 };
 
@@ -1034,6 +1273,7 @@ export const getUserPoolInfo = async (userAddress: PublicKey) => {
     address: userData.address.toBase58(),
     escrowSol: userData.escrowSolBalance.toNumber(),
     tradedVolume: userData.tradedVolume.toNumber(),
+    lockedSol: userData.lockedSolBalance.toNumber(),
   };
 };
 
@@ -1049,6 +1289,8 @@ export const getGlobalInfo = async () => {
     treasuryRate: globalPool.treasuryRate
       .slice(0, globalPool.teamCount.toNumber())
       .map((info) => info.toNumber()),
+    pricingMode: globalPool.pricingMode.toNumber(),
+    escrowLiabilities: globalPool.escrowLiabilities.toNumber(),
   };
 
   return result;
@@ -68,7 +68,7 @@ export type MugsMarketplace = {
       ];
     },
     {
-      name: "addTeamTreasury";
+      name: "updatePricingMode";
       accounts: [
         {
           name: "admin";
@@ -87,17 +87,13 @@ export type MugsMarketplace = {
           type: "u8";
         },
         {
-          name: "address";
-          type: "publicKey";
-        },
-        {
-          name: "rate";
+          name: "pricingMode";
           type: "u64";
         }
       ];
     },
     {
-      name: "removeTeamTreasury";
+      name: "addTeamTreasury";
       accounts: [
         {
           name: "admin";
@@ -118,50 +114,48 @@ export type MugsMarketplace = {
         {
           name: "address";
           type: "publicKey";
+        },
+        {
+          name: "rate";
+          type: "u64";
         }
       ];
     },
     {
-      name: "initUserPool";
+      name: "removeTeamTreasury";
       accounts: [
         {
-          name: "owner";
+          name: "admin";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "userPool";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
-        },
-        {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "rent";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "bump";
+          name: "globalBump";
           type: "u8";
+        },
+        {
+          name: "address";
+          type: "publicKey";
         }
       ];
     },
     {
-      name: "initSellData";
+      name: "migrateGlobalPool";
       accounts: [
         {
-          name: "payer";
+          name: "admin";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "sellDataInfo";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
         },
@@ -169,108 +163,94 @@ export type MugsMarketplace = {
           name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "rent";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "nft";
-          type: "publicKey";
-        },
-        {
-          name: "bump";
+          name: "globalBump";
           type: "u8";
         }
       ];
     },
     {
-      name: "setPrice";
+      name: "migrateUserData";
       accounts: [
         {
-          name: "owner";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "sellDataInfo";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nftMint";
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "sellBump";
-          type: "u8";
+          name: "address";
+          type: "publicKey";
         },
         {
-          name: "price";
-          type: "u64";
+          name: "bump";
+          type: "u8";
         }
       ];
     },
     {
-      name: "depositToEscrow";
+      name: "reconcileEscrowBalance";
       accounts: [
         {
-          name: "owner";
+          name: "admin";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "userPool";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "escrowVault";
+          name: "userPool";
           isMut: true;
           isSigner: false;
-        },
-        {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "userBump";
-          type: "u8";
+          name: "address";
+          type: "publicKey";
         },
         {
-          name: "escrowBump";
+          name: "bump";
           type: "u8";
         },
         {
-          name: "sol";
+          name: "deduction";
           type: "u64";
         }
       ];
     },
     {
-      name: "withdrawFromEscrow";
+      name: "migrateSellData";
       accounts: [
         {
-          name: "owner";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "userPool";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "escrowVault";
+          name: "sellDataInfo";
           isMut: true;
           isSigner: false;
         },
@@ -282,21 +262,17 @@ export type MugsMarketplace = {
       ];
       args: [
         {
-          name: "userBump";
-          type: "u8";
+          name: "nft";
+          type: "publicKey";
         },
         {
-          name: "escrowBump";
+          name: "bump";
           type: "u8";
-        },
-        {
-          name: "sol";
-          type: "u64";
         }
       ];
     },
     {
-      name: "initOfferData";
+      name: "migrateOfferData";
       accounts: [
         {
           name: "payer";
@@ -309,12 +285,12 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "userPool";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rent";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         }
@@ -324,6 +300,10 @@ export type MugsMarketplace = {
           name: "nft";
           type: "publicKey";
         },
+        {
+          name: "buyer";
+          type: "publicKey";
+        },
         {
           name: "bump";
           type: "u8";
@@ -331,37 +311,28 @@ export type MugsMarketplace = {
       ];
     },
     {
-      name: "makeOffer";
+      name: "migrateAuctionData";
       accounts: [
         {
-          name: "owner";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "sellDataInfo";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "offerDataInfo";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nftMint";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "userPool";
+          name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "escrowVault";
+          name: "bidderUserPool";
           isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
           name: "systemProgram";
@@ -371,40 +342,23 @@ export type MugsMarketplace = {
       ];
       args: [
         {
-          name: "sellBump";
-          type: "u8";
-        },
-        {
-          name: "offerBump";
-          type: "u8";
-        },
-        {
-          name: "userBump";
-          type: "u8";
+          name: "nft";
+          type: "publicKey";
         },
         {
-          name: "escrowBump";
+          name: "bump";
           type: "u8";
-        },
-        {
-          name: "price";
-          type: "u64";
         }
       ];
     },
     {
-      name: "cancelOffer";
+      name: "initUserPool";
       accounts: [
         {
           name: "owner";
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "offerDataInfo";
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: "userPool";
           isMut: true;
@@ -416,33 +370,20 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "escrowVault";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "nftMint";
+          name: "rent";
           isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "offerBump";
-          type: "u8";
-        },
-        {
-          name: "escrowBump";
-          type: "u8";
-        },
-        {
-          name: "userBump";
+          name: "bump";
           type: "u8";
         }
       ];
     },
     {
-      name: "initAuctionData";
+      name: "initSellData";
       accounts: [
         {
           name: "payer";
@@ -450,7 +391,7 @@ export type MugsMarketplace = {
           isSigner: true;
         },
         {
-          name: "auctionDataInfo";
+          name: "sellDataInfo";
           isMut: true;
           isSigner: false;
         },
@@ -477,91 +418,109 @@ export type MugsMarketplace = {
       ];
     },
     {
-      name: "placeBid";
+      name: "closeSellData";
       accounts: [
         {
-          name: "bidder";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "auctionDataInfo";
+          name: "sellDataInfo";
           isMut: true;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "nftMint";
-          isMut: false;
-          isSigner: false;
-        },
+          name: "bump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setPrice";
+      accounts: [
         {
-          name: "escrowVault";
+          name: "owner";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "outBidder";
+          name: "sellDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "nftMint";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "sellDataInfo";
-          isMut: true;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "auctionBump";
-          type: "u8";
-        },
-        {
-          name: "escrowBump";
+          name: "sellBump";
           type: "u8";
         },
         {
           name: "price";
           type: "u64";
+        },
+        {
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
         }
       ];
     },
     {
-      name: "updateReserve";
+      name: "quoteSale";
       accounts: [
         {
-          name: "creator";
-          isMut: true;
-          isSigner: true;
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: false;
+          isSigner: false;
         },
         {
           name: "auctionDataInfo";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
           name: "nftMint";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "auctionBump";
-          type: "u8";
+          name: "fromAuction";
+          type: "bool";
         },
         {
           name: "price";
-          type: "u64";
+          type: {
+            option: "u64";
+          };
         }
       ];
+      returns: {
+        defined: "SaleQuote";
+      };
     },
     {
-      name: "listPnftForSale";
+      name: "depositToEscrow";
       accounts: [
         {
           name: "owner";
@@ -574,88 +533,109 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "sellDataInfo";
+          name: "userPool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "destNftTokenAccount";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "userBump";
+          type: "u8";
         },
         {
-          name: "nftMint";
-          isMut: false;
-          isSigner: false;
+          name: "escrowBump";
+          type: "u8";
         },
         {
-          name: "mintMetadata";
+          name: "sol";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "withdrawFromEscrow";
+      accounts: [
+        {
+          name: "owner";
           isMut: true;
-          isSigner: false;
-          docs: ["the mint metadata"];
+          isSigner: true;
         },
         {
-          name: "tokenProgram";
-          isMut: false;
+          name: "globalAuthority";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
-          isMut: false;
+          name: "userPool";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMint";
-          isMut: false;
+          name: "escrowVault";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMintEdition";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong edition is supplied"];
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenMintRecord";
-          isMut: true;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
+          name: "userBump";
+          type: "u8";
         },
         {
-          name: "destTokenMintRecord";
-          isMut: true;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
+          name: "escrowBump";
+          type: "u8";
         },
         {
-          name: "authRules";
-          isMut: false;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong rules are supplied"];
-        },
+          name: "sol";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "auditEscrow";
+      accounts: [
         {
-          name: "sysvarInstructions";
+          name: "globalAuthority";
           isMut: false;
           isSigner: false;
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied"
-          ];
         },
         {
-          name: "associatedTokenProgram";
+          name: "escrowVault";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [];
+      returns: "u64";
+    },
+    {
+      name: "initOfferData";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "authRulesProgram";
-          isMut: false;
+          name: "offerDataInfo";
+          isMut: true;
           isSigner: false;
-          docs: ["CHECK intstruction will fail if wrong program is supplied"];
         },
         {
           name: "systemProgram";
@@ -663,32 +643,24 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "auctionDataInfo";
-          isMut: true;
+          name: "rent";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "globalBump";
-          type: "u8";
-        },
-        {
-          name: "sellBump";
-          type: "u8";
+          name: "nft";
+          type: "publicKey";
         },
         {
-          name: "auctionBump";
+          name: "bump";
           type: "u8";
-        },
-        {
-          name: "priceSol";
-          type: "u64";
         }
       ];
     },
     {
-      name: "delistPnft";
+      name: "makeOffer";
       accounts: [
         {
           name: "owner";
@@ -702,77 +674,86 @@ export type MugsMarketplace = {
         },
         {
           name: "sellDataInfo";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
+          name: "offerDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "destNftTokenAccount";
+          name: "nftMint";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "nftMint";
-          isMut: false;
+          name: "userPool";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "mintMetadata";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
-          docs: ["the mint metadata"];
         },
         {
-          name: "tokenProgram";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "sellBump";
+          type: "u8";
         },
         {
-          name: "tokenMintEdition";
-          isMut: false;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+          name: "offerBump";
+          type: "u8";
         },
         {
-          name: "tokenMintRecord";
-          isMut: true;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
+          name: "userBump";
+          type: "u8";
         },
         {
-          name: "destTokenMintRecord";
-          isMut: true;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
+          name: "escrowBump";
+          type: "u8";
         },
         {
-          name: "authRules";
-          isMut: false;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+          name: "price";
+          type: "u64";
         },
         {
-          name: "sysvarInstructions";
-          isMut: false;
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
+        }
+      ];
+    },
+    {
+      name: "cancelOffer";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
           isSigner: false;
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied"
-          ];
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
+          name: "offerDataInfo";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "authRulesProgram";
-          isMut: false;
+          name: "userPool";
+          isMut: true;
           isSigner: false;
-          docs: ["CHECK intstruction will fail if wrong program is supplied"];
         },
         {
           name: "systemProgram";
@@ -780,32 +761,36 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
-          isMut: false;
+          name: "escrowVault";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "auctionDataInfo";
-          isMut: true;
+          name: "nftMint";
+          isMut: false;
           isSigner: false;
         }
       ];
       args: [
         {
-          name: "globalBump";
+          name: "offerBump";
           type: "u8";
         },
         {
-          name: "sellBump";
+          name: "escrowBump";
+          type: "u8";
+        },
+        {
+          name: "userBump";
           type: "u8";
         }
       ];
     },
     {
-      name: "purchasePnft";
+      name: "refundOffer";
       accounts: [
         {
-          name: "buyer";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
@@ -815,149 +800,170 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "sellDataInfo";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "buyerUserPool";
+          name: "buyer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userNftTokenAccount";
+          name: "offerDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "destNftTokenAccount";
-          isMut: true;
+          name: "sellDataInfo";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "seller";
+          name: "userPool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "sellerUserPool";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "nftMint";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "makeCollectionOffer";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "mintMetadata";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
-          docs: ["the mint metadata"];
         },
         {
-          name: "tokenProgram";
-          isMut: false;
+          name: "collectionOfferInfo";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMintEdition";
+          name: "collectionMetadata";
           isMut: false;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong edition is supplied"];
         },
         {
-          name: "tokenMintRecord";
+          name: "userPool";
           isMut: true;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
         },
         {
-          name: "destTokenMintRecord";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
         },
         {
-          name: "authRules";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong rules are supplied"];
         },
         {
-          name: "sysvarInstructions";
+          name: "rent";
           isMut: false;
           isSigner: false;
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied"
-          ];
+        }
+      ];
+      args: [
+        {
+          name: "userBump";
+          type: "u8";
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
-          isSigner: false;
+          name: "escrowBump";
+          type: "u8";
         },
         {
-          name: "authRulesProgram";
-          isMut: false;
+          name: "collection";
+          type: "publicKey";
+        },
+        {
+          name: "price";
+          type: "u64";
+        },
+        {
+          name: "quantity";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "cancelCollectionOffer";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
           isSigner: false;
-          docs: ["CHECK intstruction will fail if wrong program is supplied"];
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "collectionOfferInfo";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
-          isMut: false;
+          name: "userPool";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "auctionDataInfo";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "globalBump";
-          type: "u8";
-        },
-        {
-          name: "nftBump";
-          type: "u8";
-        },
-        {
-          name: "sellerBump";
+          name: "userBump";
           type: "u8";
         },
         {
-          name: "buyerBump";
+          name: "escrowBump";
           type: "u8";
         }
       ];
     },
     {
-      name: "acceptOfferPnft";
+      name: "acceptCollectionOffer";
       accounts: [
         {
           name: "seller";
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "sellDataInfo";
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: "buyer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "offerDataInfo";
+          name: "collectionOfferInfo";
           isMut: true;
           isSigner: false;
         },
@@ -967,8 +973,8 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "nftMint";
-          isMut: false;
+          name: "buyerUserPool";
+          isMut: true;
           isSigner: false;
         },
         {
@@ -977,8 +983,8 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "buyerUserPool";
-          isMut: true;
+          name: "nftMint";
+          isMut: false;
           isSigner: false;
         },
         {
@@ -1059,70 +1065,53 @@ export type MugsMarketplace = {
           name: "tokenMetadataProgram";
           isMut: false;
           isSigner: false;
-        },
-        {
-          name: "auctionDataInfo";
-          isMut: true;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "globalBump";
-          type: "u8";
-        },
-        {
-          name: "nftBump";
-          type: "u8";
-        },
-        {
-          name: "offerBump";
-          type: "u8";
-        },
-        {
-          name: "buyerBump";
+          name: "escrowBump";
           type: "u8";
         },
         {
-          name: "sellerBump";
-          type: "u8";
+          name: "expectedPrice";
+          type: "u64";
         },
         {
-          name: "escrowBump";
-          type: "u8";
+          name: "expectedRoyalty";
+          type: "u64";
         }
       ];
     },
     {
-      name: "claimAuctionPnft";
+      name: "acceptStandingOffer";
       accounts: [
         {
-          name: "bidder";
+          name: "seller";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "globalAuthority";
+          name: "buyer";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "auctionDataInfo";
+          name: "offerDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
+          name: "sellerUserPool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "destNftTokenAccount";
+          name: "buyerUserPool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "creator";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
         },
@@ -1132,17 +1121,17 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "escrowVault";
+          name: "userNftTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "bidderUserPool";
+          name: "destNftTokenAccount";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "creatorUserPool";
+          name: "escrowVault";
           isMut: true;
           isSigner: false;
         },
@@ -1157,16 +1146,6 @@ export type MugsMarketplace = {
           isMut: false;
           isSigner: false;
         },
-        {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "tokenMetadataProgram";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "tokenMintEdition";
           isMut: false;
@@ -1209,117 +1188,137 @@ export type MugsMarketplace = {
           isMut: false;
           isSigner: false;
           docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "globalBump";
+          name: "escrowBump";
           type: "u8";
         },
         {
-          name: "auctionBump";
-          type: "u8";
+          name: "expectedPrice";
+          type: "u64";
         },
         {
-          name: "escrowBump";
-          type: "u8";
+          name: "expectedRoyalty";
+          type: "u64";
         }
       ];
     },
     {
-      name: "cancelAuctionPnft";
+      name: "initAuctionData";
       accounts: [
         {
-          name: "creator";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "globalAuthority";
-          isMut: true;
-          isSigner: false;
-        },
         {
           name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userTokenAccount";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "destNftTokenAccount";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "nftMint";
+          name: "rent";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "nft";
+          type: "publicKey";
         },
         {
-          name: "mintMetadata";
+          name: "bump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "closeAuctionData";
+      accounts: [
+        {
+          name: "payer";
           isMut: true;
-          isSigner: false;
-          docs: ["the mint metadata"];
+          isSigner: true;
         },
         {
-          name: "tokenProgram";
-          isMut: false;
+          name: "auctionDataInfo";
+          isMut: true;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "tokenMintEdition";
-          isMut: false;
-          isSigner: false;
-          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+          name: "bump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "placeBid";
+      accounts: [
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
         },
         {
-          name: "tokenMintRecord";
+          name: "globalAuthority";
           isMut: true;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
         },
         {
-          name: "destTokenMintRecord";
+          name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong record is supplied"];
         },
         {
-          name: "authRules";
+          name: "nftMint";
           isMut: false;
           isSigner: false;
-          docs: ["CHECK instruction will fail if wrong rules are supplied"];
         },
         {
-          name: "sysvarInstructions";
-          isMut: false;
+          name: "escrowVault";
+          isMut: true;
           isSigner: false;
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied"
-          ];
         },
         {
-          name: "associatedTokenProgram";
-          isMut: false;
+          name: "outBidder";
+          isMut: true;
           isSigner: false;
+          docs: ["Previous bidder released by this bid"];
         },
         {
-          name: "authRulesProgram";
-          isMut: false;
+          name: "bidderUserPool";
+          isMut: true;
           isSigner: false;
-          docs: ["CHECK intstruction will fail if wrong program is supplied"];
         },
         {
-          name: "systemProgram";
-          isMut: false;
+          name: "outBidderUserPool";
+          isMut: true;
           isSigner: false;
+          isOptional: true;
+          docs: ["Only required when the auction has a bid to refund"];
         },
         {
-          name: "tokenMetadataProgram";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
@@ -1331,32 +1330,93 @@ export type MugsMarketplace = {
       ];
       args: [
         {
-          name: "globalBump";
+          name: "auctionBump";
           type: "u8";
         },
         {
-          name: "auctionBump";
+          name: "escrowBump";
           type: "u8";
+        },
+        {
+          name: "price";
+          type: "u64";
         }
       ];
     },
     {
-      name: "createAuctionPnft";
+      name: "updateReserve";
       accounts: [
         {
-          name: "owner";
+          name: "creator";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "globalAuthority";
+          name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "auctionBump";
+          type: "u8";
+        },
+        {
+          name: "price";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "revealReserve";
+      accounts: [
+        {
+          name: "creator";
+          isMut: false;
+          isSigner: true;
+        },
         {
           name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "reservePrice";
+          type: "u64";
+        },
+        {
+          name: "salt";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
+    {
+      name: "listPnftForSale";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
         },
         {
           name: "userTokenAccount";
@@ -1443,9 +1503,16 @@ export type MugsMarketplace = {
           isSigner: false;
         },
         {
-          name: "sellDataInfo";
+          name: "auctionDataInfo";
           isMut: true;
           isSigner: false;
+        },
+        {
+          name: "paymentMintAccount";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only required for the listings priced in SPL Token"];
         }
       ];
       args: [
@@ -1454,425 +1521,5830 @@ export type MugsMarketplace = {
           type: "u8";
         },
         {
-          name: "auctionBump";
+          name: "sellBump";
           type: "u8";
         },
         {
-          name: "sellBump";
+          name: "auctionBump";
           type: "u8";
         },
         {
-          name: "startPrice";
+          name: "priceSol";
           type: "u64";
         },
         {
-          name: "minIncrease";
-          type: "u64";
+          name: "paymentMint";
+          type: "publicKey";
         },
         {
-          name: "duration";
-          type: "i64";
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
         },
         {
-          name: "reserved";
-          type: "u8";
+          name: "reservedBuyer";
+          type: {
+            option: "publicKey";
+          };
+        },
+        {
+          name: "dutch";
+          type: {
+            option: {
+              defined: "DutchSchedule";
+            };
+          };
+        },
+        {
+          name: "pricingMode";
+          type: "u64";
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "GlobalPool";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "superAdmin";
-            type: "publicKey";
-          },
-          {
-            name: "marketFeeSol";
-            type: "u64";
-          },
-          {
-            name: "teamCount";
-            type: "u64";
-          },
-          {
-            name: "teamTreasury";
-            type: {
-              array: ["publicKey", 8];
-            };
-          },
-          {
-            name: "treasuryRate";
-            type: {
-              array: ["u64", 8];
-            };
-          }
-        ];
-      };
     },
     {
-      name: "SellData";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "seller";
-            type: "publicKey";
-          },
-          {
-            name: "collection";
-            type: "publicKey";
-          },
-          {
-            name: "priceSol";
-            type: "u64";
-          },
-          {
-            name: "listedDate";
-            type: "i64";
-          },
-          {
-            name: "active";
-            type: "u64";
-          }
-        ];
-      };
+      name: "delistPnft";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "sellBump";
+          type: "u8";
+        }
+      ];
     },
     {
-      name: "OfferData";
-      type: {
-        kind: "struct";
+      name: "delistExpiredPnft";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "seller";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "globalNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "sellBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "purchasePnft";
+      accounts: [
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: false;
+          isSigner: false;
+          docs: ["NFT receiver, same as the buyer for the normal purchase"];
+        },
+        {
+          name: "recipientUserPool";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only required to credit the traded volume to the recipient"];
+        },
+        {
+          name: "userNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only required for the listings priced in SPL Token"];
+        },
+        {
+          name: "sellerPaymentTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only required for the listings priced in SPL Token"];
+        },
+        {
+          name: "paymentTokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only required for the listings priced in SPL Token",
+            "NFT token program can be Token-2022 so the payment goes through its own program"
+          ];
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: ["Only required for the purchase paid from the escrow balance"];
+        },
+        {
+          name: "sellerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "nftBump";
+          type: "u8";
+        },
+        {
+          name: "sellerBump";
+          type: "u8";
+        },
+        {
+          name: "buyerBump";
+          type: "u8";
+        },
+        {
+          name: "expectedPrice";
+          type: "u64";
+        },
+        {
+          name: "expectedRoyalty";
+          type: "u64";
+        },
+        {
+          name: "fromEscrow";
+          type: "bool";
+        },
+        {
+          name: "escrowBump";
+          type: "u8";
+        },
+        {
+          name: "creditRecipient";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "listCnftForSale";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeConfig";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied"
+          ];
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong tree is supplied"];
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "priceSol";
+          type: "u64";
+        },
+        {
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
+        },
+        {
+          name: "pricingMode";
+          type: "u64";
+        },
+        {
+          name: "metadata";
+          type: {
+            defined: "MetadataArgs";
+          };
+        },
+        {
+          name: "leaf";
+          type: {
+            defined: "CNftLeaf";
+          };
+        }
+      ];
+    },
+    {
+      name: "delistCnft";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeConfig";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied"
+          ];
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong tree is supplied"];
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "metadata";
+          type: {
+            defined: "MetadataArgs";
+          };
+        },
+        {
+          name: "leaf";
+          type: {
+            defined: "CNftLeaf";
+          };
+        }
+      ];
+    },
+    {
+      name: "purchaseCnft";
+      accounts: [
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeConfig";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied"
+          ];
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong tree is supplied"];
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "expectedPrice";
+          type: "u64";
+        },
+        {
+          name: "expectedRoyalty";
+          type: "u64";
+        },
+        {
+          name: "metadata";
+          type: {
+            defined: "MetadataArgs";
+          };
+        },
+        {
+          name: "leaf";
+          type: {
+            defined: "CNftLeaf";
+          };
+        }
+      ];
+    },
+    {
+      name: "listBundleForSale";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bundleDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "bundleId";
+          type: "u64";
+        },
+        {
+          name: "priceSol";
+          type: "u64";
+        },
+        {
+          name: "pricingMode";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "delistBundle";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bundleDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "purchaseBundle";
+      accounts: [
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bundleDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "expectedPrice";
+          type: "u64";
+        },
+        {
+          name: "expectedRoyalty";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "acceptOfferPnft";
+      accounts: [
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Signs when accepting an offer, the buyer signs when accepting a counter offer"
+          ];
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "nftBump";
+          type: "u8";
+        },
+        {
+          name: "offerBump";
+          type: "u8";
+        },
+        {
+          name: "buyerBump";
+          type: "u8";
+        },
+        {
+          name: "sellerBump";
+          type: "u8";
+        },
+        {
+          name: "escrowBump";
+          type: "u8";
+        },
+        {
+          name: "expectedPrice";
+          type: "u64";
+        },
+        {
+          name: "expectedRoyalty";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "rejectOffer";
+      accounts: [
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellerNftTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "counterOffer";
+      accounts: [
+        {
+          name: "seller";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "offerDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "counterPrice";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "acceptCounterOffer";
+      accounts: [
+        {
+          name: "seller";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Signs when accepting an offer, the buyer signs when accepting a counter offer"
+          ];
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "offerDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "escrowBump";
+          type: "u8";
+        },
+        {
+          name: "expectedPrice";
+          type: "u64";
+        },
+        {
+          name: "expectedRoyalty";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "claimAuctionPnft";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "prevBidder";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+          docs: [
+            "Only required when the auction keeps an outbid bid to refund"
+          ];
+        },
+        {
+          name: "prevBidderUserPool";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "bidderUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creatorUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "globalNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "auctionBump";
+          type: "u8";
+        },
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "cancelAuctionPnft";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Signs when canceling the auction, not required for the crank"
+          ];
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "globalNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "auctionBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "cancelBid";
+      accounts: [
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "createAuctionPnft";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionDataInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "auctionBump";
+          type: "u8";
+        },
+        {
+          name: "sellBump";
+          type: "u8";
+        },
+        {
+          name: "startPrice";
+          type: "u64";
+        },
+        {
+          name: "minIncrease";
+          type: "u64";
+        },
+        {
+          name: "duration";
+          type: "i64";
+        },
+        {
+          name: "reserved";
+          type: "u8";
+        },
+        {
+          name: "pricingMode";
+          type: "u64";
+        },
+        {
+          name: "extensionWindow";
+          type: "i64";
+        },
+        {
+          name: "extensionLength";
+          type: "i64";
+        },
+        {
+          name: "reservePrice";
+          type: "u64";
+        },
+        {
+          name: "reserveHash";
+          type: {
+            option: {
+              array: ["u8", 32];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "createSealedAuctionPnft";
+      accounts: [
+        {
+          name: "owner";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sellDataInfo";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "minPrice";
+          type: "u64";
+        },
+        {
+          name: "commitDuration";
+          type: "i64";
+        },
+        {
+          name: "revealDuration";
+          type: "i64";
+        },
+        {
+          name: "priceRule";
+          type: "u64";
+        },
+        {
+          name: "pricingMode";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "commitSealedBid";
+      accounts: [
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedBidInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidderUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "bidHash";
+          type: {
+            array: ["u8", 32];
+          };
+        },
+        {
+          name: "deposit";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "revealSealedBid";
+      accounts: [
+        {
+          name: "bidder";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedBidInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "salt";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
+    {
+      name: "settleSealedAuction";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "winner";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "winnerBidInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "winnerUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "creatorUserPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "destTokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        },
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "refundSealedBid";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "globalAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bidder";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedBidInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrowVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "escrowBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "cancelSealedAuctionPnft";
+      accounts: [
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+          docs: [
+            "Signs when canceling the auction, not required for the crank"
+          ];
+        },
+        {
+          name: "globalAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mintMetadata";
+          isMut: true;
+          isSigner: false;
+          docs: ["the mint metadata"];
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMintEdition";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong edition is supplied"];
+        },
+        {
+          name: "tokenMintRecord";
+          isMut: true;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong record is supplied"];
+        },
+        {
+          name: "authRules";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK instruction will fail if wrong rules are supplied"];
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied"
+          ];
+        },
+        {
+          name: "authRulesProgram";
+          isMut: false;
+          isSigner: false;
+          docs: ["CHECK intstruction will fail if wrong program is supplied"];
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "globalNftTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "globalBump";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "closeSealedAuction";
+      accounts: [
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sealedAuctionInfo";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "GlobalPool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "superAdmin";
+            type: "publicKey";
+          },
+          {
+            name: "marketFeeSol";
+            type: "u64";
+          },
+          {
+            name: "teamCount";
+            type: "u64";
+          },
+          {
+            name: "teamTreasury";
+            type: {
+              array: ["publicKey", 8];
+            };
+          },
+          {
+            name: "treasuryRate";
+            type: {
+              array: ["u64", 8];
+            };
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          },
+          {
+            name: "escrowLiabilities";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SellData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "seller";
+            type: "publicKey";
+          },
+          {
+            name: "collection";
+            type: "publicKey";
+          },
+          {
+            name: "priceSol";
+            type: "u64";
+          },
+          {
+            name: "listedDate";
+            type: "i64";
+          },
+          {
+            name: "active";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "collectionSource";
+            type: "u64";
+          },
+          {
+            name: "merkleTree";
+            type: "publicKey";
+          },
+          {
+            name: "leafNonce";
+            type: "u64";
+          },
+          {
+            name: "reservedBuyer";
+            type: "publicKey";
+          },
+          {
+            name: "dutchFloorPrice";
+            type: "u64";
+          },
+          {
+            name: "dutchDuration";
+            type: "i64";
+          },
+          {
+            name: "dutchStep";
+            type: "i64";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "BundleData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "seller";
+            type: "publicKey";
+          },
+          {
+            name: "bundleId";
+            type: "u64";
+          },
+          {
+            name: "priceSol";
+            type: "u64";
+          },
+          {
+            name: "listedDate";
+            type: "i64";
+          },
+          {
+            name: "itemCount";
+            type: "u64";
+          },
+          {
+            name: "mints";
+            type: {
+              array: ["publicKey", 5];
+            };
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "OfferData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "offerPrice";
+            type: "u64";
+          },
+          {
+            name: "offerListingDate";
+            type: "i64";
+          },
+          {
+            name: "active";
+            type: "u64";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "counterPrice";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CollectionOfferData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "collection";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "quantity";
+            type: "u64";
+          },
+          {
+            name: "createdDate";
+            type: "i64";
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          },
+          {
+            name: "marketFeeSol";
+            type: "u64";
+          },
+          {
+            name: "royaltyBasisPoints";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "AuctionData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "startPrice";
+            type: "u64";
+          },
+          {
+            name: "minIncreaseAmount";
+            type: "u64";
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "lastBidDate";
+            type: "i64";
+          },
+          {
+            name: "lastBidder";
+            type: "publicKey";
+          },
+          {
+            name: "highestBid";
+            type: "u64";
+          },
+          {
+            name: "duration";
+            type: "i64";
+          },
+          {
+            name: "status";
+            type: "u64";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          },
+          {
+            name: "extensionWindow";
+            type: "i64";
+          },
+          {
+            name: "extensionLength";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "reservePrice";
+            type: "u64";
+          },
+          {
+            name: "reserveHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "prevBidder";
+            type: "publicKey";
+          },
+          {
+            name: "prevBid";
+            type: "u64";
+          },
+          {
+            name: "marketFeeSol";
+            type: "u64";
+          },
+          {
+            name: "royaltyBasisPoints";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SealedAuctionData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "minPrice";
+            type: "u64";
+          },
+          {
+            name: "commitEndDate";
+            type: "i64";
+          },
+          {
+            name: "revealEndDate";
+            type: "i64";
+          },
+          {
+            name: "priceRule";
+            type: "u64";
+          },
+          {
+            name: "highestBidder";
+            type: "publicKey";
+          },
+          {
+            name: "highestBid";
+            type: "u64";
+          },
+          {
+            name: "secondBid";
+            type: "u64";
+          },
+          {
+            name: "openBids";
+            type: "u64";
+          },
+          {
+            name: "status";
+            type: "u64";
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          },
+          {
+            name: "marketFeeSol";
+            type: "u64";
+          },
+          {
+            name: "royaltyBasisPoints";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SealedBidData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "bidder";
+            type: "publicKey";
+          },
+          {
+            name: "bidHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "deposit";
+            type: "u64";
+          },
+          {
+            name: "revealedBid";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UserData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "tradedVolume";
+            type: "u64";
+          },
+          {
+            name: "escrowSolBalance";
+            type: "u64";
+          },
+          {
+            name: "lockedSolBalance";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u64";
+          },
+          {
+            name: "legacyBalance";
+            type: "u64";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "CNftLeaf";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "root";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "index";
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "DutchSchedule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "floorPrice";
+            type: "u64";
+          },
+          {
+            name: "duration";
+            type: "i64";
+          },
+          {
+            name: "step";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "SaleQuote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "pricingMode";
+            type: "u64";
+          },
+          {
+            name: "marketFee";
+            type: "u64";
+          },
+          {
+            name: "royalty";
+            type: "u64";
+          },
+          {
+            name: "sellerProceeds";
+            type: "u64";
+          },
+          {
+            name: "buyerTotal";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "NftStandard";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Programmable";
+          },
+          {
+            name: "Legacy";
+          },
+          {
+            name: "Token2022";
+          }
+        ];
+      };
+    },
+    {
+      name: "MetadataArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "primarySaleHappened";
+            type: "bool";
+          },
+          {
+            name: "isMutable";
+            type: "bool";
+          },
+          {
+            name: "editionNonce";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "tokenStandard";
+            type: {
+              option: {
+                defined: "TokenStandard";
+              };
+            };
+          },
+          {
+            name: "collection";
+            type: {
+              option: {
+                defined: "Collection";
+              };
+            };
+          },
+          {
+            name: "uses";
+            type: {
+              option: {
+                defined: "Uses";
+              };
+            };
+          },
+          {
+            name: "tokenProgramVersion";
+            type: {
+              defined: "TokenProgramVersion";
+            };
+          },
+          {
+            name: "creators";
+            type: {
+              vec: {
+                defined: "Creator";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "Creator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "verified";
+            type: "bool";
+          },
+          {
+            name: "share";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "Collection";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "verified";
+            type: "bool";
+          },
+          {
+            name: "key";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "Uses";
+      type: {
+        kind: "struct";
         fields: [
           {
-            name: "mint";
-            type: "publicKey";
+            name: "useMethod";
+            type: {
+              defined: "UseMethod";
+            };
+          },
+          {
+            name: "remaining";
+            type: "u64";
+          },
+          {
+            name: "total";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "UseMethod";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Burn";
           },
           {
-            name: "buyer";
-            type: "publicKey";
+            name: "Multiple";
           },
           {
-            name: "offerPrice";
-            type: "u64";
+            name: "Single";
+          }
+        ];
+      };
+    },
+    {
+      name: "TokenStandard";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NonFungible";
           },
           {
-            name: "offerListingDate";
-            type: "i64";
+            name: "FungibleAsset";
           },
           {
-            name: "active";
-            type: "u64";
+            name: "Fungible";
+          },
+          {
+            name: "NonFungibleEdition";
+          }
+        ];
+      };
+    },
+    {
+      name: "TokenProgramVersion";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Original";
+          },
+          {
+            name: "Token2022";
           }
         ];
       };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidSuperOwner";
+      msg: "Invalid Super Owner";
+    },
+    {
+      code: 6001;
+      name: "InvalidOwner";
+      msg: "Invalid Owner";
+    },
+    {
+      code: 6002;
+      name: "InvalidGlobalPool";
+      msg: "Invalid Global Pool Address";
+    },
+    {
+      code: 6003;
+      name: "InvalidFeePercent";
+      msg: "Marketplace Fee is Permyriad";
+    },
+    {
+      code: 6004;
+      name: "MaxTeamCountExceed";
+      msg: "Max Team Count is 8";
+    },
+    {
+      code: 6005;
+      name: "NoTeamTreasuryYet";
+      msg: "Treasury Wallet Not Configured";
+    },
+    {
+      code: 6006;
+      name: "TreasuryAddressNotFound";
+      msg: "Treasury Address Not Exist";
+    },
+    {
+      code: 6007;
+      name: "TreasuryAddressAlreadyAdded";
+      msg: "Treasury Address Already Exist";
+    },
+    {
+      code: 6008;
+      name: "MaxTreasuryRateSumExceed";
+      msg: "Total Treasury Rate Sum Should Less Than 100%";
+    },
+    {
+      code: 6009;
+      name: "TeamTreasuryCountMismatch";
+      msg: "Team Treasury Wallet Count Mismatch";
+    },
+    {
+      code: 6010;
+      name: "TeamTreasuryAddressMismatch";
+      msg: "Team Treasury Wallet Address Mismatch";
+    },
+    {
+      code: 6011;
+      name: "Uninitialized";
+      msg: "Uninitialized Account";
+    },
+    {
+      code: 6012;
+      name: "InvalidParamInput";
+      msg: "Instruction Parameter is Invalid";
+    },
+    {
+      code: 6013;
+      name: "SellerMismatch";
+      msg: "Payer Mismatch with NFT Seller";
+    },
+    {
+      code: 6014;
+      name: "InvalidNFTDataAcount";
+      msg: "Invalid NFT Data Account";
+    },
+    {
+      code: 6015;
+      name: "NotListedNFT";
+      msg: "The NFT Is Not Listed";
+    },
+    {
+      code: 6016;
+      name: "SellerAccountMismatch";
+      msg: "Seller Account Mismatch with NFT Seller Data";
+    },
+    {
+      code: 6017;
+      name: "InsufficientBuyerSolBalance";
+      msg: "Buyer Sol Balance is Less than NFT SOL Price";
+    },
+    {
+      code: 6018;
+      name: "InsufficientBuyerTokenBalance";
+      msg: "Buyer Token Balance is Less than NFT Token Price";
+    },
+    {
+      code: 6019;
+      name: "InvaliedMetadata";
+      msg: "Invalid Metadata Address";
+    },
+    {
+      code: 6020;
+      name: "MetadataCreatorParseError";
+      msg: "Can't Parse The NFT's Creators";
+    },
+    {
+      code: 6021;
+      name: "InvalidOfferDataMint";
+      msg: "Offer Data Mint mismatch with NFT Pubkey";
+    },
+    {
+      code: 6022;
+      name: "InvalidOfferDataBuyer";
+      msg: "Offer Data Buyer mismatch with Payer Pubkey";
+    },
+    {
+      code: 6023;
+      name: "OfferForNotListedNFT";
+      msg: "Making Offer for Not Listed NFT";
+    },
+    {
+      code: 6024;
+      name: "InvalidOfferPrice";
+      msg: "Offer Price Over Thank Listed Price";
+    },
+    {
+      code: 6025;
+      name: "DisabledOffer";
+      msg: "Already Canceled Offer";
+    },
+    {
+      code: 6026;
+      name: "OfferForExpiredListingNFT";
+      msg: "Offer For Sold Or Canceled NFT Listing";
+    },
+    {
+      code: 6027;
+      name: "EndedAuction";
+      msg: "Placing Bid For Ended Auction";
+    },
+    {
+      code: 6028;
+      name: "InvalidBidPrice";
+      msg: "Placing Bid With Lower Than Highest Bid";
+    },
+    {
+      code: 6029;
+      name: "DoubleBidFromOneBidder";
+      msg: "Placing Bid Double From One Bidder";
+    },
+    {
+      code: 6030;
+      name: "OutBidderMismatch";
+      msg: "Out Bidder Account Mismatch With LastBidder Data";
+    },
+    {
+      code: 6031;
+      name: "NotEndedAuction";
+      msg: "Claiming Auction For Not Ended Auction";
+    },
+    {
+      code: 6032;
+      name: "CreatorAccountMismatch";
+      msg: "Creator Account Mismatch with Auction Data";
+    },
+    {
+      code: 6033;
+      name: "BidderAccountMismatch";
+      msg: "Bidder Account Mismatch with Auction Data";
+    },
+    {
+      code: 6034;
+      name: "AuctionHasBid";
+      msg: "Canceling Auction which has Bid";
+    },
+    {
+      code: 6035;
+      name: "BidFromAuctionCreator";
+      msg: "Placing Bid From Auction Creator";
+    },
+    {
+      code: 6036;
+      name: "ListingNotAvailable";
+      msg: "Only Listing and Reserved Auction are possible to exist together";
+    },
+    {
+      code: 6037;
+      name: "NFTIsNotInUserATA";
+      msg: "NFT Is Not In User ATA";
+    },
+    {
+      code: 6038;
+      name: "NFTIsNotInEscrowATA";
+      msg: "NFT Is Not In Escrow ATA";
+    },
+    {
+      code: 6039;
+      name: "InvalidPaymentTokenAccount";
+      msg: "Payment Token Account Mismatch with Listing Payment Mint";
+    },
+    {
+      code: 6040;
+      name: "OfferForTokenListing";
+      msg: "Offer Is Only Available For SOL Listing";
+    },
+    {
+      code: 6041;
+      name: "AccountAlreadyMigrated";
+      msg: "Data Account Is Already Migrated";
+    },
+    {
+      code: 6042;
+      name: "ExpiredListing";
+      msg: "The NFT Listing Is Expired";
+    },
+    {
+      code: 6043;
+      name: "NotExpiredListing";
+      msg: "The NFT Listing Is Not Expired Yet";
+    },
+    {
+      code: 6044;
+      name: "TradeTermsMismatch";
+      msg: "Trade Price Or Royalty Mismatch with Expected Terms";
+    },
+    {
+      code: 6045;
+      name: "ReservedListing";
+      msg: "The NFT Listing Is Reserved For Another Buyer";
+    },
+    {
+      code: 6046;
+      name: "InvalidRentPayer";
+      msg: "Signer Is Not The Rent Payer Of The PDA";
+    },
+    {
+      code: 6047;
+      name: "DataAccountInUse";
+      msg: "The PDA Is Still In Use";
+    },
+    {
+      code: 6048;
+      name: "CreatorAddressMismatch";
+      msg: "Remaining Account Mismatch with The NFT Creator";
+    },
+    {
+      code: 6049;
+      name: "BundleAccountsMismatch";
+      msg: "Remaining Accounts Mismatch with The Bundle Items";
+    },
+    {
+      code: 6050;
+      name: "InvalidBundleItem";
+      msg: "Bundle Item Should Be A Programmable NFT Held By The Seller";
     },
     {
-      name: "AuctionData";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "creator";
-            type: "publicKey";
-          },
-          {
-            name: "startPrice";
-            type: "u64";
-          },
-          {
-            name: "minIncreaseAmount";
-            type: "u64";
-          },
-          {
-            name: "startDate";
-            type: "i64";
-          },
-          {
-            name: "lastBidDate";
-            type: "i64";
-          },
-          {
-            name: "lastBidder";
-            type: "publicKey";
-          },
-          {
-            name: "highestBid";
-            type: "u64";
-          },
-          {
-            name: "duration";
-            type: "i64";
-          },
-          {
-            name: "status";
-            type: "u64";
-          }
-        ];
-      };
+      code: 6051;
+      name: "CollectionMismatch";
+      msg: "The NFT Is Not A Verified Member Of The Offer Collection";
     },
     {
-      name: "UserData";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "address";
-            type: "publicKey";
-          },
-          {
-            name: "tradedVolume";
-            type: "u64";
-          },
-          {
-            name: "escrowSolBalance";
-            type: "u64";
-          }
-        ];
-      };
+      code: 6052;
+      name: "ExpiredOffer";
+      msg: "The Offer Is Expired";
+    },
+    {
+      code: 6053;
+      name: "NotRefundableOffer";
+      msg: "The Offer Is Still Valid";
+    },
+    {
+      code: 6054;
+      name: "EscrowShortfall";
+      msg: "Escrow Vault Balance Is Below The Escrow Liabilities";
+    },
+    {
+      code: 6055;
+      name: "InvalidReserveReveal";
+      msg: "Revealed Reserve Price Doesn't Match The Commitment";
+    },
+    {
+      code: 6056;
+      name: "ReserveNotRevealed";
+      msg: "Hidden Reserve Is Still In The Reveal Period";
+    },
+    {
+      code: 6057;
+      name: "BidRetractionClosed";
+      msg: "Bid Retraction Window Is Closed";
+    },
+    {
+      code: 6058;
+      name: "InvalidSealedPhase";
+      msg: "Sealed Bid Auction Is Not In This Phase";
+    },
+    {
+      code: 6059;
+      name: "InvalidBidReveal";
+      msg: "Revealed Bid Doesn't Match The Commitment";
+    },
+    {
+      code: 6060;
+      name: "WinningSealedBid";
+      msg: "Winning Sealed Bid Is Settled With The Auction";
+    },
+    {
+      code: 6061;
+      name: "InvalidTokenStandard";
+      msg: "Token Standard Is Not A Non Fungible Standard";
+    },
+    {
+      code: 6062;
+      name: "MathOverflow";
+      msg: "Amount Overflow";
+    },
+    {
+      code: 6063;
+      name: "InvalidPaymentMint";
+      msg: "Payment Mint Should Be A SPL Token Mint";
+    },
+    {
+      code: 6064;
+      name: "PaymentAccountNotInitialized";
+      msg: "Payment Token Account Is Not Initialized";
+    },
+    {
+      code: 6065;
+      name: "DuplicateUserAccount";
+      msg: "Same User Data Account Can Not Be Used Twice";
     }
   ];
-  errors: [
+  metadata: {
+    address: "5J3fJvN67uWLo2uNaygTJjdRoJs5mxn9XgtXroiQkcwm";
+  };
+};
+
+export const IDL: MugsMarketplace = {
+  version: "0.1.0",
+  name: "mugs_marketplace",
+  instructions: [
     {
-      code: 6000;
-      name: "InvalidSuperOwner";
-      msg: "Invalid Super Owner";
+      name: "initialize",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6001;
-      name: "InvalidOwner";
-      msg: "Invalid Owner";
+      name: "updateFee",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "solFee",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6002;
-      name: "InvalidGlobalPool";
-      msg: "Invalid Global Pool Address";
+      name: "updatePricingMode",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6003;
-      name: "InvalidFeePercent";
-      msg: "Marketplace Fee is Permyriad";
+      name: "addTeamTreasury",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "address",
+          type: "publicKey",
+        },
+        {
+          name: "rate",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "removeTeamTreasury",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "address",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "migrateGlobalPool",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6004;
-      name: "MaxTeamCountExceed";
-      msg: "Max Team Count is 8";
+      name: "migrateUserData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "address",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6005;
-      name: "NoTeamTreasuryYet";
-      msg: "Treasury Wallet Not Configured";
+      name: "reconcileEscrowBalance",
+      accounts: [
+        {
+          name: "admin",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "address",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+        {
+          name: "deduction",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6006;
-      name: "TreasuryAddressNotFound";
-      msg: "Treasury Address Not Exist";
+      name: "migrateSellData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6007;
-      name: "TreasuryAddressAlreadyAdded";
-      msg: "Treasury Address Already Exist";
+      name: "migrateOfferData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6008;
-      name: "MaxTreasuryRateSumExceed";
-      msg: "Total Treasury Rate Sum Should Less Than 100%";
+      name: "migrateAuctionData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bidderUserPool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6009;
-      name: "TeamTreasuryCountMismatch";
-      msg: "Team Treasury Wallet Count Mismatch";
+      name: "initUserPool",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6010;
-      name: "TeamTreasuryAddressMismatch";
-      msg: "Team Treasury Wallet Address Mismatch";
+      name: "initSellData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6011;
-      name: "Uninitialized";
-      msg: "Uninitialized Account";
+      name: "closeSellData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6012;
-      name: "InvalidParamInput";
-      msg: "Instruction Parameter is Invalid";
+      name: "setPrice",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "sellBump",
+          type: "u8",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+      ],
     },
     {
-      code: 6013;
-      name: "SellerMismatch";
-      msg: "Payer Mismatch with NFT Seller";
+      name: "quoteSale",
+      accounts: [
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "fromAuction",
+          type: "bool",
+        },
+        {
+          name: "price",
+          type: {
+            option: "u64",
+          },
+        },
+      ],
+      returns: {
+        defined: "SaleQuote",
+      },
     },
     {
-      code: 6014;
-      name: "InvalidNFTDataAcount";
-      msg: "Invalid NFT Data Account";
+      name: "depositToEscrow",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "userBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "sol",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6015;
-      name: "NotListedNFT";
-      msg: "The NFT Is Not Listed";
+      name: "withdrawFromEscrow",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "userBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "sol",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6016;
-      name: "SellerAccountMismatch";
-      msg: "Seller Account Mismatch with NFT Seller Data";
+      name: "auditEscrow",
+      accounts: [
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+      returns: "u64",
     },
     {
-      code: 6017;
-      name: "InsufficientBuyerSolBalance";
-      msg: "Buyer Sol Balance is Less than NFT SOL Price";
+      name: "initOfferData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6018;
-      name: "InsufficientBuyerTokenBalance";
-      msg: "Buyer Token Balance is Less than NFT Token Price";
+      name: "makeOffer",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "sellBump",
+          type: "u8",
+        },
+        {
+          name: "offerBump",
+          type: "u8",
+        },
+        {
+          name: "userBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+      ],
     },
     {
-      code: 6019;
-      name: "InvaliedMetadata";
-      msg: "Invalid Metadata Address";
+      name: "cancelOffer",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "offerBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "userBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6020;
-      name: "MetadataCreatorParseError";
-      msg: "Can't Parse The NFT's Creators";
+      name: "refundOffer",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6021;
-      name: "InvalidOfferDataMint";
-      msg: "Offer Data Mint mismatch with NFT Pubkey";
+      name: "makeCollectionOffer",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionOfferInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionMetadata",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "userBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "collection",
+          type: "publicKey",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+        {
+          name: "quantity",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6022;
-      name: "InvalidOfferDataBuyer";
-      msg: "Offer Data Buyer mismatch with Payer Pubkey";
+      name: "cancelCollectionOffer",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionOfferInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "userBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6023;
-      name: "OfferForNotListedNFT";
-      msg: "Making Offer for Not Listed NFT";
+      name: "acceptCollectionOffer",
+      accounts: [
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collectionOfferInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6024;
-      name: "InvalidOfferPrice";
-      msg: "Offer Price Over Thank Listed Price";
+      name: "acceptStandingOffer",
+      accounts: [
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6025;
-      name: "DisabledOffer";
-      msg: "Already Canceled Offer";
+      name: "initAuctionData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "nft",
+          type: "publicKey",
+        },
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6026;
-      name: "OfferForExpiredListingNFT";
-      msg: "Offer For Sold Or Canceled NFT Listing";
+      name: "closeAuctionData",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "bump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6027;
-      name: "EndedAuction";
-      msg: "Placing Bid For Ended Auction";
+      name: "placeBid",
+      accounts: [
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outBidder",
+          isMut: true,
+          isSigner: false,
+          docs: ["Previous bidder released by this bid"],
+        },
+        {
+          name: "bidderUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outBidderUserPool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required when the auction has a bid to refund"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "auctionBump",
+          type: "u8",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6028;
-      name: "InvalidBidPrice";
-      msg: "Placing Bid With Lower Than Highest Bid";
+      name: "updateReserve",
+      accounts: [
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "auctionBump",
+          type: "u8",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6029;
-      name: "DoubleBidFromOneBidder";
-      msg: "Placing Bid Double From One Bidder";
+      name: "revealReserve",
+      accounts: [
+        {
+          name: "creator",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "reservePrice",
+          type: "u64",
+        },
+        {
+          name: "salt",
+          type: {
+            array: ["u8", 32],
+          },
+        },
+      ],
     },
     {
-      code: 6030;
-      name: "OutBidderMismatch";
-      msg: "Out Bidder Account Mismatch With LastBidder Data";
+      name: "listPnftForSale",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMintAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required for the listings priced in SPL Token"],
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "sellBump",
+          type: "u8",
+        },
+        {
+          name: "auctionBump",
+          type: "u8",
+        },
+        {
+          name: "priceSol",
+          type: "u64",
+        },
+        {
+          name: "paymentMint",
+          type: "publicKey",
+        },
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+        {
+          name: "reservedBuyer",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "dutch",
+          type: {
+            option: {
+              defined: "DutchSchedule",
+            },
+          },
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6031;
-      name: "NotEndedAuction";
-      msg: "Claiming Auction For Not Ended Auction";
+      name: "delistPnft",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "sellBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6032;
-      name: "CreatorAccountMismatch";
-      msg: "Creator Account Mismatch with Auction Data";
+      name: "delistExpiredPnft",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "sellBump",
+          type: "u8",
+        },
+      ],
     },
     {
-      code: 6033;
-      name: "BidderAccountMismatch";
-      msg: "Bidder Account Mismatch with Auction Data";
+      name: "purchasePnft",
+      accounts: [
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: false,
+          isSigner: false,
+          docs: ["NFT receiver, same as the buyer for the normal purchase"],
+        },
+        {
+          name: "recipientUserPool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required to credit the traded volume to the recipient"],
+        },
+        {
+          name: "userNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required for the listings priced in SPL Token"],
+        },
+        {
+          name: "sellerPaymentTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required for the listings priced in SPL Token"],
+        },
+        {
+          name: "paymentTokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only required for the listings priced in SPL Token",
+            "NFT token program can be Token-2022 so the payment goes through its own program",
+          ],
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: ["Only required for the purchase paid from the escrow balance"],
+        },
+        {
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "nftBump",
+          type: "u8",
+        },
+        {
+          name: "sellerBump",
+          type: "u8",
+        },
+        {
+          name: "buyerBump",
+          type: "u8",
+        },
+        {
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
+          type: "u64",
+        },
+        {
+          name: "fromEscrow",
+          type: "bool",
+        },
+        {
+          name: "escrowBump",
+          type: "u8",
+        },
+        {
+          name: "creditRecipient",
+          type: "bool",
+        },
+      ],
     },
     {
-      code: 6034;
-      name: "AuctionHasBid";
-      msg: "Canceling Auction which has Bid";
+      name: "listCnftForSale",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeConfig",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied",
+          ],
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong tree is supplied"],
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "priceSol",
+          type: "u64",
+        },
+        {
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
+        },
+        {
+          name: "metadata",
+          type: {
+            defined: "MetadataArgs",
+          },
+        },
+        {
+          name: "leaf",
+          type: {
+            defined: "CNftLeaf",
+          },
+        },
+      ],
     },
     {
-      code: 6035;
-      name: "BidFromAuctionCreator";
-      msg: "Placing Bid From Auction Creator";
+      name: "delistCnft",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeConfig",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied",
+          ],
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong tree is supplied"],
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "metadata",
+          type: {
+            defined: "MetadataArgs",
+          },
+        },
+        {
+          name: "leaf",
+          type: {
+            defined: "CNftLeaf",
+          },
+        },
+      ],
     },
     {
-      code: 6036;
-      name: "ListingNotAvailable";
-      msg: "Only Listing and Reserved Auction are possible to exist together";
+      name: "purchaseCnft",
+      accounts: [
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeConfig",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong tree config is supplied",
+          ],
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong tree is supplied"],
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
+          type: "u64",
+        },
+        {
+          name: "metadata",
+          type: {
+            defined: "MetadataArgs",
+          },
+        },
+        {
+          name: "leaf",
+          type: {
+            defined: "CNftLeaf",
+          },
+        },
+      ],
     },
     {
-      code: 6037;
-      name: "NFTIsNotInUserATA";
-      msg: "NFT Is Not In User ATA";
+      name: "listBundleForSale",
+      accounts: [
+        {
+          name: "owner",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "bundleDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "globalBump",
+          type: "u8",
+        },
+        {
+          name: "bundleId",
+          type: "u64",
+        },
+        {
+          name: "priceSol",
+          type: "u64",
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
+        },
+      ],
     },
     {
-      code: 6038;
-      name: "NFTIsNotInEscrowATA";
-      msg: "NFT Is Not In Escrow ATA";
-    }
-  ];
-  metadata: {
-    address: "5J3fJvN67uWLo2uNaygTJjdRoJs5mxn9XgtXroiQkcwm";
-  };
-};
-
-export const IDL: MugsMarketplace = {
-  version: "0.1.0",
-  name: "mugs_marketplace",
-  instructions: [
-    {
-      name: "initialize",
+      name: "delistBundle",
       accounts: [
         {
-          name: "admin",
+          name: "owner",
           isMut: true,
           isSigner: true,
         },
         {
           name: "globalAuthority",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "escrowVault",
+          name: "bundleDataInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
@@ -1882,25 +7354,81 @@ export const IDL: MugsMarketplace = {
           name: "globalBump",
           type: "u8",
         },
-        {
-          name: "escrowBump",
-          type: "u8",
-        },
       ],
     },
     {
-      name: "updateFee",
+      name: "purchaseBundle",
       accounts: [
         {
-          name: "admin",
+          name: "buyer",
           isMut: true,
           isSigner: true,
         },
         {
           name: "globalAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "bundleDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerUserPool",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
@@ -1908,21 +7436,142 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "solFee",
+          name: "expectedPrice",
           type: "u64",
         },
-      ],
-    },
-    {
-      name: "addTeamTreasury",
-      accounts: [
         {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
+          name: "expectedRoyalty",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "acceptOfferPnft",
+      accounts: [
+        {
+          name: "seller",
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Signs when accepting an offer, the buyer signs when accepting a counter offer",
+          ],
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "offerDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyerUserPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
+        },
+        {
+          name: "tokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "destTokenMintRecord",
+          isMut: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
+        },
+        {
+          name: "authRules",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authRulesProgram",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "globalAuthority",
+          name: "auctionDataInfo",
           isMut: true,
           isSigner: false,
         },
@@ -1933,81 +7582,70 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "address",
-          type: "publicKey",
+          name: "nftBump",
+          type: "u8",
         },
         {
-          name: "rate",
-          type: "u64",
+          name: "offerBump",
+          type: "u8",
         },
-      ],
-    },
-    {
-      name: "removeTeamTreasury",
-      accounts: [
         {
-          name: "admin",
-          isMut: true,
-          isSigner: true,
+          name: "buyerBump",
+          type: "u8",
         },
         {
-          name: "globalAuthority",
-          isMut: true,
-          isSigner: false,
+          name: "sellerBump",
+          type: "u8",
         },
-      ],
-      args: [
         {
-          name: "globalBump",
+          name: "escrowBump",
           type: "u8",
         },
         {
-          name: "address",
-          type: "publicKey",
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
+          type: "u64",
         },
       ],
     },
     {
-      name: "initUserPool",
+      name: "rejectOffer",
       accounts: [
         {
-          name: "owner",
+          name: "seller",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "userPool",
+          name: "globalAuthority",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "buyer",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "rent",
-          isMut: false,
+          name: "offerDataInfo",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "bump",
-          type: "u8",
+          name: "sellerNftTokenAccount",
+          isMut: false,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "initSellData",
-      accounts: [
         {
-          name: "payer",
+          name: "userPool",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "sellDataInfo",
+          name: "escrowVault",
           isMut: true,
           isSigner: false,
         },
@@ -2016,216 +7654,174 @@ export const IDL: MugsMarketplace = {
           isMut: false,
           isSigner: false,
         },
-        {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
-        },
       ],
       args: [
         {
-          name: "nft",
-          type: "publicKey",
-        },
-        {
-          name: "bump",
+          name: "escrowBump",
           type: "u8",
         },
       ],
     },
     {
-      name: "setPrice",
+      name: "counterOffer",
       accounts: [
         {
-          name: "owner",
-          isMut: true,
+          name: "seller",
+          isMut: false,
           isSigner: true,
         },
         {
           name: "sellDataInfo",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "nftMint",
-          isMut: false,
+          name: "offerDataInfo",
+          isMut: true,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "sellBump",
-          type: "u8",
-        },
-        {
-          name: "price",
+          name: "counterPrice",
           type: "u64",
         },
       ],
     },
     {
-      name: "depositToEscrow",
+      name: "acceptCounterOffer",
       accounts: [
         {
-          name: "owner",
+          name: "seller",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+          docs: [
+            "Signs when accepting an offer, the buyer signs when accepting a counter offer",
+          ],
         },
         {
-          name: "userPool",
+          name: "sellDataInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "escrowVault",
+          name: "buyer",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "offerDataInfo",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [
-        {
-          name: "userBump",
-          type: "u8",
-        },
         {
-          name: "escrowBump",
-          type: "u8",
+          name: "sellerUserPool",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "sol",
-          type: "u64",
+          name: "nftMint",
+          isMut: false,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "withdrawFromEscrow",
-      accounts: [
         {
-          name: "owner",
+          name: "globalAuthority",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "userPool",
+          name: "buyerUserPool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "escrowVault",
+          name: "userNftTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "destNftTokenAccount",
+          isMut: true,
           isSigner: false,
         },
-      ],
-      args: [
-        {
-          name: "userBump",
-          type: "u8",
-        },
-        {
-          name: "escrowBump",
-          type: "u8",
-        },
-        {
-          name: "sol",
-          type: "u64",
-        },
-      ],
-    },
-    {
-      name: "initOfferData",
-      accounts: [
         {
-          name: "payer",
+          name: "escrowVault",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "offerDataInfo",
+          name: "mintMetadata",
           isMut: true,
           isSigner: false,
+          docs: ["the mint metadata"],
         },
         {
-          name: "systemProgram",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "tokenMintEdition",
           isMut: false,
           isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
         },
-      ],
-      args: [
-        {
-          name: "nft",
-          type: "publicKey",
-        },
-        {
-          name: "bump",
-          type: "u8",
-        },
-      ],
-    },
-    {
-      name: "makeOffer",
-      accounts: [
         {
-          name: "owner",
+          name: "tokenMintRecord",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
         },
         {
-          name: "sellDataInfo",
+          name: "destTokenMintRecord",
           isMut: true,
           isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
         },
         {
-          name: "offerDataInfo",
-          isMut: true,
+          name: "authRules",
+          isMut: false,
           isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
         },
         {
-          name: "nftMint",
+          name: "sysvarInstructions",
           isMut: false,
           isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
         },
         {
-          name: "userPool",
-          isMut: true,
+          name: "associatedTokenProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "escrowVault",
-          isMut: true,
+          name: "authRulesProgram",
+          isMut: false,
           isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
         },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "sellBump",
-          type: "u8",
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "offerBump",
-          type: "u8",
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "userBump",
+          name: "globalBump",
           type: "u8",
         },
         {
@@ -2233,36 +7829,50 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "price",
+          name: "expectedPrice",
+          type: "u64",
+        },
+        {
+          name: "expectedRoyalty",
           type: "u64",
         },
       ],
     },
     {
-      name: "cancelOffer",
+      name: "claimAuctionPnft",
       accounts: [
         {
-          name: "owner",
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalAuthority",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "offerDataInfo",
+          name: "auctionDataInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "userPool",
+          name: "userTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
+          name: "destNftTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "escrowVault",
+          name: "creator",
           isMut: true,
           isSigner: false,
         },
@@ -2271,148 +7881,136 @@ export const IDL: MugsMarketplace = {
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "offerBump",
-          type: "u8",
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
         },
         {
-          name: "escrowBump",
-          type: "u8",
+          name: "prevBidder",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+          docs: [
+            "Only required when the auction keeps an outbid bid to refund",
+          ],
         },
         {
-          name: "userBump",
-          type: "u8",
+          name: "prevBidderUserPool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
         },
-      ],
-    },
-    {
-      name: "initAuctionData",
-      accounts: [
         {
-          name: "payer",
+          name: "bidderUserPool",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "auctionDataInfo",
+          name: "creatorUserPool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "mintMetadata",
+          isMut: true,
+          isSigner: false,
+          docs: ["the mint metadata"],
+        },
+        {
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rent",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "nft",
-          type: "publicKey",
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
-          name: "bump",
-          type: "u8",
+          name: "tokenMintEdition",
+          isMut: false,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
         },
-      ],
-    },
-    {
-      name: "placeBid",
-      accounts: [
         {
-          name: "bidder",
+          name: "tokenMintRecord",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
         },
         {
-          name: "auctionDataInfo",
+          name: "destTokenMintRecord",
           isMut: true,
           isSigner: false,
+          docs: ["CHECK instruction will fail if wrong record is supplied"],
         },
         {
-          name: "nftMint",
+          name: "authRules",
           isMut: false,
           isSigner: false,
+          docs: ["CHECK instruction will fail if wrong rules are supplied"],
         },
         {
-          name: "escrowVault",
-          isMut: true,
+          name: "sysvarInstructions",
+          isMut: false,
           isSigner: false,
+          docs: [
+            "CHECK instruction will fail if wrong sysvar ixns are supplied",
+          ],
         },
         {
-          name: "outBidder",
-          isMut: true,
+          name: "associatedTokenProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "authRulesProgram",
           isMut: false,
           isSigner: false,
+          docs: ["CHECK intstruction will fail if wrong program is supplied"],
         },
         {
-          name: "sellDataInfo",
+          name: "globalNftTokenAccount",
           isMut: true,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "auctionBump",
+          name: "globalBump",
           type: "u8",
         },
         {
-          name: "escrowBump",
+          name: "auctionBump",
           type: "u8",
         },
         {
-          name: "price",
-          type: "u64",
+          name: "escrowBump",
+          type: "u8",
         },
       ],
     },
     {
-      name: "updateReserve",
+      name: "cancelAuctionPnft",
       accounts: [
         {
-          name: "creator",
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "auctionDataInfo",
+          name: "creator",
           isMut: true,
           isSigner: false,
-        },
-        {
-          name: "nftMint",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "auctionBump",
-          type: "u8",
-        },
-        {
-          name: "price",
-          type: "u64",
-        },
-      ],
-    },
-    {
-      name: "listPnftForSale",
-      accounts: [
-        {
-          name: "owner",
-          isMut: true,
-          isSigner: true,
+          docs: [
+            "Signs when canceling the auction, not required for the crank",
+          ],
         },
         {
           name: "globalAuthority",
@@ -2420,7 +8018,7 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "sellDataInfo",
+          name: "auctionDataInfo",
           isMut: true,
           isSigner: false,
         },
@@ -2450,16 +8048,6 @@ export const IDL: MugsMarketplace = {
           isMut: false,
           isSigner: false,
         },
-        {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "tokenMintEdition",
           isMut: false,
@@ -2509,7 +8097,17 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "auctionDataInfo",
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sellDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "globalNftTokenAccount",
           isMut: true,
           isSigner: false,
         },
@@ -2520,21 +8118,54 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "sellBump",
+          name: "auctionBump",
           type: "u8",
         },
+      ],
+    },
+    {
+      name: "cancelBid",
+      accounts: [
         {
-          name: "auctionBump",
-          type: "u8",
+          name: "bidder",
+          isMut: true,
+          isSigner: true,
         },
         {
-          name: "priceSol",
-          type: "u64",
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auctionDataInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "escrowBump",
+          type: "u8",
         },
       ],
     },
     {
-      name: "delistPnft",
+      name: "createAuctionPnft",
       accounts: [
         {
           name: "owner",
@@ -2547,7 +8178,7 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "sellDataInfo",
+          name: "auctionDataInfo",
           isMut: true,
           isSigner: false,
         },
@@ -2577,6 +8208,16 @@ export const IDL: MugsMarketplace = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "tokenMintEdition",
           isMut: false,
@@ -2626,12 +8267,7 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "auctionDataInfo",
+          name: "sellDataInfo",
           isMut: true,
           isSigner: false,
         },
@@ -2641,52 +8277,81 @@ export const IDL: MugsMarketplace = {
           name: "globalBump",
           type: "u8",
         },
+        {
+          name: "auctionBump",
+          type: "u8",
+        },
         {
           name: "sellBump",
           type: "u8",
         },
+        {
+          name: "startPrice",
+          type: "u64",
+        },
+        {
+          name: "minIncrease",
+          type: "u64",
+        },
+        {
+          name: "duration",
+          type: "i64",
+        },
+        {
+          name: "reserved",
+          type: "u8",
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
+        },
+        {
+          name: "extensionWindow",
+          type: "i64",
+        },
+        {
+          name: "extensionLength",
+          type: "i64",
+        },
+        {
+          name: "reservePrice",
+          type: "u64",
+        },
+        {
+          name: "reserveHash",
+          type: {
+            option: {
+              array: ["u8", 32],
+            },
+          },
+        },
       ],
     },
     {
-      name: "purchasePnft",
+      name: "createSealedAuctionPnft",
       accounts: [
         {
-          name: "buyer",
+          name: "owner",
           isMut: true,
           isSigner: true,
         },
         {
           name: "globalAuthority",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "sellDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "buyerUserPool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userNftTokenAccount",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "destNftTokenAccount",
+          name: "sealedAuctionInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "seller",
-          isMut: true,
+          name: "sellDataInfo",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "sellerUserPool",
+          name: "userTokenAccount",
           isMut: true,
           isSigner: false,
         },
@@ -2707,19 +8372,18 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "tokenMintEdition",
+          name: "tokenMetadataProgram",
           isMut: false,
           isSigner: false,
-          docs: ["CHECK instruction will fail if wrong edition is supplied"],
         },
         {
-          name: "tokenMintRecord",
-          isMut: true,
+          name: "tokenMintEdition",
+          isMut: false,
           isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
+          docs: ["CHECK instruction will fail if wrong edition is supplied"],
         },
         {
-          name: "destTokenMintRecord",
+          name: "tokenMintRecord",
           isMut: true,
           isSigner: false,
           docs: ["CHECK instruction will fail if wrong record is supplied"],
@@ -2738,11 +8402,6 @@ export const IDL: MugsMarketplace = {
             "CHECK instruction will fail if wrong sysvar ixns are supplied",
           ],
         },
-        {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "authRulesProgram",
           isMut: false,
@@ -2755,15 +8414,10 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "tokenMetadataProgram",
+          name: "rent",
           isMut: false,
           isSigner: false,
         },
-        {
-          name: "auctionDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
       ],
       args: [
         {
@@ -2771,69 +8425,52 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "nftBump",
-          type: "u8",
+          name: "minPrice",
+          type: "u64",
         },
         {
-          name: "sellerBump",
-          type: "u8",
+          name: "commitDuration",
+          type: "i64",
         },
         {
-          name: "buyerBump",
-          type: "u8",
+          name: "revealDuration",
+          type: "i64",
+        },
+        {
+          name: "priceRule",
+          type: "u64",
+        },
+        {
+          name: "pricingMode",
+          type: "u64",
         },
       ],
     },
     {
-      name: "acceptOfferPnft",
+      name: "commitSealedBid",
       accounts: [
         {
-          name: "seller",
+          name: "bidder",
           isMut: true,
           isSigner: true,
         },
-        {
-          name: "sellDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "buyer",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "offerDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "sellerUserPool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "nftMint",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "globalAuthority",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "buyerUserPool",
+          name: "sealedAuctionInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "userNftTokenAccount",
+          name: "sealedBidInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "destNftTokenAccount",
+          name: "bidderUserPool",
           isMut: true,
           isSigner: false,
         },
@@ -2843,107 +8480,66 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "mintMetadata",
-          isMut: true,
-          isSigner: false,
-          docs: ["the mint metadata"],
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMintEdition",
-          isMut: false,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong edition is supplied"],
-        },
-        {
-          name: "tokenMintRecord",
-          isMut: true,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
-        },
-        {
-          name: "destTokenMintRecord",
-          isMut: true,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
-        },
-        {
-          name: "authRules",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
-          docs: ["CHECK instruction will fail if wrong rules are supplied"],
         },
         {
-          name: "sysvarInstructions",
+          name: "rent",
           isMut: false,
           isSigner: false,
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied",
-          ],
         },
+      ],
+      args: [
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "bidHash",
+          type: {
+            array: ["u8", 32],
+          },
         },
         {
-          name: "authRulesProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["CHECK intstruction will fail if wrong program is supplied"],
+          name: "deposit",
+          type: "u64",
         },
+      ],
+    },
+    {
+      name: "revealSealedBid",
+      accounts: [
         {
-          name: "systemProgram",
+          name: "bidder",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
+          name: "sealedAuctionInfo",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "auctionDataInfo",
+          name: "sealedBidInfo",
           isMut: true,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "globalBump",
-          type: "u8",
-        },
-        {
-          name: "nftBump",
-          type: "u8",
-        },
-        {
-          name: "offerBump",
-          type: "u8",
-        },
-        {
-          name: "buyerBump",
-          type: "u8",
-        },
-        {
-          name: "sellerBump",
-          type: "u8",
+          name: "amount",
+          type: "u64",
         },
         {
-          name: "escrowBump",
-          type: "u8",
+          name: "salt",
+          type: {
+            array: ["u8", 32],
+          },
         },
       ],
     },
     {
-      name: "claimAuctionPnft",
+      name: "settleSealedAuction",
       accounts: [
         {
-          name: "bidder",
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
@@ -2953,17 +8549,17 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "auctionDataInfo",
+          name: "sealedAuctionInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "userTokenAccount",
+          name: "winner",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "destNftTokenAccount",
+          name: "winnerBidInfo",
           isMut: true,
           isSigner: false,
         },
@@ -2972,6 +8568,16 @@ export const IDL: MugsMarketplace = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destNftTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "nftMint",
           isMut: false,
@@ -2983,7 +8589,7 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "bidderUserPool",
+          name: "winnerUserPool",
           isMut: true,
           isSigner: false,
         },
@@ -3063,9 +8669,56 @@ export const IDL: MugsMarketplace = {
           type: "u8",
         },
         {
-          name: "auctionBump",
+          name: "escrowBump",
           type: "u8",
         },
+      ],
+    },
+    {
+      name: "refundSealedBid",
+      accounts: [
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "globalAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sealedAuctionInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bidder",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sealedBidInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrowVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
         {
           name: "escrowBump",
           type: "u8",
@@ -3073,31 +8726,34 @@ export const IDL: MugsMarketplace = {
       ],
     },
     {
-      name: "cancelAuctionPnft",
+      name: "cancelSealedAuctionPnft",
       accounts: [
         {
-          name: "creator",
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "globalAuthority",
+          name: "creator",
           isMut: true,
           isSigner: false,
+          docs: [
+            "Signs when canceling the auction, not required for the crank",
+          ],
         },
         {
-          name: "auctionDataInfo",
-          isMut: true,
+          name: "globalAuthority",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "userTokenAccount",
+          name: "sealedAuctionInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "destNftTokenAccount",
-          isMut: false,
+          name: "userTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
@@ -3128,12 +8784,6 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
           docs: ["CHECK instruction will fail if wrong record is supplied"],
         },
-        {
-          name: "destTokenMintRecord",
-          isMut: true,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
-        },
         {
           name: "authRules",
           isMut: false,
@@ -3148,11 +8798,6 @@ export const IDL: MugsMarketplace = {
             "CHECK instruction will fail if wrong sysvar ixns are supplied",
           ],
         },
-        {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
         {
           name: "authRulesProgram",
           isMut: false,
@@ -3170,305 +8815,670 @@ export const IDL: MugsMarketplace = {
           isSigner: false,
         },
         {
-          name: "sellDataInfo",
+          name: "globalNftTokenAccount",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
       ],
       args: [
         {
           name: "globalBump",
           type: "u8",
         },
+      ],
+    },
+    {
+      name: "closeSealedAuction",
+      accounts: [
         {
-          name: "auctionBump",
-          type: "u8",
+          name: "creator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sealedAuctionInfo",
+          isMut: true,
+          isSigner: false,
         },
       ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "GlobalPool",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "superAdmin",
+            type: "publicKey",
+          },
+          {
+            name: "marketFeeSol",
+            type: "u64",
+          },
+          {
+            name: "teamCount",
+            type: "u64",
+          },
+          {
+            name: "teamTreasury",
+            type: {
+              array: ["publicKey", 8],
+            },
+          },
+          {
+            name: "treasuryRate",
+            type: {
+              array: ["u64", 8],
+            },
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+          {
+            name: "escrowLiabilities",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "SellData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "seller",
+            type: "publicKey",
+          },
+          {
+            name: "collection",
+            type: "publicKey",
+          },
+          {
+            name: "priceSol",
+            type: "u64",
+          },
+          {
+            name: "listedDate",
+            type: "i64",
+          },
+          {
+            name: "active",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "collectionSource",
+            type: "u64",
+          },
+          {
+            name: "merkleTree",
+            type: "publicKey",
+          },
+          {
+            name: "leafNonce",
+            type: "u64",
+          },
+          {
+            name: "reservedBuyer",
+            type: "publicKey",
+          },
+          {
+            name: "dutchFloorPrice",
+            type: "u64",
+          },
+          {
+            name: "dutchDuration",
+            type: "i64",
+          },
+          {
+            name: "dutchStep",
+            type: "i64",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "BundleData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "seller",
+            type: "publicKey",
+          },
+          {
+            name: "bundleId",
+            type: "u64",
+          },
+          {
+            name: "priceSol",
+            type: "u64",
+          },
+          {
+            name: "listedDate",
+            type: "i64",
+          },
+          {
+            name: "itemCount",
+            type: "u64",
+          },
+          {
+            name: "mints",
+            type: {
+              array: ["publicKey", 5],
+            },
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "OfferData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "offerPrice",
+            type: "u64",
+          },
+          {
+            name: "offerListingDate",
+            type: "i64",
+          },
+          {
+            name: "active",
+            type: "u64",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "counterPrice",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "CollectionOfferData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "collection",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "quantity",
+            type: "u64",
+          },
+          {
+            name: "createdDate",
+            type: "i64",
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+          {
+            name: "marketFeeSol",
+            type: "u64",
+          },
+          {
+            name: "royaltyBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "AuctionData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "startPrice",
+            type: "u64",
+          },
+          {
+            name: "minIncreaseAmount",
+            type: "u64",
+          },
+          {
+            name: "startDate",
+            type: "i64",
+          },
+          {
+            name: "lastBidDate",
+            type: "i64",
+          },
+          {
+            name: "lastBidder",
+            type: "publicKey",
+          },
+          {
+            name: "highestBid",
+            type: "u64",
+          },
+          {
+            name: "duration",
+            type: "i64",
+          },
+          {
+            name: "status",
+            type: "u64",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+          {
+            name: "extensionWindow",
+            type: "i64",
+          },
+          {
+            name: "extensionLength",
+            type: "i64",
+          },
+          {
+            name: "endDate",
+            type: "i64",
+          },
+          {
+            name: "reservePrice",
+            type: "u64",
+          },
+          {
+            name: "reserveHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "prevBidder",
+            type: "publicKey",
+          },
+          {
+            name: "prevBid",
+            type: "u64",
+          },
+          {
+            name: "marketFeeSol",
+            type: "u64",
+          },
+          {
+            name: "royaltyBasisPoints",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u64",
+          },
+        ],
+      },
     },
     {
-      name: "createAuctionPnft",
-      accounts: [
-        {
-          name: "owner",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "globalAuthority",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "auctionDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "destNftTokenAccount",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "nftMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "mintMetadata",
-          isMut: true,
-          isSigner: false,
-          docs: ["the mint metadata"],
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenMintEdition",
-          isMut: false,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong edition is supplied"],
-        },
-        {
-          name: "tokenMintRecord",
-          isMut: true,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
-        },
-        {
-          name: "destTokenMintRecord",
-          isMut: true,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong record is supplied"],
-        },
-        {
-          name: "authRules",
-          isMut: false,
-          isSigner: false,
-          docs: ["CHECK instruction will fail if wrong rules are supplied"],
-        },
-        {
-          name: "sysvarInstructions",
-          isMut: false,
-          isSigner: false,
-          docs: [
-            "CHECK instruction will fail if wrong sysvar ixns are supplied",
-          ],
-        },
-        {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "authRulesProgram",
-          isMut: false,
-          isSigner: false,
-          docs: ["CHECK intstruction will fail if wrong program is supplied"],
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "sellDataInfo",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "globalBump",
-          type: "u8",
-        },
-        {
-          name: "auctionBump",
-          type: "u8",
-        },
-        {
-          name: "sellBump",
-          type: "u8",
-        },
-        {
-          name: "startPrice",
-          type: "u64",
-        },
-        {
-          name: "minIncrease",
-          type: "u64",
-        },
-        {
-          name: "duration",
-          type: "i64",
-        },
-        {
-          name: "reserved",
-          type: "u8",
-        },
-      ],
+      name: "SealedAuctionData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "minPrice",
+            type: "u64",
+          },
+          {
+            name: "commitEndDate",
+            type: "i64",
+          },
+          {
+            name: "revealEndDate",
+            type: "i64",
+          },
+          {
+            name: "priceRule",
+            type: "u64",
+          },
+          {
+            name: "highestBidder",
+            type: "publicKey",
+          },
+          {
+            name: "highestBid",
+            type: "u64",
+          },
+          {
+            name: "secondBid",
+            type: "u64",
+          },
+          {
+            name: "openBids",
+            type: "u64",
+          },
+          {
+            name: "status",
+            type: "u64",
+          },
+          {
+            name: "pricingMode",
+            type: "u64",
+          },
+          {
+            name: "marketFeeSol",
+            type: "u64",
+          },
+          {
+            name: "royaltyBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: "GlobalPool",
+      name: "SealedBidData",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "superAdmin",
+            name: "mint",
             type: "publicKey",
           },
           {
-            name: "marketFeeSol",
-            type: "u64",
-          },
-          {
-            name: "teamCount",
-            type: "u64",
+            name: "bidder",
+            type: "publicKey",
           },
           {
-            name: "teamTreasury",
+            name: "bidHash",
             type: {
-              array: ["publicKey", 8],
+              array: ["u8", 32],
             },
           },
           {
-            name: "treasuryRate",
-            type: {
-              array: ["u64", 8],
-            },
+            name: "deposit",
+            type: "u64",
+          },
+          {
+            name: "revealedBid",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "SellData",
+      name: "UserData",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mint",
+            name: "address",
             type: "publicKey",
           },
           {
-            name: "seller",
-            type: "publicKey",
+            name: "tradedVolume",
+            type: "u64",
           },
           {
-            name: "collection",
-            type: "publicKey",
+            name: "escrowSolBalance",
+            type: "u64",
           },
           {
-            name: "priceSol",
+            name: "lockedSolBalance",
             type: "u64",
           },
           {
-            name: "listedDate",
-            type: "i64",
+            name: "version",
+            type: "u64",
           },
           {
-            name: "active",
+            name: "legacyBalance",
             type: "u64",
           },
         ],
       },
     },
+  ],
+  types: [
     {
-      name: "OfferData",
+      name: "CNftLeaf",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mint",
-            type: "publicKey",
+            name: "root",
+            type: {
+              array: ["u8", 32],
+            },
           },
           {
-            name: "buyer",
-            type: "publicKey",
+            name: "nonce",
+            type: "u64",
           },
           {
-            name: "offerPrice",
+            name: "index",
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "DutchSchedule",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "floorPrice",
             type: "u64",
           },
           {
-            name: "offerListingDate",
+            name: "duration",
             type: "i64",
           },
           {
-            name: "active",
-            type: "u64",
+            name: "step",
+            type: "i64",
           },
         ],
       },
     },
     {
-      name: "AuctionData",
+      name: "SaleQuote",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mint",
-            type: "publicKey",
+            name: "price",
+            type: "u64",
           },
           {
-            name: "creator",
-            type: "publicKey",
+            name: "pricingMode",
+            type: "u64",
           },
           {
-            name: "startPrice",
+            name: "marketFee",
             type: "u64",
           },
           {
-            name: "minIncreaseAmount",
+            name: "royalty",
             type: "u64",
           },
           {
-            name: "startDate",
-            type: "i64",
+            name: "sellerProceeds",
+            type: "u64",
           },
           {
-            name: "lastBidDate",
-            type: "i64",
+            name: "buyerTotal",
+            type: "u64",
           },
+        ],
+      },
+    },
+    {
+      name: "NftStandard",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "lastBidder",
-            type: "publicKey",
+            name: "Programmable",
           },
           {
-            name: "highestBid",
-            type: "u64",
+            name: "Legacy",
           },
           {
-            name: "duration",
-            type: "i64",
+            name: "Token2022",
+          },
+        ],
+      },
+    },
+    {
+      name: "MetadataArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
           },
           {
-            name: "status",
-            type: "u64",
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "sellerFeeBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "primarySaleHappened",
+            type: "bool",
+          },
+          {
+            name: "isMutable",
+            type: "bool",
+          },
+          {
+            name: "editionNonce",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "tokenStandard",
+            type: {
+              option: {
+                defined: "TokenStandard",
+              },
+            },
+          },
+          {
+            name: "collection",
+            type: {
+              option: {
+                defined: "Collection",
+              },
+            },
+          },
+          {
+            name: "uses",
+            type: {
+              option: {
+                defined: "Uses",
+              },
+            },
+          },
+          {
+            name: "tokenProgramVersion",
+            type: {
+              defined: "TokenProgramVersion",
+            },
+          },
+          {
+            name: "creators",
+            type: {
+              vec: {
+                defined: "Creator",
+              },
+            },
           },
         ],
       },
     },
     {
-      name: "UserData",
+      name: "Creator",
       type: {
         kind: "struct",
         fields: [
@@ -3477,16 +9487,105 @@ export const IDL: MugsMarketplace = {
             type: "publicKey",
           },
           {
-            name: "tradedVolume",
+            name: "verified",
+            type: "bool",
+          },
+          {
+            name: "share",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "Collection",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "verified",
+            type: "bool",
+          },
+          {
+            name: "key",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "Uses",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "useMethod",
+            type: {
+              defined: "UseMethod",
+            },
+          },
+          {
+            name: "remaining",
             type: "u64",
           },
           {
-            name: "escrowSolBalance",
+            name: "total",
             type: "u64",
           },
         ],
       },
     },
+    {
+      name: "UseMethod",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Burn",
+          },
+          {
+            name: "Multiple",
+          },
+          {
+            name: "Single",
+          },
+        ],
+      },
+    },
+    {
+      name: "TokenStandard",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "NonFungible",
+          },
+          {
+            name: "FungibleAsset",
+          },
+          {
+            name: "Fungible",
+          },
+          {
+            name: "NonFungibleEdition",
+          },
+        ],
+      },
+    },
+    {
+      name: "TokenProgramVersion",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Original",
+          },
+          {
+            name: "Token2022",
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
@@ -3684,6 +9783,141 @@ export const IDL: MugsMarketplace = {
       name: "NFTIsNotInEscrowATA",
       msg: "NFT Is Not In Escrow ATA",
     },
+    {
+      code: 6039,
+      name: "InvalidPaymentTokenAccount",
+      msg: "Payment Token Account Mismatch with Listing Payment Mint",
+    },
+    {
+      code: 6040,
+      name: "OfferForTokenListing",
+      msg: "Offer Is Only Available For SOL Listing",
+    },
+    {
+      code: 6041,
+      name: "AccountAlreadyMigrated",
+      msg: "Data Account Is Already Migrated",
+    },
+    {
+      code: 6042,
+      name: "ExpiredListing",
+      msg: "The NFT Listing Is Expired",
+    },
+    {
+      code: 6043,
+      name: "NotExpiredListing",
+      msg: "The NFT Listing Is Not Expired Yet",
+    },
+    {
+      code: 6044,
+      name: "TradeTermsMismatch",
+      msg: "Trade Price Or Royalty Mismatch with Expected Terms",
+    },
+    {
+      code: 6045,
+      name: "ReservedListing",
+      msg: "The NFT Listing Is Reserved For Another Buyer",
+    },
+    {
+      code: 6046,
+      name: "InvalidRentPayer",
+      msg: "Signer Is Not The Rent Payer Of The PDA",
+    },
+    {
+      code: 6047,
+      name: "DataAccountInUse",
+      msg: "The PDA Is Still In Use",
+    },
+    {
+      code: 6048,
+      name: "CreatorAddressMismatch",
+      msg: "Remaining Account Mismatch with The NFT Creator",
+    },
+    {
+      code: 6049,
+      name: "BundleAccountsMismatch",
+      msg: "Remaining Accounts Mismatch with The Bundle Items",
+    },
+    {
+      code: 6050,
+      name: "InvalidBundleItem",
+      msg: "Bundle Item Should Be A Programmable NFT Held By The Seller",
+    },
+    {
+      code: 6051,
+      name: "CollectionMismatch",
+      msg: "The NFT Is Not A Verified Member Of The Offer Collection",
+    },
+    {
+      code: 6052,
+      name: "ExpiredOffer",
+      msg: "The Offer Is Expired",
+    },
+    {
+      code: 6053,
+      name: "NotRefundableOffer",
+      msg: "The Offer Is Still Valid",
+    },
+    {
+      code: 6054,
+      name: "EscrowShortfall",
+      msg: "Escrow Vault Balance Is Below The Escrow Liabilities",
+    },
+    {
+      code: 6055,
+      name: "InvalidReserveReveal",
+      msg: "Revealed Reserve Price Doesn't Match The Commitment",
+    },
+    {
+      code: 6056,
+      name: "ReserveNotRevealed",
+      msg: "Hidden Reserve Is Still In The Reveal Period",
+    },
+    {
+      code: 6057,
+      name: "BidRetractionClosed",
+      msg: "Bid Retraction Window Is Closed",
+    },
+    {
+      code: 6058,
+      name: "InvalidSealedPhase",
+      msg: "Sealed Bid Auction Is Not In This Phase",
+    },
+    {
+      code: 6059,
+      name: "InvalidBidReveal",
+      msg: "Revealed Bid Doesn't Match The Commitment",
+    },
+    {
+      code: 6060,
+      name: "WinningSealedBid",
+      msg: "Winning Sealed Bid Is Settled With The Auction",
+    },
+    {
+      code: 6061,
+      name: "InvalidTokenStandard",
+      msg: "Token Standard Is Not A Non Fungible Standard",
+    },
+    {
+      code: 6062,
+      name: "MathOverflow",
+      msg: "Amount Overflow",
+    },
+    {
+      code: 6063,
+      name: "InvalidPaymentMint",
+      msg: "Payment Mint Should Be A SPL Token Mint",
+    },
+    {
+      code: 6064,
+      name: "PaymentAccountNotInitialized",
+      msg: "Payment Token Account Is Not Initialized",
+    },
+    {
+      code: 6065,
+      name: "DuplicateUserAccount",
+      msg: "Same User Data Account Can Not Be Used Twice",
+    },
   ],
   metadata: {
     address: "5J3fJvN67uWLo2uNaygTJjdRoJs5mxn9XgtXroiQkcwm",
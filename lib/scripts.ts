@@ -27,6 +27,19 @@ import {
   AUCTION_DATA_SEED,
   AuctionData,
   AUCTION_DATA_SIZE,
+  PERMYRIAD,
+  DutchSchedule,
+  PRICING_MODE_DEFAULT,
+  PRICING_MODE_FEE_ON_TOP,
+  PRICING_MODE_FEE_INCLUSIVE,
+  SaleQuote,
+  BUNDLE_DATA_SEED,
+  BundleData,
+  COLLECTION_OFFER_SEED,
+  CollectionOfferData,
+  SEALED_AUCTION_SEED,
+  SEALED_BID_SEED,
+  SealedAuctionData,
 } from "./types";
 import {
   getAssociatedTokenAccount,
@@ -41,6 +54,7 @@ import {
   txWithComputeUnitsIxs,
 } from "./utils";
 import { programs } from "@metaplex/js";
+import { createHash } from "crypto";
 import { PROGRAM_ID as TOKEN_AUTH_RULES_ID } from "@metaplex-foundation/mpl-token-auth-rules";
 export const MPL_DEFAULT_RULE_SET = new PublicKey(
   "H6mX25exrJBXk86zGMX6Dd4WJoR6ZbjnzqUVT8d3NjAT"
@@ -86,6 +100,7 @@ export const getAllListedNFTs = async (
       let listedDate = new anchor.BN(buf);
       buf = data.slice(120, 128).reverse();
       let active = new anchor.BN(buf);
+      let paymentMint = new PublicKey(data.slice(128, 160));
 
       if (active.toNumber() == 1)
         result.push({
@@ -95,7 +110,8 @@ export const getAllListedNFTs = async (
           priceSol,
           listedDate,
           active,
-        });
+          paymentMint,
+        } as SellData);
     }
   } catch (e) {
     console.log(e);
@@ -112,6 +128,7 @@ export const getAllListedNFTs = async (
         priceSol: info.priceSol.toNumber(),
         listedDate: info.listedDate.toNumber(),
         active: info.active.toNumber(),
+        paymentMint: info.paymentMint.toBase58(),
       };
     }),
   };
@@ -370,6 +387,131 @@ export const getOfferDataState = async (
   }
 };
 
+/** Listing pricing mode takes precedence, unset global mode keeps the legacy mode of the settlement path */
+export const resolvePricingMode = (
+  listingMode: number,
+  globalMode: number,
+  legacyMode: number
+) => {
+  if (listingMode != PRICING_MODE_DEFAULT) return listingMode;
+  if (globalMode != PRICING_MODE_DEFAULT) return globalMode;
+  return legacyMode;
+};
+
+/** Same breakdown as the on-chain quote, fees & royalties are always computed on the price */
+export const getSaleQuote = (
+  price: anchor.BN,
+  marketFeeSol: anchor.BN,
+  sellerFeeBasisPoints: number,
+  pricingMode: number
+): SaleQuote => {
+  const marketFee = price.mul(marketFeeSol).div(new anchor.BN(PERMYRIAD));
+  const royalty = price
+    .mul(new anchor.BN(sellerFeeBasisPoints))
+    .div(new anchor.BN(PERMYRIAD));
+  const feeInclusive = pricingMode == PRICING_MODE_FEE_INCLUSIVE;
+  return {
+    price,
+    pricingMode: new anchor.BN(pricingMode),
+    marketFee,
+    royalty,
+    sellerProceeds: feeInclusive ? price.sub(marketFee).sub(royalty) : price,
+    buyerTotal: feeInclusive ? price : price.add(marketFee).add(royalty),
+  };
+};
+
+/** Current price of the Dutch auction listing, the fixed price for the normal listing */
+export const getCurrentPrice = (
+  sellData: SellData,
+  timestamp: number
+): anchor.BN => {
+  if (sellData.dutchDuration.isZero()) return sellData.priceSol;
+  let elapsed = Math.max(timestamp - sellData.listedDate.toNumber(), 0);
+  if (elapsed >= sellData.dutchDuration.toNumber())
+    return sellData.dutchFloorPrice;
+  const step = sellData.dutchStep.toNumber();
+  if (step > 0) elapsed -= elapsed % step;
+  const decay = sellData.priceSol
+    .sub(sellData.dutchFloorPrice)
+    .mul(new anchor.BN(elapsed))
+    .div(sellData.dutchDuration);
+  return sellData.priceSol.sub(decay);
+};
+
+/** SOL is paid to the wallet, SPL Token to the wallet ATA of the payment mint */
+export const getPaymentAddress = async (
+  wallet: PublicKey,
+  paymentMint: PublicKey
+) => {
+  if (paymentMint.equals(PublicKey.default)) return wallet;
+  return await getAssociatedTokenAccount(wallet, paymentMint);
+};
+
+/** Sealed bid commitment, bound to the auction mint & the bidder */
+export const getSealedBidHash = (
+  mint: PublicKey,
+  bidder: PublicKey,
+  amount: number,
+  salt: Buffer
+) => {
+  return createHash("sha256")
+    .update(mint.toBuffer())
+    .update(bidder.toBuffer())
+    .update(new anchor.BN(amount).toArrayLike(Buffer, "le", 8))
+    .update(salt)
+    .digest();
+};
+
+/** Hidden reserve price commitment, bound to the auction mint & the creator */
+export const getReserveHash = (
+  mint: PublicKey,
+  creator: PublicKey,
+  reservePrice: number,
+  salt: Buffer
+) => {
+  return createHash("sha256")
+    .update(mint.toBuffer())
+    .update(creator.toBuffer())
+    .update(new anchor.BN(reservePrice).toArrayLike(Buffer, "le", 8))
+    .update(salt)
+    .digest();
+};
+
+/** Team treasuries and then the metadata creators receiving the fees & royalties of the sale */
+export const getTradeRemainingAccounts = async (
+  mint: PublicKey,
+  treasuryAddresses: PublicKey[],
+  connection: Connection
+) => {
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  let remainingAccounts = [];
+  treasuryAddresses.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: new PublicKey(creator.address),
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  return {
+    sellerFeeBasisPoints: metadata.data.data.sellerFeeBasisPoints,
+    remainingAccounts,
+  };
+};
+
 export const createInitializeTx = async (
   userAddress: PublicKey,
   program: anchor.Program
@@ -432,6 +574,37 @@ export const createUpdateFeeTx = async (
   return tx;
 };
 
+export const createUpdatePricingModeTx = async (
+  userAddress: PublicKey,
+  program: anchor.Program,
+  pricingMode: number
+) => {
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>updating pricing mode",
+    globalAuthority.toBase58(),
+    pricingMode
+  );
+
+  tx.add(
+    program.instruction.updatePricingMode(bump, new anchor.BN(pricingMode), {
+      accounts: {
+        admin: userAddress,
+        globalAuthority,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
 export const createAddTreasuryTx = async (
   userAddress: PublicKey,
   address: PublicKey,
@@ -496,25 +669,24 @@ export const createRemoveTreasuryTx = async (
   return tx;
 };
 
-export const createInitUserTx = async (
+export const createMigrateGlobalPoolTx = async (
   userAddress: PublicKey,
   program: anchor.Program
 ) => {
-  const [userPool, user_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
   let tx = txWithComputeUnitsIxs();
-  console.log("==>initializing user pool", userPool.toBase58());
+  console.log("==>migrating global pool", globalAuthority.toBase58());
 
   tx.add(
-    program.instruction.initUserPool(user_bump, {
+    program.instruction.migrateGlobalPool(bump, {
       accounts: {
-        owner: userAddress,
-        userPool,
+        admin: userAddress,
+        globalAuthority,
         systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
       },
       instructions: [],
       signers: [],
@@ -524,76 +696,78 @@ export const createInitUserTx = async (
   return tx;
 };
 
-export const createDepositTx = async (
-  userAddress: PublicKey,
-  sol: number,
+export const createMigrateUserDataTx = async (
+  payer: PublicKey,
+  address: PublicKey,
   program: anchor.Program
 ) => {
-  let tx = txWithComputeUnitsIxs();
-
-  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(ESCROW_VAULT_SEED)],
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
   const [userPool, user_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    [Buffer.from(USER_DATA_SEED), address.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("escrowVault = ", escrowVault.toBase58());
-  console.log("==> Depositing", userAddress.toBase58(), "Sol", sol);
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>migrating user pool",
+    address.toBase58(),
+    userPool.toBase58()
+  );
+
   tx.add(
-    program.instruction.depositToEscrow(
-      user_bump,
-      escrow_bump,
-      new anchor.BN(sol),
-      {
-        accounts: {
-          owner: userAddress,
-          userPool,
-          escrowVault,
-          systemProgram: SystemProgram.programId,
-        },
-        instructions: [],
-        signers: [],
-      }
-    )
+    program.instruction.migrateUserData(address, user_bump, {
+      accounts: {
+        payer,
+        globalAuthority,
+        userPool,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
   return tx;
 };
 
-export const createWithdrawTx = async (
-  userAddress: PublicKey,
-  sol: number,
+export const createReconcileEscrowBalanceTx = async (
+  admin: PublicKey,
+  address: PublicKey,
+  deduction: number,
   program: anchor.Program
 ) => {
-  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(ESCROW_VAULT_SEED)],
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
-  console.log("escrowVault = ", escrowVault.toBase58());
 
   const [userPool, user_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    [Buffer.from(USER_DATA_SEED), address.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
   let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>reconciling escrow balance",
+    address.toBase58(),
+    "Deduction:",
+    deduction
+  );
 
-  console.log("==> Withdrawing", userAddress.toBase58(), "Sol", sol);
   tx.add(
-    program.instruction.withdrawFromEscrow(
+    program.instruction.reconcileEscrowBalance(
+      address,
       user_bump,
-      escrow_bump,
-      new anchor.BN(sol),
+      new anchor.BN(deduction),
       {
         accounts: {
-          owner: userAddress,
+          admin,
+          globalAuthority,
           userPool,
-          escrowVault,
-          systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
@@ -604,49 +778,25 @@ export const createWithdrawTx = async (
   return tx;
 };
 
-export const createTransferTx = async (
+export const createMigrateSellDataTx = async (
+  payer: PublicKey,
   mint: PublicKey,
-  userAddress: PublicKey,
-  recipientAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection
+  program: anchor.Program
 ) => {
-  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
-  if (!(await isExistAccount(userTokenAccount, connection))) {
-    let accountOfNFT = await getNFTTokenAccount(mint, connection);
-    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
-      let nftOwner = await getOwnerOfNFT(mint, connection);
-      if (nftOwner.toBase58() == userAddress.toBase58())
-        userTokenAccount = accountOfNFT;
-      else {
-        throw "Error: Nft is not owned by user";
-      }
-    }
-  }
-  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
-
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    recipientAddress,
-    [mint]
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("Dest NFT Account = ", ret.destinationAccounts[0].toBase58());
-
   let tx = txWithComputeUnitsIxs();
+  console.log("==>migrating sell PDA", mint.toBase58(), nftData.toBase58());
 
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> transfering", mint.toBase58());
   tx.add(
-    program.instruction.transfer({
+    program.instruction.migrateSellData(mint, nft_bump, {
       accounts: {
-        owner: userAddress,
-        userTokenAccount,
-        recipient: recipientAddress,
-        destNftTokenAccount: ret.destinationAccounts[0],
-        nftMint: mint,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        payer,
+        sellDataInfo: nftData,
+        systemProgram: SystemProgram.programId,
       },
       instructions: [],
       signers: [],
@@ -656,56 +806,37 @@ export const createTransferTx = async (
   return tx;
 };
 
-export const createTransferFromVaultTx = async (
+export const createMigrateOfferDataTx = async (
+  payer: PublicKey,
   mint: PublicKey,
-  userAddress: PublicKey,
-  recipientAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection
+  buyer: PublicKey,
+  program: anchor.Program
 ) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    recipientAddress,
-    [mint]
-  );
-  let userTokenAccount = ret.destinationAccounts[0];
-  console.log("User NFT = ", mint.toBase58(), userTokenAccount.toBase58());
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [userPool, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
-    [mint]
-  );
-
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
-
   let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>migrating offer PDA",
+    mint.toBase58(),
+    buyer.toBase58(),
+    offerData.toBase58()
+  );
 
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> transferFromVault", mint.toBase58());
   tx.add(
-    program.instruction.transferFromVault(bump, nft_bump, {
+    program.instruction.migrateOfferData(mint, buyer, offer_bump, {
       accounts: {
-        owner: userAddress,
-        globalAuthority,
-        sellDataInfo: nftData,
-        recipient: recipientAddress,
-        userTokenAccount,
-        destNftTokenAccount: destinationAccounts[0],
-        nftMint: mint,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        payer,
+        offerDataInfo: offerData,
+        userPool,
+        systemProgram: SystemProgram.programId,
       },
       instructions: [],
       signers: [],
@@ -715,26 +846,56 @@ export const createTransferFromVaultTx = async (
   return tx;
 };
 
-export const createInitSellDataTx = async (
+export const createMigrateAuctionDataTx = async (
+  payer: PublicKey,
   mint: PublicKey,
-  userAddress: PublicKey,
-  program: anchor.Program
+  program: anchor.Program,
+  connection: Connection
 ) => {
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let tx = txWithComputeUnitsIxs();
-  console.log("==>initializing sell PDA", mint.toBase58(), nftData.toBase58());
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  // Old layout can't be decoded with the current IDL, the last bidder is read from the raw data
+  // 8 discriminator + mint 32 + creator 32 + 4 * 8
+  let auctionAccount = await connection.getAccountInfo(auctionData);
+  if (!auctionAccount) {
+    throw "Error: Auction PDA is not initialized";
+  }
+  let lastBidder = new PublicKey(auctionAccount.data.slice(104, 136));
+
+  // The escrowed bid of the last bidder is moved to the locked balance
+  let bidderUserPool = MARKETPLACE_PROGRAM_ID;
+  if (lastBidder.toBase58() != PublicKey.default.toBase58()) {
+    [bidderUserPool] = await PublicKey.findProgramAddress(
+      [Buffer.from(USER_DATA_SEED), lastBidder.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+  }
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>migrating auction PDA",
+    mint.toBase58(),
+    auctionData.toBase58(),
+    "LastBidder:",
+    lastBidder.toBase58()
+  );
 
   tx.add(
-    program.instruction.initSellData(mint, nft_bump, {
+    program.instruction.migrateAuctionData(mint, auction_bump, {
       accounts: {
-        payer: userAddress,
-        sellDataInfo: nftData,
+        payer,
+        globalAuthority,
+        auctionDataInfo: auctionData,
+        bidderUserPool,
         systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
       },
       instructions: [],
       signers: [],
@@ -744,81 +905,70 @@ export const createInitSellDataTx = async (
   return tx;
 };
 
-export const createListForSellNftTx = async (
-  mint: PublicKey,
+export const createInitUserTx = async (
   userAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection,
-  priceSol: number
+  program: anchor.Program
 ) => {
-  if (priceSol < 0) {
-    throw "Invalid Price Value";
-  }
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
-  if (!(await isExistAccount(userTokenAccount, connection))) {
-    let accountOfNFT = await getNFTTokenAccount(mint, connection);
-    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
-      let nftOwner = await getOwnerOfNFT(mint, connection);
-      if (nftOwner.toBase58() == userAddress.toBase58())
-        userTokenAccount = accountOfNFT;
-      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
-        throw "Error: Nft is not owned by user";
-      }
-    }
-  }
-  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+  let tx = txWithComputeUnitsIxs();
+  console.log("==>initializing user pool", userPool.toBase58());
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
+  tx.add(
+    program.instruction.initUserPool(user_bump, {
+      accounts: {
+        owner: userAddress,
+        userPool,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
-  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  return tx;
+};
+
+export const createDepositTx = async (
+  userAddress: PublicKey,
+  sol: number,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
-    [mint]
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
-
-  const metadata = await getMetadata(mint);
-  console.log("Metadata=", metadata.toBase58());
-
-  let tx = txWithComputeUnitsIxs();
-
-  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-  console.log("==>listing", mint.toBase58(), priceSol);
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
 
+  console.log("escrowVault = ", escrowVault.toBase58());
+  console.log("==> Depositing", userAddress.toBase58(), "Sol", sol);
   tx.add(
-    program.instruction.listNftForSale(
-      bump,
-      nft_bump,
-      auction_bump,
-      new anchor.BN(priceSol),
+    program.instruction.depositToEscrow(
+      user_bump,
+      escrow_bump,
+      new anchor.BN(sol),
       {
         accounts: {
           owner: userAddress,
           globalAuthority,
-          sellDataInfo: nftData,
-          userTokenAccount,
-          destNftTokenAccount: destinationAccounts[0],
-          nftMint: mint,
-          mintMetadata: metadata,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          tokenMetadataProgram: METAPLEX,
-          auctionDataInfo: auctionData,
+          userPool,
+          escrowVault,
+          systemProgram: SystemProgram.programId,
         },
         instructions: [],
         signers: [],
@@ -829,182 +979,123 @@ export const createListForSellNftTx = async (
   return tx;
 };
 
-export const createListForSellPNftTx = async (
-  mint: PublicKey,
+export const createWithdrawTx = async (
   userAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection,
-  priceSol: number
+  sol: number,
+  program: anchor.Program
 ) => {
-  if (priceSol < 0) {
-    throw "Invalid Price Value";
-  }
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
-  console.log("globalAuthority", globalAuthority.toBase58());
-  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
-  console.log("userTokenAccount", userTokenAccount.toBase58());
-  if (!(await isExistAccount(userTokenAccount, connection))) {
-    let accountOfNFT = await getNFTTokenAccount(mint, connection);
-    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
-      let nftOwner = await getOwnerOfNFT(mint, connection);
-      if (nftOwner.toBase58() == userAddress.toBase58())
-        userTokenAccount = accountOfNFT;
-      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
-        throw "Error: Nft is not owned by user";
-      }
-    }
-  }
-  console.log("NFT === ", mint.toBase58(), userTokenAccount.toBase58());
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
+  console.log("escrowVault = ", escrowVault.toBase58());
 
-  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
-    [mint]
-  );
-  console.log("instructions ==>", instructions);
-  console.log("userTokenAccount NFT Account = ", userTokenAccount.toBase58());
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
-  const nftEdition = await getMasterEdition(mint);
-  console.log("nftEdition:", nftEdition);
-
-  const tokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    userTokenAccount
-  );
-  const userAccountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(userTokenAccount)
-  );
-  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
-
-  const destTokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    destinationAccounts[0]
-  );
-  const accountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destinationAccounts[0])
-  );
-  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
-  console.log("accountExisted: ", accountExisted);
-  console.log("instructions: ", instructions);
-
-  const metadata = await getMetadata(mint);
-  console.log("Metadata=", metadata.toBase58());
-
   let tx = txWithComputeUnitsIxs();
 
-  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-  console.log("==>listing", mint.toBase58(), priceSol);
-
+  console.log("==> Withdrawing", userAddress.toBase58(), "Sol", sol);
   tx.add(
-    program.instruction.listPnftForSale(
-      bump,
-      nft_bump,
-      auction_bump,
-      new anchor.BN(priceSol),
+    program.instruction.withdrawFromEscrow(
+      user_bump,
+      escrow_bump,
+      new anchor.BN(sol),
       {
         accounts: {
           owner: userAddress,
           globalAuthority,
-          sellDataInfo: nftData,
-          userTokenAccount,
-          destNftTokenAccount: destinationAccounts[0],
-          nftMint: mint,
-          mintMetadata: metadata,
-          tokenMint: mint,
-          tokenMintEdition: nftEdition,
-          tokenMintRecord: tokenMintRecord,
-          destTokenMintRecord: destTokenMintRecord,
+          userPool,
+          escrowVault,
           systemProgram: SystemProgram.programId,
-          auctionDataInfo: auctionData,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          tokenMetadataProgram: METAPLEX,
-          authRules: MPL_DEFAULT_RULE_SET,
-          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          authRulesProgram: TOKEN_AUTH_RULES_ID,
         },
         instructions: [],
         signers: [],
       }
     )
   );
-  console.log("tx:ixs.len:", tx.instructions.length);
-  //   if (!accountExisted) {
-  //     tx.add(destinationAccounts[0]); // Add instruction to create account if it doesn't exist
-  //   }
 
   return tx;
 };
 
-export const createDelistNftTx = async (
-  mint: PublicKey,
-  userAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection
-) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
-  let userTokenAccount = ret.destinationAccounts[0];
-  console.log("User NFT = ", mint.toBase58(), userTokenAccount.toBase58());
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+export const createAuditEscrowTx = async (program: anchor.Program) => {
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [escrowVault] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [auctionData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
+  let tx = txWithComputeUnitsIxs();
+  console.log("==> auditing escrow", escrowVault.toBase58());
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
+  tx.add(
+    program.instruction.auditEscrow({
+      accounts: {
+        globalAuthority,
+        escrowVault,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createTransferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  recipientAddress: PublicKey,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+  if (!(await isExistAccount(userTokenAccount, connection))) {
+    let accountOfNFT = await getNFTTokenAccount(mint, connection);
+    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
+      let nftOwner = await getOwnerOfNFT(mint, connection);
+      if (nftOwner.toBase58() == userAddress.toBase58())
+        userTokenAccount = accountOfNFT;
+      else {
+        throw "Error: Nft is not owned by user";
+      }
+    }
+  }
+  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    recipientAddress,
     [mint]
   );
 
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+  console.log("Dest NFT Account = ", ret.destinationAccounts[0].toBase58());
 
   let tx = txWithComputeUnitsIxs();
 
   if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> withdrawing", mint.toBase58());
+  console.log("==> transfering", mint.toBase58());
   tx.add(
-    program.instruction.delistNft(bump, nft_bump, {
+    program.instruction.transfer({
       accounts: {
         owner: userAddress,
-        globalAuthority,
-        sellDataInfo: nftData,
         userTokenAccount,
-        destNftTokenAccount: destinationAccounts[0],
+        recipient: recipientAddress,
+        destNftTokenAccount: ret.destinationAccounts[0],
         nftMint: mint,
         tokenProgram: TOKEN_PROGRAM_ID,
-        auctionDataInfo: auctionData,
       },
       instructions: [],
       signers: [],
@@ -1014,16 +1105,17 @@ export const createDelistNftTx = async (
   return tx;
 };
 
-export const createDelistPNftTx = async (
+export const createTransferFromVaultTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
+  recipientAddress: PublicKey,
   program: anchor.Program,
   connection: Connection
 ) => {
   let ret = await getATokenAccountsNeedCreate(
     connection,
     userAddress,
-    userAddress,
+    recipientAddress,
     [mint]
   );
   let userTokenAccount = ret.destinationAccounts[0];
@@ -1039,11 +1131,6 @@ export const createDelistPNftTx = async (
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [auctionData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
   let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
     connection,
     userAddress,
@@ -1051,59 +1138,23 @@ export const createDelistPNftTx = async (
     [mint]
   );
 
-  console.log("Dest PNFT Account = ", destinationAccounts[0].toBase58());
-  const nftEdition = await getMasterEdition(mint);
-  console.log("nftEdition:", nftEdition);
-
-  const tokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    userTokenAccount
-  );
-  const userAccountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(userTokenAccount)
-  );
-  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
-
-  const destTokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    destinationAccounts[0]
-  );
-  const accountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destinationAccounts[0])
-  );
-  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
-  console.log("accountExisted: ", accountExisted);
-  console.log("instructions: ", instructions);
-
-  const metadata = await getMetadata(mint);
-  console.log("Metadata=", metadata.toBase58());
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
 
   let tx = txWithComputeUnitsIxs();
 
   if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> withdrawing", mint.toBase58());
+  console.log("==> transferFromVault", mint.toBase58());
   tx.add(
-    program.instruction.delistPnft(bump, nft_bump, {
+    program.instruction.transferFromVault(bump, nft_bump, {
       accounts: {
         owner: userAddress,
         globalAuthority,
         sellDataInfo: nftData,
+        recipient: recipientAddress,
         userTokenAccount,
         destNftTokenAccount: destinationAccounts[0],
         nftMint: mint,
-        tokenMint: mint,
-        mintMetadata: metadata,
-        tokenMintEdition: nftEdition,
-        tokenMintRecord: tokenMintRecord,
-        destTokenMintRecord: destTokenMintRecord,
-        systemProgram: SystemProgram.programId,
-        auctionDataInfo: auctionData,
         tokenProgram: TOKEN_PROGRAM_ID,
-        tokenMetadataProgram: METAPLEX,
-        authRules: MPL_DEFAULT_RULE_SET,
-        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        authRulesProgram: TOKEN_AUTH_RULES_ID,
       },
       instructions: [],
       signers: [],
@@ -1113,10 +1164,9 @@ export const createDelistPNftTx = async (
   return tx;
 };
 
-export const createSetPriceTx = async (
+export const createInitSellDataTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  newPrice: number,
   program: anchor.Program
 ) => {
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
@@ -1125,14 +1175,15 @@ export const createSetPriceTx = async (
   );
 
   let tx = txWithComputeUnitsIxs();
+  console.log("==>initializing sell PDA", mint.toBase58(), nftData.toBase58());
 
-  console.log("==> setting price", mint.toBase58(), newPrice);
   tx.add(
-    program.instruction.setPrice(nft_bump, new anchor.BN(newPrice), {
+    program.instruction.initSellData(mint, nft_bump, {
       accounts: {
-        owner: userAddress,
+        payer: userAddress,
         sellDataInfo: nftData,
-        nftMint: mint,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
       },
       instructions: [],
       signers: [],
@@ -1142,306 +1193,2913 @@ export const createSetPriceTx = async (
   return tx;
 };
 
-export const createPurchaseTx = async (
+export const createCloseSellDataTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  treasuryAddresses: PublicKey[],
-  program: anchor.Program,
-  connection: Connection
+  program: anchor.Program
 ) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
-  let userNftTokenAccount = ret.destinationAccounts[0];
-  console.log("User NFT = ", mint.toBase58(), userNftTokenAccount.toBase58());
-
-  let tx = txWithComputeUnitsIxs();
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
-    MARKETPLACE_PROGRAM_ID
-  );
-
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [auctionData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  let { destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
-    [mint]
-  );
-
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
-
-  let sellInfo = await getNFTPoolState(mint, program);
-  let seller = sellInfo.seller;
-
-  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  console.log("Seller = ", seller.toBase58());
-
-  const mintMetadata = await getMetadata(mint);
-  console.log("Metadata=", mintMetadata.toBase58());
-
-  let {
-    metadata: { Metadata },
-  } = programs;
-  let metadataAccount = await Metadata.getPDA(mint);
-  const metadata = await Metadata.load(connection, metadataAccount);
-  let creators = metadata.data.data.creators;
-
-  let treasuryAccounts: PublicKey[] = treasuryAddresses;
-  console.log(
-    "=> Treasury Accounts:",
-    treasuryAccounts.map((address) => address.toBase58())
-  );
-
-  let remainingAccounts = [];
-  treasuryAccounts.map((address) => {
-    remainingAccounts.push({
-      pubkey: address,
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-  creators.map((creator) => {
-    remainingAccounts.push({
-      pubkey: new PublicKey(creator.address),
-      isWritable: true,
-      isSigner: false,
-    });
-  });
+  let tx = txWithComputeUnitsIxs();
+  console.log("==>closing sell PDA", mint.toBase58(), nftData.toBase58());
 
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> Purchasing", mint.toBase58());
   tx.add(
-    program.instruction.purchase(bump, nft_bump, buyer_bump, seller_bump, {
+    program.instruction.closeSellData(nft_bump, {
       accounts: {
-        buyer: userAddress,
-        globalAuthority,
-        buyerUserPool,
+        payer: userAddress,
         sellDataInfo: nftData,
-        userNftTokenAccount,
-        destNftTokenAccount: destinationAccounts[0],
-        nftMint: mint,
-        seller,
-        sellerUserPool,
-        mintMetadata,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-        tokenMetadataProgram: METAPLEX,
-        auctionDataInfo: auctionData,
       },
       instructions: [],
       signers: [],
-      remainingAccounts,
     })
   );
 
   return tx;
 };
 
-export const createPurchasePNftTx = async (
+export const createListForSellNftTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  treasuryAddresses: PublicKey[],
   program: anchor.Program,
-  connection: Connection
+  connection: Connection,
+  priceSol: number
 ) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
-  let userNftTokenAccount = ret.destinationAccounts[0];
-  console.log("User NFT = ", mint.toBase58(), userNftTokenAccount.toBase58());
-
-  let tx = txWithComputeUnitsIxs();
+  if (priceSol < 0) {
+    throw "Invalid Price Value";
+  }
 
   const [globalAuthority, bump] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
+  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+  if (!(await isExistAccount(userTokenAccount, connection))) {
+    let accountOfNFT = await getNFTTokenAccount(mint, connection);
+    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
+      let nftOwner = await getOwnerOfNFT(mint, connection);
+      if (nftOwner.toBase58() == userAddress.toBase58())
+        userTokenAccount = accountOfNFT;
+      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
+        throw "Error: Nft is not owned by user";
+      }
+    }
+  }
+  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [auctionData, _] = await PublicKey.findProgramAddress(
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  let sellInfo = await getNFTPoolState(mint, program);
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
+  console.log("==>listing", mint.toBase58(), priceSol);
+
+  tx.add(
+    program.instruction.listNftForSale(
+      bump,
+      nft_bump,
+      auction_bump,
+      new anchor.BN(priceSol),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          sellDataInfo: nftData,
+          userTokenAccount,
+          destNftTokenAccount: destinationAccounts[0],
+          nftMint: mint,
+          mintMetadata: metadata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          auctionDataInfo: auctionData,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createListForSellPNftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program,
+  connection: Connection,
+  priceSol: number,
+  paymentMint: PublicKey = PublicKey.default,
+  expiresAt: number | null = null,
+  reservedBuyer: PublicKey | null = null,
+  dutch: DutchSchedule | null = null,
+  pricingMode: number = PRICING_MODE_DEFAULT
+) => {
+  if (priceSol < 0) {
+    throw "Invalid Price Value";
+  }
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+  console.log("globalAuthority", globalAuthority.toBase58());
+  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+  console.log("userTokenAccount", userTokenAccount.toBase58());
+  if (!(await isExistAccount(userTokenAccount, connection))) {
+    let accountOfNFT = await getNFTTokenAccount(mint, connection);
+    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
+      let nftOwner = await getOwnerOfNFT(mint, connection);
+      if (nftOwner.toBase58() == userAddress.toBase58())
+        userTokenAccount = accountOfNFT;
+      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
+        throw "Error: Nft is not owned by user";
+      }
+    }
+  }
+  console.log("NFT === ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+  console.log("instructions ==>", instructions);
+  console.log("userTokenAccount NFT Account = ", userTokenAccount.toBase58());
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    userTokenAccount
+  );
+  const userAccountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(userTokenAccount)
+  );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destinationAccounts[0]
+  );
+  const accountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destinationAccounts[0])
+  );
+  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
+  console.log("accountExisted: ", accountExisted);
+  console.log("instructions: ", instructions);
+
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
+  console.log(
+    "==>listing",
+    mint.toBase58(),
+    priceSol,
+    "PaymentMint:",
+    paymentMint.toBase58()
+  );
+
+  tx.add(
+    program.instruction.listPnftForSale(
+      bump,
+      nft_bump,
+      auction_bump,
+      new anchor.BN(priceSol),
+      paymentMint,
+      expiresAt === null ? null : new anchor.BN(expiresAt),
+      reservedBuyer,
+      dutch,
+      new anchor.BN(pricingMode),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          sellDataInfo: nftData,
+          userTokenAccount,
+          destNftTokenAccount: destinationAccounts[0],
+          nftMint: mint,
+          mintMetadata: metadata,
+          tokenMint: mint,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          systemProgram: SystemProgram.programId,
+          auctionDataInfo: auctionData,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+          paymentMintAccount: paymentMint.equals(PublicKey.default)
+            ? MARKETPLACE_PROGRAM_ID
+            : paymentMint,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+  console.log("tx:ixs.len:", tx.instructions.length);
+  //   if (!accountExisted) {
+  //     tx.add(destinationAccounts[0]); // Add instruction to create account if it doesn't exist
+  //   }
+
+  return tx;
+};
+
+export const createDelistNftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
+  let userTokenAccount = ret.destinationAccounts[0];
+  console.log("User NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+  console.log("==> withdrawing", mint.toBase58());
+  tx.add(
+    program.instruction.delistNft(bump, nft_bump, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        sellDataInfo: nftData,
+        userTokenAccount,
+        destNftTokenAccount: destinationAccounts[0],
+        nftMint: mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        auctionDataInfo: auctionData,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createDelistPNftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
+  let userTokenAccount = ret.destinationAccounts[0];
+  console.log("User NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+
+  console.log("Dest PNFT Account = ", destinationAccounts[0].toBase58());
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    userTokenAccount
+  );
+  const userAccountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(userTokenAccount)
+  );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destinationAccounts[0]
+  );
+  const accountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destinationAccounts[0])
+  );
+  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
+  console.log("accountExisted: ", accountExisted);
+  console.log("instructions: ", instructions);
+
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+  console.log("==> withdrawing", mint.toBase58());
+  tx.add(
+    program.instruction.delistPnft(bump, nft_bump, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        sellDataInfo: nftData,
+        userTokenAccount,
+        destNftTokenAccount: destinationAccounts[0],
+        nftMint: mint,
+        tokenMint: mint,
+        mintMetadata: metadata,
+        tokenMintEdition: nftEdition,
+        tokenMintRecord: tokenMintRecord,
+        destTokenMintRecord: destTokenMintRecord,
+        systemProgram: SystemProgram.programId,
+        auctionDataInfo: auctionData,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METAPLEX,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createDelistExpiredPNftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  // Anyone can unlock the expired listing, the NFT stays with the seller
+  let sellInfo = await getNFTPoolState(mint, program);
+  let seller = sellInfo.seller;
+  let userTokenAccount = await getAssociatedTokenAccount(seller, mint);
+  console.log("Seller NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+  // Global authority ATA clears the SPL delegate when the owner is not signing
+  const globalNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    userTokenAccount
+  );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
+
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  console.log("==> delisting expired", mint.toBase58(), seller.toBase58());
+  tx.add(
+    program.instruction.delistExpiredPnft(bump, nft_bump, {
+      accounts: {
+        payer: userAddress,
+        globalAuthority,
+        sellDataInfo: nftData,
+        seller,
+        userTokenAccount,
+        nftMint: mint,
+        mintMetadata: metadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMintEdition: nftEdition,
+        tokenMintRecord: tokenMintRecord,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+        systemProgram: SystemProgram.programId,
+        tokenMetadataProgram: METAPLEX,
+        auctionDataInfo: auctionData,
+        globalNftTokenAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createSetPriceTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  newPrice: number,
+  program: anchor.Program,
+  expiresAt: number | null = null
+) => {
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+
+  console.log("==> setting price", mint.toBase58(), newPrice, expiresAt);
+  tx.add(
+    program.instruction.setPrice(
+      nft_bump,
+      new anchor.BN(newPrice),
+      expiresAt === null ? null : new anchor.BN(expiresAt),
+      {
+        accounts: {
+          owner: userAddress,
+          sellDataInfo: nftData,
+          nftMint: mint,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createPurchaseTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
+  let userNftTokenAccount = ret.destinationAccounts[0];
+  console.log("User NFT = ", mint.toBase58(), userNftTokenAccount.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+
+  let sellInfo = await getNFTPoolState(mint, program);
+  let seller = sellInfo.seller;
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log("Seller = ", seller.toBase58());
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  let treasuryAccounts: PublicKey[] = treasuryAddresses;
+  console.log(
+    "=> Treasury Accounts:",
+    treasuryAccounts.map((address) => address.toBase58())
+  );
+
+  let remainingAccounts = [];
+  treasuryAccounts.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: new PublicKey(creator.address),
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+  console.log("==> Purchasing", mint.toBase58());
+  tx.add(
+    program.instruction.purchase(bump, nft_bump, buyer_bump, seller_bump, {
+      accounts: {
+        buyer: userAddress,
+        globalAuthority,
+        buyerUserPool,
+        sellDataInfo: nftData,
+        userNftTokenAccount,
+        destNftTokenAccount: destinationAccounts[0],
+        nftMint: mint,
+        seller,
+        sellerUserPool,
+        mintMetadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenMetadataProgram: METAPLEX,
+        auctionDataInfo: auctionData,
+      },
+      instructions: [],
+      signers: [],
+      remainingAccounts,
+    })
+  );
+
+  return tx;
+};
+
+export const createPurchasePNftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection,
+  fromEscrow: boolean = false,
+  recipient: PublicKey | null = null,
+  creditRecipient: boolean = false
+) => {
+  // The NFT is sent to the recipient for the gift purchase
+  let receiver = recipient ?? userAddress;
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    receiver,
+    [mint]
+  );
+  let userNftTokenAccount = ret.destinationAccounts[0];
+  console.log("User NFT = ", mint.toBase58(), userNftTokenAccount.toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [recipientUserPool] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), receiver.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let sellInfo = await getNFTPoolState(mint, program);
+  let seller = sellInfo.seller;
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log("Seller = ", seller.toBase58());
+
+  // let { destinationAccounts } = await getATokenAccountsNeedCreate(connection, userAddress, globalAuthority, [mint]);
+  let destNftTokenAccount = await getAssociatedTokenAccount(seller, mint);
+
+  console.log("Dest NFT Account = ", destNftTokenAccount.toBase58());
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    userNftTokenAccount
+  );
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  // Purchase is rejected when the listing terms are changed after signing
+  let globalState = await getGlobalState(program);
+  let price = getCurrentPrice(sellInfo, Math.floor(Date.now() / 1000));
+  let quote = getSaleQuote(
+    price,
+    globalState.marketFeeSol,
+    metadata.data.data.sellerFeeBasisPoints,
+    resolvePricingMode(
+      sellInfo.pricingMode.toNumber(),
+      globalState.pricingMode.toNumber(),
+      PRICING_MODE_FEE_ON_TOP
+    )
+  );
+
+  // SPL Token listings are paid between the payment mint ATAs
+  let paymentMint = sellInfo.paymentMint;
+  let isSolPayment = paymentMint.equals(PublicKey.default);
+  if (fromEscrow && !isSolPayment) {
+    throw "Error: Only SOL listings can be paid from the escrow";
+  }
+
+  let treasuryAccounts: PublicKey[] = treasuryAddresses;
+  console.log(
+    "=> Treasury Accounts:",
+    treasuryAccounts.map((address) => address.toBase58())
+  );
+
+  let remainingAccounts = [];
+  for (const address of treasuryAccounts) {
+    remainingAccounts.push({
+      pubkey: await getPaymentAddress(address, paymentMint),
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+  for (const creator of creators) {
+    remainingAccounts.push({
+      pubkey: await getPaymentAddress(
+        new PublicKey(creator.address),
+        paymentMint
+      ),
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+  // The buyer creates the missing payout ATAs of the seller, treasuries & creators
+  if (!isSolPayment) {
+    let payees = [
+      seller,
+      ...treasuryAccounts,
+      ...creators.map((creator) => new PublicKey(creator.address)),
+    ];
+    // One create instruction per ATA, the seller can be a creator too
+    payees = payees.filter(
+      (payee, index) => payees.findIndex((other) => other.equals(payee)) == index
+    );
+    for (const payee of payees) {
+      let payeeAta = await getATokenAccountsNeedCreate(
+        connection,
+        userAddress,
+        payee,
+        [paymentMint]
+      );
+      payeeAta.instructions.map((ix) => tx.add(ix));
+    }
+  }
+  console.log(
+    "==> Purchasing",
+    mint.toBase58(),
+    "Price:",
+    quote.price.toString(),
+    "Royalty:",
+    quote.royalty.toString()
+  );
+  tx.add(
+    program.instruction.purchasePnft(
+      bump,
+      nft_bump,
+      seller_bump,
+      buyer_bump,
+      quote.price,
+      quote.royalty,
+      fromEscrow,
+      escrow_bump,
+      creditRecipient,
+      {
+        accounts: {
+          buyer: userAddress,
+          globalAuthority,
+          buyerUserPool,
+          recipient: receiver,
+          recipientUserPool: creditRecipient
+            ? recipientUserPool
+            : MARKETPLACE_PROGRAM_ID,
+          sellDataInfo: nftData,
+          userNftTokenAccount,
+          destNftTokenAccount: destNftTokenAccount,
+          nftMint: mint,
+          tokenMint: mint,
+          seller,
+          buyerPaymentTokenAccount: isSolPayment
+            ? MARKETPLACE_PROGRAM_ID
+            : await getAssociatedTokenAccount(userAddress, paymentMint),
+          sellerPaymentTokenAccount: isSolPayment
+            ? MARKETPLACE_PROGRAM_ID
+            : await getAssociatedTokenAccount(seller, paymentMint),
+          paymentTokenProgram: isSolPayment
+            ? MARKETPLACE_PROGRAM_ID
+            : TOKEN_PROGRAM_ID,
+          escrowVault: fromEscrow ? escrowVault : MARKETPLACE_PROGRAM_ID,
+          // creator,
+          sellerUserPool,
+          mintMetadata,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          systemProgram: SystemProgram.programId,
+          auctionDataInfo: auctionData,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const BUBBLEGUM_PROGRAM_ID = new PublicKey(
+  "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+);
+export const SPL_NOOP_PROGRAM_ID = new PublicKey(
+  "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"
+);
+export const SPL_ACCOUNT_COMPRESSION_PROGRAM_ID = new PublicKey(
+  "cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"
+);
+
+/** Merkle proof nodes of the compressed NFT leaf, passed as the remaining accounts */
+const getProofAccounts = (proof: PublicKey[]) => {
+  return proof.map((node) => {
+    return {
+      pubkey: node,
+      isWritable: false,
+      isSigner: false,
+    };
+  });
+};
+
+/**
+ * Compressed NFT is identified by the asset id instead of the mint
+ * The metadata, the leaf & the proof are fetched from the DAS API by the caller
+ */
+export const createListCNftForSaleTx = async (
+  assetId: PublicKey,
+  merkleTree: PublicKey,
+  userAddress: PublicKey,
+  priceSol: number,
+  metadata: any,
+  leaf: { root: number[]; nonce: anchor.BN; index: number },
+  proof: PublicKey[],
+  program: anchor.Program,
+  expiresAt: number | null = null,
+  pricingMode: number = PRICING_MODE_DEFAULT
+) => {
+  if (priceSol < 0) {
+    throw "Invalid Price Value";
+  }
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), assetId.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), assetId.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [treeConfig, tree_bump] = await PublicKey.findProgramAddress(
+    [merkleTree.toBuffer()],
+    BUBBLEGUM_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log("==>listing cNFT", assetId.toBase58(), priceSol);
+
+  tx.add(
+    program.instruction.listCnftForSale(
+      bump,
+      new anchor.BN(priceSol),
+      expiresAt === null ? null : new anchor.BN(expiresAt),
+      new anchor.BN(pricingMode),
+      metadata,
+      leaf,
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          sellDataInfo: nftData,
+          treeConfig,
+          merkleTree,
+          logWrapper: SPL_NOOP_PROGRAM_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+          bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          auctionDataInfo: auctionData,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts: getProofAccounts(proof),
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createDelistCNftTx = async (
+  assetId: PublicKey,
+  merkleTree: PublicKey,
+  userAddress: PublicKey,
+  metadata: any,
+  leaf: { root: number[]; nonce: anchor.BN; index: number },
+  proof: PublicKey[],
+  program: anchor.Program
+) => {
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), assetId.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [treeConfig, tree_bump] = await PublicKey.findProgramAddress(
+    [merkleTree.toBuffer()],
+    BUBBLEGUM_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log("==> delisting cNFT", assetId.toBase58());
+
+  tx.add(
+    program.instruction.delistCnft(bump, metadata, leaf, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        sellDataInfo: nftData,
+        treeConfig,
+        merkleTree,
+        logWrapper: SPL_NOOP_PROGRAM_ID,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+      remainingAccounts: getProofAccounts(proof),
+    })
+  );
+
+  return tx;
+};
+
+export const createPurchaseCNftTx = async (
+  assetId: PublicKey,
+  merkleTree: PublicKey,
+  userAddress: PublicKey,
+  treasuryAddresses: PublicKey[],
+  metadata: any,
+  leaf: { root: number[]; nonce: anchor.BN; index: number },
+  proof: PublicKey[],
+  program: anchor.Program
+) => {
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), assetId.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let sellInfo = await getNFTPoolState(assetId, program);
+  let seller = sellInfo.seller;
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [treeConfig, tree_bump] = await PublicKey.findProgramAddress(
+    [merkleTree.toBuffer()],
+    BUBBLEGUM_PROGRAM_ID
+  );
+
+  // Purchase is rejected when the listing terms are changed after signing
+  let globalState = await getGlobalState(program);
+  let quote = getSaleQuote(
+    sellInfo.priceSol,
+    globalState.marketFeeSol,
+    metadata.sellerFeeBasisPoints,
+    resolvePricingMode(
+      sellInfo.pricingMode.toNumber(),
+      globalState.pricingMode.toNumber(),
+      PRICING_MODE_FEE_ON_TOP
+    )
+  );
+
+  // Team treasuries, the metadata creators and then the proof nodes
+  let remainingAccounts = [];
+  treasuryAddresses.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  metadata.creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: creator.address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  remainingAccounts.push(...getProofAccounts(proof));
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==> Purchasing cNFT",
+    assetId.toBase58(),
+    "Price:",
+    quote.price.toString()
+  );
+
+  tx.add(
+    program.instruction.purchaseCnft(
+      bump,
+      quote.price,
+      quote.royalty,
+      metadata,
+      leaf,
+      {
+        accounts: {
+          buyer: userAddress,
+          globalAuthority,
+          sellDataInfo: nftData,
+          buyerUserPool,
+          seller,
+          sellerUserPool,
+          treeConfig,
+          merkleTree,
+          logWrapper: SPL_NOOP_PROGRAM_ID,
+          compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+          bubblegumProgram: BUBBLEGUM_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const getBundleDataState = async (
+  seller: PublicKey,
+  bundleId: number,
+  program: anchor.Program
+): Promise<BundleData | null> => {
+  const [bundleData, _] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(BUNDLE_DATA_SEED),
+      seller.toBuffer(),
+      new anchor.BN(bundleId).toArrayLike(Buffer, "le", 8),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+  console.log("Bundle Data PDA: ", bundleData.toBase58());
+  try {
+    let poolState = await program.account.bundleData.fetch(bundleData);
+    return poolState as unknown as BundleData;
+  } catch {
+    return null;
+  }
+};
+
+/** Per bundle item the mint, metadata, edition, seller token account & token record */
+const getBundleItemAccounts = async (mints: PublicKey[], seller: PublicKey) => {
+  let itemAccounts = [];
+  for (const mint of mints) {
+    const tokenAccount = await getAssociatedTokenAccount(seller, mint);
+    itemAccounts.push(
+      { pubkey: mint, isWritable: false, isSigner: false },
+      { pubkey: await getMetadata(mint), isWritable: true, isSigner: false },
+      {
+        pubkey: await getMasterEdition(mint),
+        isWritable: false,
+        isSigner: false,
+      },
+      { pubkey: tokenAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: findTokenRecordPda(mint, tokenAccount),
+        isWritable: true,
+        isSigner: false,
+      }
+    );
+  }
+  return itemAccounts;
+};
+
+export const createListBundleForSaleTx = async (
+  mints: PublicKey[],
+  userAddress: PublicKey,
+  bundleId: number,
+  priceSol: number,
+  program: anchor.Program,
+  pricingMode: number = PRICING_MODE_DEFAULT
+) => {
+  if (priceSol < 0) {
+    throw "Invalid Price Value";
+  }
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [bundleData, _] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(BUNDLE_DATA_SEED),
+      userAddress.toBuffer(),
+      new anchor.BN(bundleId).toArrayLike(Buffer, "le", 8),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let remainingAccounts = await getBundleItemAccounts(mints, userAddress);
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>listing bundle",
+    bundleId,
+    mints.map((mint) => mint.toBase58()),
+    priceSol
+  );
+
+  tx.add(
+    program.instruction.listBundleForSale(
+      bump,
+      new anchor.BN(bundleId),
+      new anchor.BN(priceSol),
+      new anchor.BN(pricingMode),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          bundleDataInfo: bundleData,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createDelistBundleTx = async (
+  userAddress: PublicKey,
+  bundleId: number,
+  program: anchor.Program
+) => {
+  let bundleInfo = await getBundleDataState(userAddress, bundleId, program);
+  let mints = bundleInfo.mints.slice(0, bundleInfo.itemCount.toNumber());
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [bundleData, _] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(BUNDLE_DATA_SEED),
+      userAddress.toBuffer(),
+      new anchor.BN(bundleId).toArrayLike(Buffer, "le", 8),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let remainingAccounts = await getBundleItemAccounts(mints, userAddress);
+
+  let tx = txWithComputeUnitsIxs();
+  console.log("==> delisting bundle", bundleId);
+
+  tx.add(
+    program.instruction.delistBundle(bump, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        bundleDataInfo: bundleData,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METAPLEX,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+      remainingAccounts,
+    })
+  );
+
+  return tx;
+};
+
+export const createPurchaseBundleTx = async (
+  seller: PublicKey,
+  bundleId: number,
+  userAddress: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let bundleInfo = await getBundleDataState(seller, bundleId, program);
+  let itemCount = bundleInfo.itemCount.toNumber();
+  let mints = bundleInfo.mints.slice(0, itemCount);
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [bundleData, _] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(BUNDLE_DATA_SEED),
+      seller.toBuffer(),
+      new anchor.BN(bundleId).toArrayLike(Buffer, "le", 8),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let globalState = await getGlobalState(program);
+  let pricingMode = resolvePricingMode(
+    bundleInfo.pricingMode.toNumber(),
+    globalState.pricingMode.toNumber(),
+    PRICING_MODE_FEE_ON_TOP
+  );
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+
+  // Team treasuries and then per bundle item the mint, metadata, edition, seller token account
+  // & token record, buyer token account & token record and the creators
+  let remainingAccounts = [];
+  treasuryAddresses.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  // Bundle price is split evenly over the items, the division dust goes to the first item
+  let royalty = new anchor.BN(0);
+  for (const [k, mint] of mints.entries()) {
+    let itemPrice = bundleInfo.priceSol.div(new anchor.BN(itemCount));
+    if (k == 0) itemPrice = itemPrice.add(bundleInfo.priceSol.modn(itemCount));
+
+    const metadataAccount = await Metadata.getPDA(mint);
+    const metadata = await Metadata.load(connection, metadataAccount);
+    royalty = royalty.add(
+      getSaleQuote(
+        itemPrice,
+        globalState.marketFeeSol,
+        metadata.data.data.sellerFeeBasisPoints,
+        pricingMode
+      ).royalty
+    );
+
+    const tokenAccount = await getAssociatedTokenAccount(seller, mint);
+    const destTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+    remainingAccounts.push(
+      { pubkey: mint, isWritable: false, isSigner: false },
+      { pubkey: metadataAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: await getMasterEdition(mint),
+        isWritable: false,
+        isSigner: false,
+      },
+      { pubkey: tokenAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: findTokenRecordPda(mint, tokenAccount),
+        isWritable: true,
+        isSigner: false,
+      },
+      { pubkey: destTokenAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: findTokenRecordPda(mint, destTokenAccount),
+        isWritable: true,
+        isSigner: false,
+      }
+    );
+    metadata.data.data.creators.map((creator) => {
+      remainingAccounts.push({
+        pubkey: new PublicKey(creator.address),
+        isWritable: true,
+        isSigner: false,
+      });
+    });
+  }
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==> Purchasing bundle",
+    bundleId,
+    "Seller:",
+    seller.toBase58(),
+    "Price:",
+    bundleInfo.priceSol.toString()
+  );
+
+  tx.add(
+    program.instruction.purchaseBundle(bump, bundleInfo.priceSol, royalty, {
+      accounts: {
+        buyer: userAddress,
+        globalAuthority,
+        bundleDataInfo: bundleData,
+        buyerUserPool,
+        seller,
+        sellerUserPool,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METAPLEX,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+      remainingAccounts,
+    })
+  );
+
+  return tx;
+};
+
+export const createInitOfferDataTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+) => {
+  const [offerData, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>initializing offer PDA",
+    mint.toBase58(),
+    offerData.toBase58()
+  );
+
+  tx.add(
+    program.instruction.initOfferData(mint, bump, {
+      accounts: {
+        payer: userAddress,
+        offerDataInfo: offerData,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createMakeOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  price: number,
+  program: anchor.Program,
+  expiresAt: number | null = null
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log("escrowVault = ", escrowVault.toBase58());
+
+  console.log(
+    "==> making Offer",
+    mint.toBase58(),
+    userAddress.toBase58(),
+    "Price:",
+    price,
+    "ExpiresAt:",
+    expiresAt
+  );
+  tx.add(
+    program.instruction.makeOffer(
+      nft_bump,
+      offer_bump,
+      user_bump,
+      escrow_bump,
+      new anchor.BN(price),
+      expiresAt === null ? null : new anchor.BN(expiresAt),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          sellDataInfo: nftData,
+          offerDataInfo: offerData,
+          nftMint: mint,
+          userPool,
+          escrowVault,
+          systemProgram: SystemProgram.programId,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createCancelOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log("==> canceling Offer", mint.toBase58(), userAddress.toBase58());
+  tx.add(
+    program.instruction.cancelOffer(offer_bump, escrow_bump, user_bump, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        offerDataInfo: offerData,
+        userPool: userPool,
+        systemProgram: SystemProgram.programId,
+        escrowVault: escrowVault,
+        nftMint: mint,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createRefundOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  buyer: PublicKey,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log("==> refunding Offer", mint.toBase58(), buyer.toBase58());
+  tx.add(
+    program.instruction.refundOffer(escrow_bump, {
+      accounts: {
+        payer: userAddress,
+        globalAuthority,
+        buyer,
+        offerDataInfo: offerData,
+        sellDataInfo: nftData,
+        userPool,
+        escrowVault,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createRejectOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  buyer: PublicKey,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  // Offers are rejected by the NFT holder
+  let sellerNftTokenAccount = await getAssociatedTokenAccount(
+    userAddress,
+    mint
+  );
+  if (!(await isExistAccount(sellerNftTokenAccount, connection))) {
+    sellerNftTokenAccount = await getNFTTokenAccount(mint, connection);
+  }
+
+  console.log("==> rejecting Offer", mint.toBase58(), buyer.toBase58());
+  tx.add(
+    program.instruction.rejectOffer(escrow_bump, {
+      accounts: {
+        seller: userAddress,
+        globalAuthority,
+        buyer,
+        offerDataInfo: offerData,
+        sellerNftTokenAccount,
+        userPool,
+        escrowVault,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createCounterOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  buyer: PublicKey,
+  counterPrice: number,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log(
+    "==> countering Offer",
+    mint.toBase58(),
+    buyer.toBase58(),
+    "CounterPrice:",
+    counterPrice
+  );
+  tx.add(
+    program.instruction.counterOffer(new anchor.BN(counterPrice), {
+      accounts: {
+        seller: userAddress,
+        sellDataInfo: nftData,
+        offerDataInfo: offerData,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const getCollectionOfferState = async (
+  collection: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+): Promise<CollectionOfferData | null> => {
+  const [collectionOffer, _] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(COLLECTION_OFFER_SEED),
+      collection.toBuffer(),
+      userAddress.toBuffer(),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+  console.log("Collection Offer PDA: ", collectionOffer.toBase58());
+  try {
+    let offerState = await program.account.collectionOfferData.fetch(
+      collectionOffer
+    );
+    return offerState as unknown as CollectionOfferData;
+  } catch {
+    return null;
+  }
+};
+
+export const createMakeCollectionOfferTx = async (
+  collection: PublicKey,
+  userAddress: PublicKey,
+  price: number,
+  quantity: number,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [collectionOffer, offer_bump] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(COLLECTION_OFFER_SEED),
+      collection.toBuffer(),
+      userAddress.toBuffer(),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  // Collection NFT royalty is fixed with the offer
+  const collectionMetadata = await getMetadata(collection);
+
+  console.log(
+    "==> making Collection Offer",
+    collection.toBase58(),
+    userAddress.toBase58(),
+    "Price:",
+    price,
+    "Quantity:",
+    quantity
+  );
+  tx.add(
+    program.instruction.makeCollectionOffer(
+      user_bump,
+      escrow_bump,
+      collection,
+      new anchor.BN(price),
+      new anchor.BN(quantity),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          collectionOfferInfo: collectionOffer,
+          collectionMetadata,
+          userPool,
+          escrowVault,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createCancelCollectionOfferTx = async (
+  collection: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [collectionOffer, offer_bump] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(COLLECTION_OFFER_SEED),
+      collection.toBuffer(),
+      userAddress.toBuffer(),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  console.log(
+    "==> canceling Collection Offer",
+    collection.toBase58(),
+    userAddress.toBase58()
+  );
+  tx.add(
+    program.instruction.cancelCollectionOffer(user_bump, escrow_bump, {
+      accounts: {
+        owner: userAddress,
+        globalAuthority,
+        collectionOfferInfo: collectionOffer,
+        userPool,
+        escrowVault,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createAcceptCollectionOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  buyer: PublicKey,
+  collection: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [collectionOffer, offer_bump] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from(COLLECTION_OFFER_SEED),
+      collection.toBuffer(),
+      buyer.toBuffer(),
+    ],
+    MARKETPLACE_PROGRAM_ID
+  );
+  let offerInfo = (await program.account.collectionOfferData.fetch(
+    collectionOffer
+  )) as unknown as CollectionOfferData;
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let ret = await getATokenAccountsNeedCreate(connection, userAddress, buyer, [
+    mint,
+  ]);
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    ret.destinationAccounts[0]
+  );
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let { remainingAccounts } = await getTradeRemainingAccounts(
+    mint,
+    treasuryAddresses,
+    connection
+  );
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  // Offer settles at the fee rates fixed on the creation
+  let quote = getSaleQuote(
+    offerInfo.price,
+    offerInfo.marketFeeSol,
+    offerInfo.royaltyBasisPoints.toNumber(),
+    offerInfo.pricingMode.toNumber()
+  );
+
+  console.log(
+    "==> Accept Collection Offer  Mint:",
+    mint.toBase58(),
+    "Collection:",
+    collection.toBase58(),
+    "Buyer:",
+    buyer.toBase58(),
+    "Price:",
+    offerInfo.price.toNumber()
+  );
+
+  tx.add(
+    program.instruction.acceptCollectionOffer(
+      escrow_bump,
+      quote.price,
+      quote.royalty,
+      {
+        accounts: {
+          seller: userAddress,
+          buyer,
+          collectionOfferInfo: collectionOffer,
+          sellerUserPool,
+          buyerUserPool,
+          globalAuthority,
+          nftMint: mint,
+          userNftTokenAccount: ret.destinationAccounts[0],
+          destNftTokenAccount,
+          escrowVault,
+          mintMetadata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+          systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: METAPLEX,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createAcceptOfferTx = async (
+  mint: PublicKey,
+  buyer: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let sellInfo = await getNFTPoolState(mint, program);
+  let seller = sellInfo.seller;
+  let offerInfo = await getOfferDataState(mint, buyer, program);
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let ret = await getATokenAccountsNeedCreate(connection, seller, buyer, [
+    mint,
+  ]);
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
+  );
+
+  console.log("escrowVault = ", escrowVault.toBase58());
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  let treasuryAccounts: PublicKey[] = treasuryAddresses;
+  console.log(
+    "=> Treasury Accounts:",
+    treasuryAccounts.map((address) => address.toBase58())
+  );
+
+  let remainingAccounts = [];
+  treasuryAccounts.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: new PublicKey(creator.address),
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  console.log(
+    "==> Accept Offer  Mint:",
+    mint.toBase58(),
+    "Buyer:",
+    buyer.toBase58(),
+    "Seller:",
+    seller.toBase58(),
+    "OfferPrice:",
+    offerInfo.offerPrice.toNumber()
+  );
+
+  tx.add(
+    program.instruction.acceptOffer(
+      bump,
+      nft_bump,
+      offer_bump,
+      buyer_bump,
+      seller_bump,
+      escrow_bump,
+      {
+        accounts: {
+          seller,
+          sellDataInfo: nftData,
+          buyer,
+          offerDataInfo: offerData,
+          sellerUserPool,
+          nftMint: mint,
+          globalAuthority,
+          buyerUserPool,
+          userNftTokenAccount: ret.destinationAccounts[0],
+          destNftTokenAccount,
+          escrowVault,
+          mintMetadata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: METAPLEX,
+          auctionDataInfo: auctionData,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createAcceptOfferPNftTx = async (
+  mint: PublicKey,
+  buyer: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let sellInfo = await getNFTPoolState(mint, program);
+  let seller = sellInfo.seller;
+  let offerInfo = await getOfferDataState(mint, buyer, program);
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let ret = await getATokenAccountsNeedCreate(connection, seller, buyer, [
+    mint,
+  ]);
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(seller, mint);
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    ret.destinationAccounts[0]
+  );
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+
+  console.log("escrowVault = ", escrowVault.toBase58());
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  let treasuryAccounts: PublicKey[] = treasuryAddresses;
+  console.log(
+    "=> Treasury Accounts:",
+    treasuryAccounts.map((address) => address.toBase58())
+  );
+
+  let remainingAccounts = [];
+  treasuryAccounts.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: new PublicKey(creator.address),
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  console.log(
+    "==> Accept Offer  Mint:",
+    mint.toBase58(),
+    "Buyer:",
+    buyer.toBase58(),
+    "Seller:",
+    seller.toBase58(),
+    "OfferPrice:",
+    offerInfo.offerPrice.toNumber()
+  );
+
+  // Accept is rejected when the offer terms are changed after signing
+  let royalty = offerInfo.offerPrice
+    .mul(new anchor.BN(metadata.data.data.sellerFeeBasisPoints))
+    .div(new anchor.BN(PERMYRIAD));
+
+  tx.add(
+    program.instruction.acceptOfferPnft(
+      bump,
+      nft_bump,
+      offer_bump,
+      buyer_bump,
+      seller_bump,
+      escrow_bump,
+      offerInfo.offerPrice,
+      royalty,
+      {
+        accounts: {
+          seller,
+          sellDataInfo: nftData,
+          buyer,
+          offerDataInfo: offerData,
+          sellerUserPool,
+          nftMint: mint,
+          globalAuthority,
+          buyerUserPool,
+          userNftTokenAccount: ret.destinationAccounts[0],
+          destNftTokenAccount,
+          escrowVault,
+          mintMetadata,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          systemProgram: SystemProgram.programId,
+          auctionDataInfo: auctionData,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createAcceptStandingOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  buyer: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let offerInfo = await getOfferDataState(mint, buyer, program);
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let ret = await getATokenAccountsNeedCreate(connection, userAddress, buyer, [
+    mint,
+  ]);
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    ret.destinationAccounts[0]
+  );
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let { sellerFeeBasisPoints, remainingAccounts } =
+    await getTradeRemainingAccounts(mint, treasuryAddresses, connection);
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  // Accept is rejected when the offer terms are changed after signing
+  let globalState = await getGlobalState(program);
+  let quote = getSaleQuote(
+    offerInfo.offerPrice,
+    globalState.marketFeeSol,
+    sellerFeeBasisPoints,
+    resolvePricingMode(
+      PRICING_MODE_DEFAULT,
+      globalState.pricingMode.toNumber(),
+      PRICING_MODE_FEE_INCLUSIVE
+    )
+  );
+
+  console.log(
+    "==> Accept Standing Offer  Mint:",
+    mint.toBase58(),
+    "Buyer:",
+    buyer.toBase58(),
+    "OfferPrice:",
+    offerInfo.offerPrice.toNumber()
+  );
+
+  tx.add(
+    program.instruction.acceptStandingOffer(
+      escrow_bump,
+      quote.price,
+      quote.royalty,
+      {
+        accounts: {
+          seller: userAddress,
+          buyer,
+          offerDataInfo: offerData,
+          sellerUserPool,
+          buyerUserPool,
+          globalAuthority,
+          nftMint: mint,
+          userNftTokenAccount: ret.destinationAccounts[0],
+          destNftTokenAccount,
+          escrowVault,
+          mintMetadata,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+          systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: METAPLEX,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createAcceptCounterOfferTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
+) => {
+  let sellInfo = await getNFTPoolState(mint, program);
   let seller = sellInfo.seller;
+  let offerInfo = await getOfferDataState(mint, userAddress, program);
+
+  let tx = txWithComputeUnitsIxs();
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [auctionData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
   const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("Seller = ", seller.toBase58());
-
-  // let { destinationAccounts } = await getATokenAccountsNeedCreate(connection, userAddress, globalAuthority, [mint]);
-  let destNftTokenAccount = await getAssociatedTokenAccount(seller, mint);
-
-  console.log("Dest NFT Account = ", destNftTokenAccount.toBase58());
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(seller, mint);
+
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    ret.destinationAccounts[0]
+  );
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let { sellerFeeBasisPoints, remainingAccounts } =
+    await getTradeRemainingAccounts(mint, treasuryAddresses, connection);
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  // Accept is rejected when the counter price is changed after signing
+  let globalState = await getGlobalState(program);
+  let quote = getSaleQuote(
+    offerInfo.counterPrice,
+    globalState.marketFeeSol,
+    sellerFeeBasisPoints,
+    resolvePricingMode(
+      sellInfo.pricingMode.toNumber(),
+      globalState.pricingMode.toNumber(),
+      PRICING_MODE_FEE_INCLUSIVE
+    )
+  );
+
+  console.log(
+    "==> Accept Counter Offer  Mint:",
+    mint.toBase58(),
+    "Buyer:",
+    userAddress.toBase58(),
+    "Seller:",
+    seller.toBase58(),
+    "CounterPrice:",
+    offerInfo.counterPrice.toNumber()
+  );
+
+  tx.add(
+    program.instruction.acceptCounterOffer(
+      bump,
+      escrow_bump,
+      quote.price,
+      quote.royalty,
+      {
+        accounts: {
+          seller,
+          sellDataInfo: nftData,
+          buyer: userAddress,
+          offerDataInfo: offerData,
+          sellerUserPool,
+          nftMint: mint,
+          globalAuthority,
+          buyerUserPool,
+          userNftTokenAccount: ret.destinationAccounts[0],
+          destNftTokenAccount,
+          escrowVault,
+          mintMetadata,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          systemProgram: SystemProgram.programId,
+          auctionDataInfo: auctionData,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+        },
+        instructions: [],
+        signers: [],
+        remainingAccounts,
+      }
+    )
+  );
+
+  return tx;
+};
+
+export const createInitAuctionDataTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+) => {
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>initializing auction PDA",
+    mint.toBase58(),
+    nftData.toBase58()
+  );
+
+  tx.add(
+    program.instruction.initAuctionData(mint, nft_bump, {
+      accounts: {
+        payer: userAddress,
+        auctionDataInfo: nftData,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createCloseAuctionDataTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  program: anchor.Program
+) => {
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+  console.log(
+    "==>closing auction PDA",
+    mint.toBase58(),
+    auctionData.toBase58()
+  );
+
+  tx.add(
+    program.instruction.closeAuctionData(auction_bump, {
+      accounts: {
+        payer: userAddress,
+        auctionDataInfo: auctionData,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createCreateAuctionTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  startPrice: number,
+  minIncrease: number,
+  duration: number,
+  reserved: boolean,
+  program: anchor.Program,
+  connection: Connection
+) => {
+  if (startPrice < 0 || minIncrease < 0 || duration < 0) {
+    throw "Invalid Price Value";
+  }
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+  if (!(await isExistAccount(userTokenAccount, connection))) {
+    let accountOfNFT = await getNFTTokenAccount(mint, connection);
+    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
+      let nftOwner = await getOwnerOfNFT(mint, connection);
+      if (nftOwner.toBase58() == userAddress.toBase58())
+        userTokenAccount = accountOfNFT;
+      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
+        throw "Error: Nft is not owned by user";
+      }
+    }
+  }
+  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellData, sell_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+
+  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
+  console.log(
+    "==>creating Auction",
+    mint.toBase58(),
+    startPrice,
+    minIncrease,
+    duration,
+    reserved
+  );
+
+  tx.add(
+    program.instruction.createAuction(
+      bump,
+      nft_bump,
+      sell_bump,
+      new anchor.BN(startPrice),
+      new anchor.BN(minIncrease),
+      new anchor.BN(duration),
+      new anchor.BN(reserved ? 1 : 0),
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          auctionDataInfo: nftData,
+          userTokenAccount,
+          destNftTokenAccount: destinationAccounts[0],
+          nftMint: mint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          sellDataInfo: sellData,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
+  );
+
+  return tx;
+};
+export const createCreateAuctionPnftTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  startPrice: number,
+  minIncrease: number,
+  duration: number,
+  reserved: boolean,
+  program: anchor.Program,
+  connection: Connection,
+  pricingMode: number = PRICING_MODE_DEFAULT,
+  extensionWindow: number = 0,
+  extensionLength: number = 0,
+  reservePrice: number = 0,
+  reserveHash: Buffer | null = null
+) => {
+  if (startPrice < 0 || minIncrease < 0 || duration < 0 || reservePrice < 0) {
+    throw "Invalid Price Value";
+  }
+
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
+  if (!(await isExistAccount(userTokenAccount, connection))) {
+    let accountOfNFT = await getNFTTokenAccount(mint, connection);
+    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
+      let nftOwner = await getOwnerOfNFT(mint, connection);
+      if (nftOwner.toBase58() == userAddress.toBase58())
+        userTokenAccount = accountOfNFT;
+      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
+        throw "Error: Nft is not owned by user";
+      }
+    }
+  }
+  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+
+  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sellData, sell_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
 
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    globalAuthority,
+    [mint]
+  );
+  const destTokenAccount = destinationAccounts[0];
+  console.log("Dest NFT Account = ", destTokenAccount.toBase58());
   const nftEdition = await getMasterEdition(mint);
   console.log("nftEdition:", nftEdition);
 
   const tokenMintRecord = findTokenRecordPda(
     new anchor.web3.PublicKey(mint),
-    userNftTokenAccount
+    userTokenAccount
+  );
+  const userAccountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(userTokenAccount)
   );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
 
   const destTokenMintRecord = findTokenRecordPda(
     new anchor.web3.PublicKey(mint),
-    destNftTokenAccount
-  );
-  const mintMetadata = await getMetadata(mint);
-  console.log("Metadata=", mintMetadata.toBase58());
-
-  let {
-    metadata: { Metadata },
-  } = programs;
-  let metadataAccount = await Metadata.getPDA(mint);
-  const metadata = await Metadata.load(connection, metadataAccount);
-  let creators = metadata.data.data.creators;
-
-  let treasuryAccounts: PublicKey[] = treasuryAddresses;
-  console.log(
-    "=> Treasury Accounts:",
-    treasuryAccounts.map((address) => address.toBase58())
-  );
-
-  let remainingAccounts = [];
-  treasuryAccounts.map((address) => {
-    remainingAccounts.push({
-      pubkey: address,
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-  creators.map((creator) => {
-    remainingAccounts.push({
-      pubkey: new PublicKey(creator.address),
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-  console.log("==> Purchasing", mint.toBase58());
-  tx.add(
-    program.instruction.purchasePnft(bump, nft_bump, buyer_bump, seller_bump, {
-      accounts: {
-        buyer: userAddress,
-        globalAuthority,
-        buyerUserPool,
-        sellDataInfo: nftData,
-        userNftTokenAccount,
-        destNftTokenAccount: destNftTokenAccount,
-        nftMint: mint,
-        tokenMint: mint,
-        seller,
-        // creator,
-        sellerUserPool,
-        mintMetadata,
-        tokenMintEdition: nftEdition,
-        tokenMintRecord: tokenMintRecord,
-        destTokenMintRecord: destTokenMintRecord,
-        systemProgram: SystemProgram.programId,
-        auctionDataInfo: auctionData,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        tokenMetadataProgram: METAPLEX,
-        authRules: MPL_DEFAULT_RULE_SET,
-        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        authRulesProgram: TOKEN_AUTH_RULES_ID,
-      },
-      instructions: [],
-      signers: [],
-      remainingAccounts,
-    })
+    destTokenAccount
   );
-
-  return tx;
-};
-
-export const createInitOfferDataTx = async (
-  mint: PublicKey,
-  userAddress: PublicKey,
-  program: anchor.Program
-) => {
-  const [offerData, bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
+  const accountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destTokenAccount)
   );
+  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
+  console.log("accountExisted: ", accountExisted);
+  console.log("instructions: ", instructions);
 
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
   let tx = txWithComputeUnitsIxs();
+
+  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
   console.log(
-    "==>initializing offer PDA",
+    "==>creating Auction",
     mint.toBase58(),
-    offerData.toBase58()
+    startPrice,
+    minIncrease,
+    duration,
+    reserved,
+    "Reserve:",
+    reserveHash ? "hidden" : reservePrice
   );
 
   tx.add(
-    program.instruction.initOfferData(mint, bump, {
-      accounts: {
-        payer: userAddress,
-        offerDataInfo: offerData,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
-      },
-      instructions: [],
-      signers: [],
-    })
+    program.instruction.createAuctionPnft(
+      bump,
+      auction_bump,
+      sell_bump,
+      new anchor.BN(startPrice),
+      new anchor.BN(minIncrease),
+      new anchor.BN(duration),
+      reserved ? 1 : 0,
+      new anchor.BN(pricingMode),
+      new anchor.BN(extensionWindow),
+      new anchor.BN(extensionLength),
+      new anchor.BN(reservePrice),
+      reserveHash ? [...reserveHash] : null,
+      {
+        accounts: {
+          owner: userAddress,
+          globalAuthority,
+          auctionDataInfo: auctionData,
+          userTokenAccount,
+          destNftTokenAccount: destTokenAccount,
+          nftMint: mint,
+          tokenMint: mint,
+          mintMetadata: metadata,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
+          destTokenMintRecord: destTokenMintRecord,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: METAPLEX,
+          authRules: MPL_DEFAULT_RULE_SET,
+          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          authRulesProgram: TOKEN_AUTH_RULES_ID,
+          sellDataInfo: sellData,
+        },
+        instructions: [],
+        signers: [],
+      }
+    )
   );
 
   return tx;
 };
 
-export const createMakeOfferTx = async (
+export const createPlaceBidTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
   price: number,
   program: anchor.Program
 ) => {
+  let auctionInfo = await getAuctionDataState(mint, program);
+
   let tx = txWithComputeUnitsIxs();
 
+  const [globalAuthority] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+  const [sellData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
@@ -1450,145 +4108,261 @@ export const createMakeOfferTx = async (
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+  const [bidderUserPool] = await PublicKey.findProgramAddress(
     [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
   console.log("escrowVault = ", escrowVault.toBase58());
 
+  // The bid outbid by the last bid is refunded, the last bid stays locked for the retraction
+  let outBidder = userAddress;
+  let outBidderUserPool = MARKETPLACE_PROGRAM_ID;
+  if (auctionInfo.prevBidder.toBase58() != PublicKey.default.toBase58()) {
+    outBidder = auctionInfo.prevBidder;
+    if (outBidder.toBase58() != userAddress.toBase58()) {
+      [outBidderUserPool] = await PublicKey.findProgramAddress(
+        [Buffer.from(USER_DATA_SEED), outBidder.toBuffer()],
+        MARKETPLACE_PROGRAM_ID
+      );
+    }
+  }
+
   console.log(
-    "==> making Offer",
+    "==> placing Bid",
     mint.toBase58(),
     userAddress.toBase58(),
     "Price:",
-    price
+    price,
+    "LastBidder:",
+    outBidder.toBase58()
   );
   tx.add(
-    program.instruction.makeOffer(
-      nft_bump,
-      offer_bump,
-      user_bump,
-      escrow_bump,
-      new anchor.BN(price),
-      {
-        accounts: {
-          owner: userAddress,
-          sellDataInfo: nftData,
-          offerDataInfo: offerData,
-          nftMint: mint,
-          userPool,
-          escrowVault,
-          systemProgram: SystemProgram.programId,
-        },
-        instructions: [],
-        signers: [],
-      }
-    )
+    program.instruction.placeBid(nft_bump, escrow_bump, new anchor.BN(price), {
+      accounts: {
+        bidder: userAddress,
+        globalAuthority,
+        auctionDataInfo: nftData,
+        nftMint: mint,
+        escrowVault,
+        outBidder,
+        bidderUserPool,
+        outBidderUserPool,
+        systemProgram: SystemProgram.programId,
+        sellDataInfo: sellData,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
   return tx;
 };
 
-export const createCancelOfferTx = async (
+export const createClaimAuctionTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  program: anchor.Program
+  treasuryAddresses: PublicKey[],
+  program: anchor.Program,
+  connection: Connection
 ) => {
-  let tx = txWithComputeUnitsIxs();
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
 
-  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), userAddress.toBuffer()],
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
+  let tx = txWithComputeUnitsIxs();
+  let userTokenAccount = ret.destinationAccounts[0];
+  let destNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
+  );
+  console.log("Bidder NFT Account = ", userTokenAccount.toBase58());
+
+  let auctionInfo = await getAuctionDataState(mint, program);
+  let creator = auctionInfo.creator;
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
   const [userPool, user_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(ESCROW_VAULT_SEED)],
+  const [creatorUserPool, creator_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), creator.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("==> canceling Offer", mint.toBase58(), userAddress.toBase58());
+  const mintMetadata = await getMetadata(mint);
+  console.log("Metadata=", mintMetadata.toBase58());
+
+  let {
+    metadata: { Metadata },
+  } = programs;
+  let metadataAccount = await Metadata.getPDA(mint);
+  const metadata = await Metadata.load(connection, metadataAccount);
+  let creators = metadata.data.data.creators;
+
+  let treasuryAccounts: PublicKey[] = treasuryAddresses;
+  console.log(
+    "=> Treasury Accounts:",
+    treasuryAccounts.map((address) => address.toBase58())
+  );
+
+  let remainingAccounts = [];
+  treasuryAccounts.map((address) => {
+    remainingAccounts.push({
+      pubkey: address,
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+  creators.map((creator) => {
+    remainingAccounts.push({
+      pubkey: new PublicKey(creator.address),
+      isWritable: true,
+      isSigner: false,
+    });
+  });
+
+  console.log(
+    "==> claiming Auction",
+    mint.toBase58(),
+    userAddress.toBase58(),
+    "Creator:",
+    creator.toBase58()
+  );
   tx.add(
-    program.instruction.cancelOffer(offer_bump, escrow_bump, user_bump, {
+    program.instruction.claimAuction(bump, nft_bump, escrow_bump, {
       accounts: {
-        owner: userAddress,
-        offerDataInfo: offerData,
-        userPool: userPool,
-        systemProgram: SystemProgram.programId,
-        escrowVault: escrowVault,
+        bidder: userAddress,
+        globalAuthority,
+        auctionDataInfo: nftData,
+        userTokenAccount: ret.destinationAccounts[0],
+        destNftTokenAccount,
         nftMint: mint,
+        escrowVault,
+        creator,
+        bidderUserPool: userPool,
+        creatorUserPool,
+        mintMetadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenMetadataProgram: METAPLEX,
       },
       instructions: [],
       signers: [],
+      remainingAccounts,
     })
   );
 
   return tx;
 };
 
-export const createAcceptOfferTx = async (
+export const createClaimAuctionPnftTx = async (
   mint: PublicKey,
-  buyer: PublicKey,
+  userAddress: PublicKey,
   treasuryAddresses: PublicKey[],
   program: anchor.Program,
   connection: Connection
 ) => {
-  let sellInfo = await getNFTPoolState(mint, program);
-  let seller = sellInfo.seller;
-  let offerInfo = await getOfferDataState(mint, buyer, program);
-
-  let tx = txWithComputeUnitsIxs();
+  // Anyone can settle the ended auction, the NFT goes to the winning bidder
+  let auctionInfo = await getAuctionDataState(mint, program);
+  let bidder = auctionInfo.lastBidder;
+  let creator = auctionInfo.creator;
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    bidder,
+    [mint]
+  );
 
   const [globalAuthority, bump] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
-
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
+  // Global authority ATA clears the SPL delegate when the owner is not signing
+  const globalNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
   );
 
-  const [auctionData, _] = await PublicKey.findProgramAddress(
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
   const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(ESCROW_VAULT_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
+  let tx = txWithComputeUnitsIxs();
+  let userTokenAccount = ret.destinationAccounts[0];
+  console.log("Bidder NFT Account = ", userTokenAccount.toBase58());
+
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(creator, mint);
+
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), bidder.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
+  // The outbid bid still locked is refunded on the settlement
+  let prevBidder = MARKETPLACE_PROGRAM_ID;
+  let prevBidderUserPool = MARKETPLACE_PROGRAM_ID;
+  if (auctionInfo.prevBidder.toBase58() != PublicKey.default.toBase58()) {
+    prevBidder = auctionInfo.prevBidder;
+    [prevBidderUserPool] = await PublicKey.findProgramAddress(
+      [Buffer.from(USER_DATA_SEED), prevBidder.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+  }
+
+  const [creatorUserPool, creator_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), creator.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
 
-  let ret = await getATokenAccountsNeedCreate(connection, seller, buyer, [
-    mint,
-  ]);
-
-  let destNftTokenAccount = await getAssociatedTokenAccount(
-    globalAuthority,
-    mint
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    userTokenAccount
+  );
+  const userAccountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(userTokenAccount)
   );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
 
-  console.log("escrowVault = ", escrowVault.toBase58());
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+  const accountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destNftTokenAccount)
+  );
+  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
+  console.log("accountExisted: ", accountExisted);
 
   const mintMetadata = await getMetadata(mint);
   console.log("Metadata=", mintMetadata.toBase58());
@@ -1600,8 +4374,6 @@ export const createAcceptOfferTx = async (
   const metadata = await Metadata.load(connection, metadataAccount);
   let creators = metadata.data.data.creators;
 
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-
   let treasuryAccounts: PublicKey[] = treasuryAddresses;
   console.log(
     "=> Treasury Accounts:",
@@ -1625,83 +4397,127 @@ export const createAcceptOfferTx = async (
   });
 
   console.log(
-    "==> Accept Offer  Mint:",
+    "==> claiming Auction",
     mint.toBase58(),
-    "Buyer:",
-    buyer.toBase58(),
-    "Seller:",
-    seller.toBase58(),
-    "OfferPrice:",
-    offerInfo.offerPrice.toNumber()
+    bidder.toBase58(),
+    "Creator:",
+    creator.toBase58()
+  );
+  tx.add(
+    program.instruction.claimAuctionPnft(bump, nft_bump, escrow_bump, {
+      accounts: {
+        payer: userAddress,
+        bidder,
+        globalAuthority,
+        auctionDataInfo: nftData,
+        userTokenAccount: ret.destinationAccounts[0],
+        destNftTokenAccount,
+        nftMint: mint,
+        escrowVault,
+        creator,
+        prevBidder,
+        prevBidderUserPool,
+        bidderUserPool: userPool,
+        creatorUserPool,
+        mintMetadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenMetadataProgram: METAPLEX,
+        tokenMintEdition: nftEdition,
+        tokenMintRecord: tokenMintRecord,
+        destTokenMintRecord: destTokenMintRecord,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+        globalNftTokenAccount,
+      },
+      instructions: [],
+      signers: [],
+      remainingAccounts,
+    })
+  );
+
+  return tx;
+};
+
+export const createUpdateReserveTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  newPrice: number,
+  program: anchor.Program
+) => {
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
   );
 
+  let tx = txWithComputeUnitsIxs();
+
+  console.log("==> Updating reserved price", mint.toBase58(), newPrice);
   tx.add(
-    program.instruction.acceptOffer(
-      bump,
-      nft_bump,
-      offer_bump,
-      buyer_bump,
-      seller_bump,
-      escrow_bump,
-      {
-        accounts: {
-          seller,
-          sellDataInfo: nftData,
-          buyer,
-          offerDataInfo: offerData,
-          sellerUserPool,
-          nftMint: mint,
-          globalAuthority,
-          buyerUserPool,
-          userNftTokenAccount: ret.destinationAccounts[0],
-          destNftTokenAccount,
-          escrowVault,
-          mintMetadata,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-          tokenMetadataProgram: METAPLEX,
-          auctionDataInfo: auctionData,
-        },
-        instructions: [],
-        signers: [],
-        remainingAccounts,
-      }
-    )
+    program.instruction.updateReserve(nft_bump, new anchor.BN(newPrice), {
+      accounts: {
+        creator: userAddress,
+        auctionDataInfo: nftData,
+        nftMint: mint,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createRevealReserveTx = async (
+  mint: PublicKey,
+  userAddress: PublicKey,
+  reservePrice: number,
+  salt: Buffer,
+  program: anchor.Program
+) => {
+  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+
+  console.log("==> Revealing reserve price", mint.toBase58(), reservePrice);
+  tx.add(
+    program.instruction.revealReserve(new anchor.BN(reservePrice), [...salt], {
+      accounts: {
+        creator: userAddress,
+        auctionDataInfo: nftData,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
   return tx;
 };
 
-export const createAcceptOfferPNftTx = async (
+export const createCancelBidTx = async (
   mint: PublicKey,
-  buyer: PublicKey,
-  treasuryAddresses: PublicKey[],
-  program: anchor.Program,
-  connection: Connection
+  userAddress: PublicKey,
+  program: anchor.Program
 ) => {
-  let sellInfo = await getNFTPoolState(mint, program);
-  let seller = sellInfo.seller;
-  let offerInfo = await getOfferDataState(mint, buyer, program);
-
   let tx = txWithComputeUnitsIxs();
 
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [auctionData, _] = await PublicKey.findProgramAddress(
     [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [offerData, offer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(OFFER_DATA_SEED), mint.toBuffer(), buyer.toBuffer()],
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
@@ -1710,150 +4526,76 @@ export const createAcceptOfferPNftTx = async (
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [buyerUserPool, buyer_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), buyer.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [sellerUserPool, seller_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), seller.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  let ret = await getATokenAccountsNeedCreate(connection, seller, buyer, [
-    mint,
-  ]);
-
-  let destNftTokenAccount = await getAssociatedTokenAccount(seller, mint);
-
-  const nftEdition = await getMasterEdition(mint);
-  console.log("nftEdition:", nftEdition);
-
-  const tokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    ret.destinationAccounts[0]
-  );
-
-  const destTokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    destNftTokenAccount
-  );
-
-  console.log("escrowVault = ", escrowVault.toBase58());
-
-  const mintMetadata = await getMetadata(mint);
-  console.log("Metadata=", mintMetadata.toBase58());
-
-  let {
-    metadata: { Metadata },
-  } = programs;
-  let metadataAccount = await Metadata.getPDA(mint);
-  const metadata = await Metadata.load(connection, metadataAccount);
-  let creators = metadata.data.data.creators;
-
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-
-  let treasuryAccounts: PublicKey[] = treasuryAddresses;
-  console.log(
-    "=> Treasury Accounts:",
-    treasuryAccounts.map((address) => address.toBase58())
-  );
-
-  let remainingAccounts = [];
-  treasuryAccounts.map((address) => {
-    remainingAccounts.push({
-      pubkey: address,
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-  creators.map((creator) => {
-    remainingAccounts.push({
-      pubkey: new PublicKey(creator.address),
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-
-  console.log(
-    "==> Accept Offer  Mint:",
-    mint.toBase58(),
-    "Buyer:",
-    buyer.toBase58(),
-    "Seller:",
-    seller.toBase58(),
-    "OfferPrice:",
-    offerInfo.offerPrice.toNumber()
-  );
-
+  console.log("==> canceling Bid", mint.toBase58(), userAddress.toBase58());
   tx.add(
-    program.instruction.acceptOfferPnft(
-      bump,
-      nft_bump,
-      offer_bump,
-      buyer_bump,
-      seller_bump,
-      escrow_bump,
-      {
-        accounts: {
-          seller,
-          sellDataInfo: nftData,
-          buyer,
-          offerDataInfo: offerData,
-          sellerUserPool,
-          nftMint: mint,
-          globalAuthority,
-          buyerUserPool,
-          userNftTokenAccount: ret.destinationAccounts[0],
-          destNftTokenAccount,
-          escrowVault,
-          mintMetadata,
-          tokenMintEdition: nftEdition,
-          tokenMintRecord: tokenMintRecord,
-          destTokenMintRecord: destTokenMintRecord,
-          systemProgram: SystemProgram.programId,
-          auctionDataInfo: auctionData,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          tokenMetadataProgram: METAPLEX,
-          authRules: MPL_DEFAULT_RULE_SET,
-          sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          authRulesProgram: TOKEN_AUTH_RULES_ID,
-        },
-        instructions: [],
-        signers: [],
-        remainingAccounts,
-      }
-    )
+    program.instruction.cancelBid(escrow_bump, {
+      accounts: {
+        bidder: userAddress,
+        globalAuthority,
+        auctionDataInfo: nftData,
+        userPool,
+        escrowVault,
+        systemProgram: SystemProgram.programId,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
   return tx;
 };
 
-export const createInitAuctionDataTx = async (
+export const createCancelAuctionTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  program: anchor.Program
+  program: anchor.Program,
+  connection: Connection
 ) => {
+  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
+
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let tx = txWithComputeUnitsIxs();
-  console.log(
-    "==>initializing auction PDA",
-    mint.toBase58(),
-    nftData.toBase58()
+  const [sellData, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    userAddress,
+    [mint]
+  );
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
   );
 
+  console.log("User NFT Account = ", destinationAccounts[0].toBase58());
+
+  let tx = txWithComputeUnitsIxs();
+
+  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
+
+  console.log("==> canceling Auction", mint.toBase58());
   tx.add(
-    program.instruction.initAuctionData(mint, nft_bump, {
+    program.instruction.cancelAuction(bump, nft_bump, {
       accounts: {
-        payer: userAddress,
+        creator: userAddress,
+        globalAuthority,
         auctionDataInfo: nftData,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY,
+        userTokenAccount: destinationAccounts[0],
+        destNftTokenAccount,
+        nftMint: mint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        sellDataInfo: sellData,
       },
       instructions: [],
       signers: [],
@@ -1863,109 +4605,143 @@ export const createInitAuctionDataTx = async (
   return tx;
 };
 
-export const createCreateAuctionTx = async (
+export const createCancelAuctionPnftTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  startPrice: number,
-  minIncrease: number,
-  duration: number,
-  reserved: boolean,
   program: anchor.Program,
   connection: Connection
 ) => {
-  if (startPrice < 0 || minIncrease < 0 || duration < 0) {
-    throw "Invalid Price Value";
-  }
-
   const [globalAuthority, bump] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
-
-  let userTokenAccount = await getAssociatedTokenAccount(userAddress, mint);
-  if (!(await isExistAccount(userTokenAccount, connection))) {
-    let accountOfNFT = await getNFTTokenAccount(mint, connection);
-    if (userTokenAccount.toBase58() != accountOfNFT.toBase58()) {
-      let nftOwner = await getOwnerOfNFT(mint, connection);
-      if (nftOwner.toBase58() == userAddress.toBase58())
-        userTokenAccount = accountOfNFT;
-      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
-        throw "Error: Nft is not owned by user";
-      }
-    }
-  }
-  console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
+  // Global authority ATA clears the SPL delegate when the owner is not signing
+  const globalNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
+  );
 
   const [nftData, nft_bump] = await PublicKey.findProgramAddress(
     [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [sellData, sell_bump] = await PublicKey.findProgramAddress(
+  const [sellData, _] = await PublicKey.findProgramAddress(
     [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
+  // Anyone can close the auction ended without bids, the NFT stays with the creator
+  let auctionInfo = await getAuctionDataState(mint, program);
+  let creator = auctionInfo.creator;
   let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
     connection,
     userAddress,
-    globalAuthority,
+    creator,
     [mint]
   );
 
-  console.log("Dest NFT Account = ", destinationAccounts[0].toBase58());
-
+  let destNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
+  );
+
+  console.log("User NFT Account = ", destinationAccounts[0].toBase58());
+  const nftEdition = await getMasterEdition(mint);
+  console.log("nftEdition:", nftEdition);
+
+  const tokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destinationAccounts[0]
+  );
+  const userAccountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destinationAccounts[0])
+  );
+  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
+
+  const destTokenMintRecord = findTokenRecordPda(
+    new anchor.web3.PublicKey(mint),
+    destNftTokenAccount
+  );
+  const accountExisted = await connection.getAccountInfo(
+    new anchor.web3.PublicKey(destNftTokenAccount)
+  );
+  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
+  console.log("accountExisted: ", accountExisted);
+  console.log("instructions: ", instructions);
+
+  const metadata = await getMetadata(mint);
+  console.log("Metadata=", metadata.toBase58());
   let tx = txWithComputeUnitsIxs();
 
   if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-  console.log(
-    "==>creating Auction",
-    mint.toBase58(),
-    startPrice,
-    minIncrease,
-    duration,
-    reserved
-  );
 
+  console.log("==> canceling Auction", mint.toBase58());
   tx.add(
-    program.instruction.createAuction(
-      bump,
-      nft_bump,
-      sell_bump,
-      new anchor.BN(startPrice),
-      new anchor.BN(minIncrease),
-      new anchor.BN(duration),
-      new anchor.BN(reserved ? 1 : 0),
-      {
-        accounts: {
-          owner: userAddress,
-          globalAuthority,
-          auctionDataInfo: nftData,
-          userTokenAccount,
-          destNftTokenAccount: destinationAccounts[0],
-          nftMint: mint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          sellDataInfo: sellData,
-        },
-        instructions: [],
-        signers: [],
-      }
-    )
+    program.instruction.cancelAuctionPnft(bump, nft_bump, {
+      accounts: {
+        payer: userAddress,
+        creator,
+        globalAuthority,
+        auctionDataInfo: nftData,
+        userTokenAccount: destinationAccounts[0],
+        destNftTokenAccount,
+        nftMint: mint,
+        mintMetadata: metadata,
+        tokenMintEdition: nftEdition,
+        tokenMintRecord: tokenMintRecord,
+        destTokenMintRecord: destTokenMintRecord,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METAPLEX,
+        authRules: MPL_DEFAULT_RULE_SET,
+        sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        authRulesProgram: TOKEN_AUTH_RULES_ID,
+        sellDataInfo: sellData,
+        globalNftTokenAccount,
+      },
+      instructions: [],
+      signers: [],
+    })
   );
 
   return tx;
 };
-export const createCreateAuctionPnftTx = async (
+
+export const getSealedAuctionState = async (
+  mint: PublicKey,
+  program: anchor.Program
+): Promise<SealedAuctionData | null> => {
+  if (!mint) return null;
+
+  const [sealedAuction, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+  console.log("Sealed Auction PDA: ", sealedAuction.toBase58());
+  try {
+    let poolState = await program.account.sealedAuctionData.fetch(
+      sealedAuction
+    );
+    return poolState as unknown as SealedAuctionData;
+  } catch {
+    return null;
+  }
+};
+
+export const createCreateSealedAuctionPnftTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  startPrice: number,
-  minIncrease: number,
-  duration: number,
-  reserved: boolean,
+  minPrice: number,
+  commitDuration: number,
+  revealDuration: number,
+  priceRule: number,
+  pricingMode: number,
   program: anchor.Program,
   connection: Connection
 ) => {
-  if (startPrice < 0 || minIncrease < 0 || duration < 0) {
+  if (minPrice < 0 || commitDuration <= 0 || revealDuration <= 0) {
     throw "Invalid Price Value";
   }
 
@@ -1981,15 +4757,15 @@ export const createCreateAuctionPnftTx = async (
       let nftOwner = await getOwnerOfNFT(mint, connection);
       if (nftOwner.toBase58() == userAddress.toBase58())
         userTokenAccount = accountOfNFT;
-      else if (nftOwner.toBase58() !== globalAuthority.toBase58()) {
+      else {
         throw "Error: Nft is not owned by user";
       }
     }
   }
   console.log("NFT = ", mint.toBase58(), userTokenAccount.toBase58());
 
-  const [auctionData, auction_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  const [sealedAuction, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
@@ -1998,14 +4774,6 @@ export const createCreateAuctionPnftTx = async (
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    globalAuthority,
-    [mint]
-  );
-  const destTokenAccount = destinationAccounts[0];
-  console.log("Dest NFT Account = ", destTokenAccount.toBase58());
   const nftEdition = await getMasterEdition(mint);
   console.log("nftEdition:", nftEdition);
 
@@ -2013,66 +4781,48 @@ export const createCreateAuctionPnftTx = async (
     new anchor.web3.PublicKey(mint),
     userTokenAccount
   );
-  const userAccountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(userTokenAccount)
-  );
   console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
 
-  const destTokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    destTokenAccount
-  );
-  const accountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destTokenAccount)
-  );
-  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
-  console.log("accountExisted: ", accountExisted);
-  console.log("instructions: ", instructions);
-
   const metadata = await getMetadata(mint);
   console.log("Metadata=", metadata.toBase58());
   let tx = txWithComputeUnitsIxs();
 
-  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
   console.log(
-    "==>creating Auction",
+    "==>creating Sealed Auction",
     mint.toBase58(),
-    startPrice,
-    minIncrease,
-    duration,
-    reserved
+    minPrice,
+    commitDuration,
+    revealDuration,
+    "PriceRule:",
+    priceRule
   );
 
   tx.add(
-    program.instruction.createAuctionPnft(
+    program.instruction.createSealedAuctionPnft(
       bump,
-      auction_bump,
-      sell_bump,
-      new anchor.BN(startPrice),
-      new anchor.BN(minIncrease),
-      new anchor.BN(duration),
-      new anchor.BN(reserved ? 1 : 0),
+      new anchor.BN(minPrice),
+      new anchor.BN(commitDuration),
+      new anchor.BN(revealDuration),
+      new anchor.BN(priceRule),
+      new anchor.BN(pricingMode),
       {
         accounts: {
           owner: userAddress,
           globalAuthority,
-          auctionDataInfo: auctionData,
+          sealedAuctionInfo: sealedAuction,
+          sellDataInfo: sellData,
           userTokenAccount,
-          destNftTokenAccount: destTokenAccount,
           nftMint: mint,
-          tokenMint: mint,
           mintMetadata: metadata,
-          tokenMintEdition: nftEdition,
-          tokenMintRecord: tokenMintRecord,
-          destTokenMintRecord: destTokenMintRecord,
-          systemProgram: SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
           tokenMetadataProgram: METAPLEX,
+          tokenMintEdition: nftEdition,
+          tokenMintRecord: tokenMintRecord,
           authRules: MPL_DEFAULT_RULE_SET,
           sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           authRulesProgram: TOKEN_AUTH_RULES_ID,
-          sellDataInfo: sellData,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
         },
         instructions: [],
         signers: [],
@@ -2083,57 +4833,58 @@ export const createCreateAuctionPnftTx = async (
   return tx;
 };
 
-export const createPlaceBidTx = async (
+export const createCommitSealedBidTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  price: number,
+  bidHash: Buffer,
+  deposit: number,
   program: anchor.Program
 ) => {
-  let auctionInfo = await getAuctionDataState(mint, program);
-
   let tx = txWithComputeUnitsIxs();
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [sellData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [sealedAuction, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(ESCROW_VAULT_SEED)],
+  const [sealedBid, bid_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_BID_SEED), mint.toBuffer(), userAddress.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("escrowVault = ", escrowVault.toBase58());
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
 
-  let outBidder = userAddress;
-  if (auctionInfo.lastBidder.toBase58() != PublicKey.default.toBase58()) {
-    outBidder = auctionInfo.lastBidder;
-  }
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
+  );
 
   console.log(
-    "==> placing Bid",
+    "==> committing Sealed Bid",
     mint.toBase58(),
     userAddress.toBase58(),
-    "Price:",
-    price,
-    "LastBidder:",
-    outBidder.toBase58()
+    "Deposit:",
+    deposit
   );
   tx.add(
-    program.instruction.placeBid(nft_bump, escrow_bump, new anchor.BN(price), {
+    program.instruction.commitSealedBid([...bidHash], new anchor.BN(deposit), {
       accounts: {
         bidder: userAddress,
-        auctionDataInfo: nftData,
-        nftMint: mint,
+        globalAuthority,
+        sealedAuctionInfo: sealedAuction,
+        sealedBidInfo: sealedBid,
+        bidderUserPool: userPool,
         escrowVault,
-        outBidder,
         systemProgram: SystemProgram.programId,
-        sellDataInfo: sellData,
+        rent: SYSVAR_RENT_PUBKEY,
       },
       instructions: [],
       signers: [],
@@ -2143,144 +4894,71 @@ export const createPlaceBidTx = async (
   return tx;
 };
 
-export const createClaimAuctionTx = async (
+export const createRevealSealedBidTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  treasuryAddresses: PublicKey[],
-  program: anchor.Program,
-  connection: Connection
+  amount: number,
+  salt: Buffer,
+  program: anchor.Program
 ) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
-
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(GLOBAL_AUTHORITY_SEED)],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(ESCROW_VAULT_SEED)],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  let tx = txWithComputeUnitsIxs();
-  let userTokenAccount = ret.destinationAccounts[0];
-  let destNftTokenAccount = await getAssociatedTokenAccount(
-    globalAuthority,
-    mint
-  );
-  console.log("Bidder NFT Account = ", userTokenAccount.toBase58());
-
-  let auctionInfo = await getAuctionDataState(mint, program);
-  let creator = auctionInfo.creator;
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-
-  const [userPool, user_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const [creatorUserPool, creator_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), creator.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
-  const mintMetadata = await getMetadata(mint);
-  console.log("Metadata=", mintMetadata.toBase58());
-
-  let {
-    metadata: { Metadata },
-  } = programs;
-  let metadataAccount = await Metadata.getPDA(mint);
-  const metadata = await Metadata.load(connection, metadataAccount);
-  let creators = metadata.data.data.creators;
-
-  let treasuryAccounts: PublicKey[] = treasuryAddresses;
-  console.log(
-    "=> Treasury Accounts:",
-    treasuryAccounts.map((address) => address.toBase58())
-  );
-
-  let remainingAccounts = [];
-  treasuryAccounts.map((address) => {
-    remainingAccounts.push({
-      pubkey: address,
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-  creators.map((creator) => {
-    remainingAccounts.push({
-      pubkey: new PublicKey(creator.address),
-      isWritable: true,
-      isSigner: false,
-    });
-  });
+  let tx = txWithComputeUnitsIxs();
+
+  const [sealedAuction, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [sealedBid, bid_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_BID_SEED), mint.toBuffer(), userAddress.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
 
   console.log(
-    "==> claiming Auction",
+    "==> revealing Sealed Bid",
     mint.toBase58(),
     userAddress.toBase58(),
-    "Creator:",
-    creator.toBase58()
+    "Amount:",
+    amount
   );
   tx.add(
-    program.instruction.claimAuction(bump, nft_bump, escrow_bump, {
+    program.instruction.revealSealedBid(new anchor.BN(amount), [...salt], {
       accounts: {
         bidder: userAddress,
-        globalAuthority,
-        auctionDataInfo: nftData,
-        userTokenAccount: ret.destinationAccounts[0],
-        destNftTokenAccount,
-        nftMint: mint,
-        escrowVault,
-        creator,
-        bidderUserPool: userPool,
-        creatorUserPool,
-        mintMetadata,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-        tokenMetadataProgram: METAPLEX,
+        sealedAuctionInfo: sealedAuction,
+        sealedBidInfo: sealedBid,
       },
       instructions: [],
       signers: [],
-      remainingAccounts,
     })
   );
 
   return tx;
 };
 
-export const createClaimAuctionPnftTx = async (
+export const createSettleSealedAuctionTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
   treasuryAddresses: PublicKey[],
   program: anchor.Program,
   connection: Connection
 ) => {
-  let ret = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
+  // Anyone can settle the sealed auction, the NFT goes to the highest revealed bidder
+  let auctionInfo = await getSealedAuctionState(mint, program);
+  let winner = auctionInfo.highestBidder;
+  let creator = auctionInfo.creator;
 
   const [globalAuthority, bump] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  const [sealedAuction, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  const [winnerBid, bid_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_BID_SEED), mint.toBuffer(), winner.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
@@ -2289,18 +4967,8 @@ export const createClaimAuctionPnftTx = async (
     MARKETPLACE_PROGRAM_ID
   );
 
-  let tx = txWithComputeUnitsIxs();
-  let userTokenAccount = ret.destinationAccounts[0];
-  console.log("Bidder NFT Account = ", userTokenAccount.toBase58());
-
-  let auctionInfo = await getAuctionDataState(mint, program);
-  let creator = auctionInfo.creator;
-  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
-
-  let destNftTokenAccount = await getAssociatedTokenAccount(creator, mint);
-
-  const [userPool, user_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(USER_DATA_SEED), userAddress.toBuffer()],
+  const [winnerUserPool, winner_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), winner.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
@@ -2308,6 +4976,18 @@ export const createClaimAuctionPnftTx = async (
     [Buffer.from(USER_DATA_SEED), creator.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
+
+  let ret = await getATokenAccountsNeedCreate(
+    connection,
+    userAddress,
+    winner,
+    [mint]
+  );
+  let userTokenAccount = ret.destinationAccounts[0];
+  console.log("Winner NFT Account = ", userTokenAccount.toBase58());
+
+  let destNftTokenAccount = await getAssociatedTokenAccount(creator, mint);
+
   const nftEdition = await getMasterEdition(mint);
   console.log("nftEdition:", nftEdition);
 
@@ -2315,72 +4995,46 @@ export const createClaimAuctionPnftTx = async (
     new anchor.web3.PublicKey(mint),
     userTokenAccount
   );
-  const userAccountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(userTokenAccount)
-  );
-  console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
 
   const destTokenMintRecord = findTokenRecordPda(
     new anchor.web3.PublicKey(mint),
     destNftTokenAccount
   );
-  const accountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destNftTokenAccount)
-  );
-  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
-  console.log("accountExisted: ", accountExisted);
 
   const mintMetadata = await getMetadata(mint);
   console.log("Metadata=", mintMetadata.toBase58());
 
-  let {
-    metadata: { Metadata },
-  } = programs;
-  let metadataAccount = await Metadata.getPDA(mint);
-  const metadata = await Metadata.load(connection, metadataAccount);
-  let creators = metadata.data.data.creators;
-
-  let treasuryAccounts: PublicKey[] = treasuryAddresses;
-  console.log(
-    "=> Treasury Accounts:",
-    treasuryAccounts.map((address) => address.toBase58())
+  let { remainingAccounts } = await getTradeRemainingAccounts(
+    mint,
+    treasuryAddresses,
+    connection
   );
 
-  let remainingAccounts = [];
-  treasuryAccounts.map((address) => {
-    remainingAccounts.push({
-      pubkey: address,
-      isWritable: true,
-      isSigner: false,
-    });
-  });
-  creators.map((creator) => {
-    remainingAccounts.push({
-      pubkey: new PublicKey(creator.address),
-      isWritable: true,
-      isSigner: false,
-    });
-  });
+  let tx = txWithComputeUnitsIxs();
+  if (ret.instructions.length > 0) ret.instructions.map((ix) => tx.add(ix));
 
   console.log(
-    "==> claiming Auction",
+    "==> settling Sealed Auction",
     mint.toBase58(),
-    userAddress.toBase58(),
+    "Winner:",
+    winner.toBase58(),
     "Creator:",
     creator.toBase58()
   );
   tx.add(
-    program.instruction.claimAuctionPnft(bump, nft_bump, escrow_bump, {
+    program.instruction.settleSealedAuction(bump, escrow_bump, {
       accounts: {
-        bidder: userAddress,
+        payer: userAddress,
         globalAuthority,
-        auctionDataInfo: nftData,
-        userTokenAccount: ret.destinationAccounts[0],
+        sealedAuctionInfo: sealedAuction,
+        winner,
+        winnerBidInfo: winnerBid,
+        creator,
+        userTokenAccount,
         destNftTokenAccount,
         nftMint: mint,
         escrowVault,
-        creator,
-        bidderUserPool: userPool,
+        winnerUserPool,
         creatorUserPool,
         mintMetadata,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -2403,86 +5057,51 @@ export const createClaimAuctionPnftTx = async (
   return tx;
 };
 
-export const createUpdateReserveTx = async (
+export const createRefundSealedBidTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
-  newPrice: number,
+  bidder: PublicKey,
   program: anchor.Program
 ) => {
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
-  );
-
   let tx = txWithComputeUnitsIxs();
 
-  console.log("==> Updating reserved price", mint.toBase58(), newPrice);
-  tx.add(
-    program.instruction.updateReserve(nft_bump, new anchor.BN(newPrice), {
-      accounts: {
-        creator: userAddress,
-        auctionDataInfo: nftData,
-        nftMint: mint,
-      },
-      instructions: [],
-      signers: [],
-    })
-  );
-
-  return tx;
-};
-
-export const createCancelAuctionTx = async (
-  mint: PublicKey,
-  userAddress: PublicKey,
-  program: anchor.Program,
-  connection: Connection
-) => {
-  const [globalAuthority, bump] = await PublicKey.findProgramAddress(
+  const [globalAuthority, _] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
+  const [sealedAuction, auction_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  const [sellData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [sealedBid, bid_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_BID_SEED), mint.toBuffer(), bidder.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
+  const [userPool, user_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(USER_DATA_SEED), bidder.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
   );
 
-  let destNftTokenAccount = await getAssociatedTokenAccount(
-    globalAuthority,
-    mint
+  const [escrowVault, escrow_bump] = await PublicKey.findProgramAddress(
+    [Buffer.from(ESCROW_VAULT_SEED)],
+    MARKETPLACE_PROGRAM_ID
   );
 
-  console.log("User NFT Account = ", destinationAccounts[0].toBase58());
-
-  let tx = txWithComputeUnitsIxs();
-
-  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-
-  console.log("==> canceling Auction", mint.toBase58());
+  console.log("==> refunding Sealed Bid", mint.toBase58(), bidder.toBase58());
   tx.add(
-    program.instruction.cancelAuction(bump, nft_bump, {
+    program.instruction.refundSealedBid(escrow_bump, {
       accounts: {
-        creator: userAddress,
+        payer: userAddress,
         globalAuthority,
-        auctionDataInfo: nftData,
-        userTokenAccount: destinationAccounts[0],
-        destNftTokenAccount,
-        nftMint: mint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        sellDataInfo: sellData,
+        sealedAuctionInfo: sealedAuction,
+        bidder,
+        sealedBidInfo: sealedBid,
+        userPool,
+        escrowVault,
+        systemProgram: SystemProgram.programId,
       },
       instructions: [],
       signers: [],
@@ -2492,91 +5111,97 @@ export const createCancelAuctionTx = async (
   return tx;
 };
 
-export const createCancelAuctionPnftTx = async (
+export const createCancelSealedAuctionPnftTx = async (
   mint: PublicKey,
   userAddress: PublicKey,
   program: anchor.Program,
   connection: Connection
 ) => {
+  // Anyone can cancel the sealed auction ended without revealed bids
+  let auctionInfo = await getSealedAuctionState(mint, program);
+  let creator = auctionInfo.creator;
+
   const [globalAuthority, bump] = await PublicKey.findProgramAddress(
     [Buffer.from(GLOBAL_AUTHORITY_SEED)],
     MARKETPLACE_PROGRAM_ID
   );
-
-  const [nftData, nft_bump] = await PublicKey.findProgramAddress(
-    [Buffer.from(AUCTION_DATA_SEED), mint.toBuffer()],
-    MARKETPLACE_PROGRAM_ID
+  // Global authority ATA clears the SPL delegate when the owner is not signing
+  const globalNftTokenAccount = await getAssociatedTokenAccount(
+    globalAuthority,
+    mint
   );
 
-  const [sellData, _] = await PublicKey.findProgramAddress(
-    [Buffer.from(SELL_DATA_SEED), mint.toBuffer()],
+  const [sealedAuction, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
     MARKETPLACE_PROGRAM_ID
   );
 
-  let { instructions, destinationAccounts } = await getATokenAccountsNeedCreate(
-    connection,
-    userAddress,
-    userAddress,
-    [mint]
-  );
-
-  let destNftTokenAccount = await getAssociatedTokenAccount(
-    globalAuthority,
-    mint
-  );
+  let userTokenAccount = await getAssociatedTokenAccount(creator, mint);
+  console.log("Creator NFT Account = ", userTokenAccount.toBase58());
 
-  console.log("User NFT Account = ", destinationAccounts[0].toBase58());
   const nftEdition = await getMasterEdition(mint);
   console.log("nftEdition:", nftEdition);
 
   const tokenMintRecord = findTokenRecordPda(
     new anchor.web3.PublicKey(mint),
-    destinationAccounts[0]
-  );
-  const userAccountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destinationAccounts[0])
+    userTokenAccount
   );
   console.log("tokenMintRecord: ", tokenMintRecord.toBase58());
 
-  const destTokenMintRecord = findTokenRecordPda(
-    new anchor.web3.PublicKey(mint),
-    destNftTokenAccount
-  );
-  const accountExisted = await connection.getAccountInfo(
-    new anchor.web3.PublicKey(destNftTokenAccount)
-  );
-  console.log("destTokenMintRecord: ", destTokenMintRecord.toBase58());
-  console.log("accountExisted: ", accountExisted);
-  console.log("instructions: ", instructions);
-
   const metadata = await getMetadata(mint);
   console.log("Metadata=", metadata.toBase58());
   let tx = txWithComputeUnitsIxs();
 
-  if (instructions.length > 0) instructions.map((ix) => tx.add(ix));
-
-  console.log("==> canceling Auction", mint.toBase58());
+  console.log("==> canceling Sealed Auction", mint.toBase58());
   tx.add(
-    program.instruction.cancelAuctionPnft(bump, nft_bump, {
+    program.instruction.cancelSealedAuctionPnft(bump, {
       accounts: {
-        creator: userAddress,
+        payer: userAddress,
+        creator,
         globalAuthority,
-        auctionDataInfo: nftData,
-        userTokenAccount: destinationAccounts[0],
-        destNftTokenAccount,
+        sealedAuctionInfo: sealedAuction,
+        userTokenAccount,
         nftMint: mint,
         mintMetadata: metadata,
+        tokenProgram: TOKEN_PROGRAM_ID,
         tokenMintEdition: nftEdition,
         tokenMintRecord: tokenMintRecord,
-        destTokenMintRecord: destTokenMintRecord,
-        systemProgram: SystemProgram.programId,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        tokenMetadataProgram: METAPLEX,
         authRules: MPL_DEFAULT_RULE_SET,
         sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
-        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         authRulesProgram: TOKEN_AUTH_RULES_ID,
-        sellDataInfo: sellData,
+        systemProgram: SystemProgram.programId,
+        tokenMetadataProgram: METAPLEX,
+        globalNftTokenAccount,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      },
+      instructions: [],
+      signers: [],
+    })
+  );
+
+  return tx;
+};
+
+export const createCloseSealedAuctionTx = async (
+  mint: PublicKey,
+  program: anchor.Program
+) => {
+  let auctionInfo = await getSealedAuctionState(mint, program);
+
+  const [sealedAuction, _] = await PublicKey.findProgramAddress(
+    [Buffer.from(SEALED_AUCTION_SEED), mint.toBuffer()],
+    MARKETPLACE_PROGRAM_ID
+  );
+
+  let tx = txWithComputeUnitsIxs();
+
+  // The rent goes back to the creator, no signature is required
+  console.log("==> closing Sealed Auction", mint.toBase58());
+  tx.add(
+    program.instruction.closeSealedAuction({
+      accounts: {
+        creator: auctionInfo.creator,
+        sealedAuctionInfo: sealedAuction,
       },
       instructions: [],
       signers: [],
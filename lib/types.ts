@@ -3,48 +3,96 @@ import { PublicKey } from "@solana/web3.js";
 
 export const GLOBAL_AUTHORITY_SEED = "global-authority-v1";
 export const SELL_DATA_SEED = "sell-info-v1";
-export const SELL_DATA_SIZE = 128;
+export const SELL_DATA_SIZE = 312;
 export const OFFER_DATA_SEED = "offer-info-v1";
-export const OFFER_DATA_SIZE = 96;
+export const OFFER_DATA_SIZE = 120;
 export const USER_DATA_SEED = "user-info-v1";
 export const AUCTION_DATA_SEED = "auction-info-v1";
-export const AUCTION_DATA_SIZE = 160;
+export const AUCTION_DATA_SIZE = 328;
 export const ESCROW_VAULT_SEED = "escrow-vault";
+export const BUNDLE_DATA_SEED = "bundle-info-v1";
+export const COLLECTION_OFFER_SEED = "collection-offer-v1";
+export const SEALED_AUCTION_SEED = "sealed-auction-v1";
+export const SEALED_BID_SEED = "sealed-bid-v1";
+
+export const PERMYRIAD = 10_000;
+export const PRICING_MODE_DEFAULT = 0;
+export const PRICING_MODE_FEE_ON_TOP = 1; // Buyer pays the price plus the fees & royalties
+export const PRICING_MODE_FEE_INCLUSIVE = 2; // Seller receives the price minus the fees & royalties
 
 export const MARKETPLACE_PROGRAM_ID = new PublicKey(
   "5J3fJvN67uWLo2uNaygTJjdRoJs5mxn9XgtXroiQkcwm"
 );
 
 export interface GlobalPool {
-  // 8 + 368
+  // 8 + 384
   superAdmin: PublicKey; // 32
   marketFeeSol: anchor.BN; // 8
   teamCount: anchor.BN; // 8
   teamTreasury: PublicKey[]; // 8 * 32
   treasuryRate: anchor.BN[]; // 8 * 8
+  pricingMode: anchor.BN; // 8
+  escrowLiabilities: anchor.BN; // 8
 }
 
 export interface SellData {
-  // 8 + 120
+  // 8 + 304
   mint: PublicKey; // 32
   seller: PublicKey; // 32
   collection: PublicKey; // 32
   priceSol: anchor.BN; // 8
   listedDate: anchor.BN; // 8
   active: anchor.BN; // 8
+  paymentMint: PublicKey; // 32
+  expiresAt: anchor.BN; // 8
+  collectionSource: anchor.BN; // 8
+  merkleTree: PublicKey; // 32
+  leafNonce: anchor.BN; // 8
+  reservedBuyer: PublicKey; // 32
+  dutchFloorPrice: anchor.BN; // 8
+  dutchDuration: anchor.BN; // 8
+  dutchStep: anchor.BN; // 8
+  payer: PublicKey; // 32
+  pricingMode: anchor.BN; // 8
+}
+
+export interface BundleData {
+  // 8 + 232
+  seller: PublicKey; // 32
+  bundleId: anchor.BN; // 8
+  priceSol: anchor.BN; // 8
+  listedDate: anchor.BN; // 8
+  itemCount: anchor.BN; // 8
+  mints: PublicKey[]; // 5 * 32
+  pricingMode: anchor.BN; // 8
 }
 
 export interface OfferData {
-  // 8 + 88
+  // 8 + 112
   mint: PublicKey; // 32
   buyer: PublicKey; // 32
   offerPrice: anchor.BN; // 8
   offerListingDate: anchor.BN; // 8
   active: anchor.BN; // 8
+  expiresAt: anchor.BN; // 8
+  counterPrice: anchor.BN; // 8
+  version: anchor.BN; // 8
+}
+
+export interface CollectionOfferData {
+  // 8 + 112
+  buyer: PublicKey; // 32
+  collection: PublicKey; // 32
+  price: anchor.BN; // 8
+  quantity: anchor.BN; // 8
+  createdDate: anchor.BN; // 8
+  pricingMode: anchor.BN; // 8
+  marketFeeSol: anchor.BN; // 8
+  royaltyBasisPoints: anchor.BN; // 8
 }
 
 export interface AuctionData {
-  // 8 + 152
+  // 8 + 320
   mint: PublicKey; // 32
   creator: PublicKey; // 32
   startPrice: anchor.BN; // 8
@@ -55,11 +103,68 @@ export interface AuctionData {
   highestBid: anchor.BN; // 8
   duration: anchor.BN; // 8
   status: anchor.BN; // 8
+  payer: PublicKey; // 32
+  pricingMode: anchor.BN; // 8
+  extensionWindow: anchor.BN; // 8
+  extensionLength: anchor.BN; // 8
+  endDate: anchor.BN; // 8
+  reservePrice: anchor.BN; // 8
+  reserveHash: number[]; // 32
+  prevBidder: PublicKey; // 32
+  prevBid: anchor.BN; // 8
+  marketFeeSol: anchor.BN; // 8
+  royaltyBasisPoints: anchor.BN; // 8
+  version: anchor.BN; // 8
+}
+
+export interface SealedAuctionData {
+  // 8 + 184
+  mint: PublicKey; // 32
+  creator: PublicKey; // 32
+  minPrice: anchor.BN; // 8
+  commitEndDate: anchor.BN; // 8
+  revealEndDate: anchor.BN; // 8
+  priceRule: anchor.BN; // 8
+  highestBidder: PublicKey; // 32
+  highestBid: anchor.BN; // 8
+  secondBid: anchor.BN; // 8
+  openBids: anchor.BN; // 8
+  status: anchor.BN; // 8
+  pricingMode: anchor.BN; // 8
+  marketFeeSol: anchor.BN; // 8
+  royaltyBasisPoints: anchor.BN; // 8
+}
+
+export interface SealedBidData {
+  // 8 + 112
+  mint: PublicKey; // 32
+  bidder: PublicKey; // 32
+  bidHash: number[]; // 32
+  deposit: anchor.BN; // 8
+  revealedBid: anchor.BN; // 8
 }
 
 export interface UserData {
-  // 8 + 48
+  // 8 + 72
   address: PublicKey; // 32
   tradedVolume: anchor.BN; // 8
   escrowSolBalance: anchor.BN; // 8
+  lockedSolBalance: anchor.BN; // 8
+  version: anchor.BN; // 8
+  legacyBalance: anchor.BN; // 8
+}
+
+export interface DutchSchedule {
+  floorPrice: anchor.BN;
+  duration: anchor.BN;
+  step: anchor.BN;
+}
+
+export interface SaleQuote {
+  price: anchor.BN;
+  pricingMode: anchor.BN;
+  marketFee: anchor.BN;
+  royalty: anchor.BN;
+  sellerProceeds: anchor.BN;
+  buyerTotal: anchor.BN;
 }
@@ -7,6 +7,7 @@ import {
   Transaction,
   Keypair,
   ComputeBudgetProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 import {
   TOKEN_PROGRAM_ID,
@@ -286,6 +287,113 @@ export const createTokenMint = async (
   console.log("Tx Hash=", txId);
 };
 
+export const TOKEN_STANDARD_NON_FUNGIBLE = 0;
+export const TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE = 4;
+
+const serializeString = (value: string) => {
+  const data = Buffer.from(value);
+  const len = Buffer.alloc(4);
+  len.writeUInt32LE(data.length);
+  return Buffer.concat([len, data]);
+};
+
+/** Create & mint one NFT to the owner for testing, the payer is the update authority & verified creator */
+export const createTestNft = async (
+  connection: Connection,
+  payer: Keypair,
+  owner: PublicKey,
+  tokenStandard: number,
+  sellerFeeBasisPoints: number = 500
+) => {
+  const mint = Keypair.generate();
+  const metadata = await getMetadata(mint.publicKey);
+  const masterEdition = await getMasterEdition(mint.publicKey);
+  const tokenAccount = await getAssociatedTokenAccount(owner, mint.publicKey);
+  const isProgrammable =
+    tokenStandard == TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE;
+
+  const feeBasisPoints = Buffer.alloc(2);
+  feeBasisPoints.writeUInt16LE(sellerFeeBasisPoints);
+  // CreateV1 with one verified creator, without collection & rule set, decimals 0, print supply zero
+  const createData = Buffer.concat([
+    Buffer.from([42, 0]),
+    serializeString("Mugs Test"),
+    serializeString("MUGS"),
+    serializeString(""),
+    feeBasisPoints,
+    Buffer.from([1, 1, 0, 0, 0]),
+    payer.publicKey.toBuffer(),
+    Buffer.from([1, 100]),
+    Buffer.from([0, 1, tokenStandard, 0, 0, 0, 0, 1, 0, 1, 0]),
+  ]);
+  const createIx = new TransactionInstruction({
+    programId: METAPLEX,
+    keys: [
+      { pubkey: metadata, isSigner: false, isWritable: true },
+      { pubkey: masterEdition, isSigner: false, isWritable: true },
+      { pubkey: mint.publicKey, isSigner: true, isWritable: true },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      {
+        pubkey: SYSVAR_INSTRUCTIONS_PUBKEY,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: createData,
+  });
+
+  // MintV1 of the single token, creates the owner ATA & the token record of the pNFT
+  const amount = Buffer.alloc(8);
+  amount.writeUInt32LE(1);
+  const mintIx = new TransactionInstruction({
+    programId: METAPLEX,
+    keys: [
+      { pubkey: tokenAccount, isSigner: false, isWritable: true },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      { pubkey: metadata, isSigner: false, isWritable: false },
+      { pubkey: masterEdition, isSigner: false, isWritable: true },
+      {
+        pubkey: isProgrammable
+          ? findTokenRecordPda(mint.publicKey, tokenAccount)
+          : METAPLEX,
+        isSigner: false,
+        isWritable: isProgrammable,
+      },
+      { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: false },
+      { pubkey: METAPLEX, isSigner: false, isWritable: false },
+      { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      {
+        pubkey: SYSVAR_INSTRUCTIONS_PUBKEY,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      {
+        pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: METAPLEX, isSigner: false, isWritable: false },
+      { pubkey: METAPLEX, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.concat([Buffer.from([43, 0]), amount, Buffer.from([0])]),
+  });
+
+  const tx = txWithComputeUnitsIxs();
+  tx.add(createIx).add(mintIx);
+  const txId = await connection.sendTransaction(tx, [payer, mint]);
+  await connection.confirmTransaction(txId, "confirmed");
+  console.log("Tx Hash=", txId);
+
+  return mint.publicKey;
+};
+
 export const isExistAccount = async (
   address: PublicKey,
   connection: Connection
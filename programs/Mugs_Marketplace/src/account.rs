@@ -1,43 +1,108 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::*;
+use crate::error::*;
+use crate::utils::{get_sale_quote, resolve_pricing_mode};
+
 #[account]
 #[derive(Default)]
 pub struct GlobalPool {
-    // 8 + 368
+    // 8 + 384
     pub super_admin: Pubkey,        // 32
     pub market_fee_sol: u64,        // 8    Permyriad
     pub team_count: u64,            // 8
     pub team_treasury: [Pubkey; 8], // 8 * 32
     pub treasury_rate: [u64; 8],    // 8 * 8
+    // 0-legacy mode of each path, 1-fee on top, 2-fee inclusive
+    pub pricing_mode: u64, // 8
+    // Sum of the user escrow balances, available & locked
+    pub escrow_liabilities: u64, // 8
 }
 
 #[account]
 #[derive(Default)]
 pub struct SellData {
-    // 8 + 120
+    // 8 + 304
     pub mint: Pubkey,       // 32
     pub seller: Pubkey,     // 32
     pub collection: Pubkey, // 32
+    // Price in lamports or in payment_mint base units
+    pub price_sol: u64,   // 8
+    pub listed_date: i64, // 8
+    pub active: u64,      // 8
+    // Pubkey::default() for SOL listings
+    pub payment_mint: Pubkey, // 32
+    // 0 for listings without expiry
+    pub expires_at: i64, // 8
+    // 0-unverified, 1-metadata collection, 2-first verified creator
+    pub collection_source: u64, // 8
+    // Compressed NFT listings only, the mint is the asset id
+    pub merkle_tree: Pubkey, // 32
+    pub leaf_nonce: u64,     // 8
+    // Pubkey::default() for public listings
+    pub reserved_buyer: Pubkey, // 32
+    // Dutch auction decays from price_sol to the floor price for the duration
+    // 0 duration for fixed price listings, 0 step for the linear decay
+    pub dutch_floor_price: u64, // 8
+    pub dutch_duration: i64,    // 8
+    pub dutch_step: i64,        // 8
+    // Refunded with the rent when the PDA is closed
+    pub payer: Pubkey, // 32
+    // 0-global mode, 1-fee on top, 2-fee inclusive
+    pub pricing_mode: u64, // 8
+}
+
+#[account]
+#[derive(Default)]
+pub struct BundleData {
+    // 8 + 232
+    pub seller: Pubkey,     // 32
+    pub bundle_id: u64,     // 8
     pub price_sol: u64,     // 8
     pub listed_date: i64,   // 8
-    pub active: u64,        // 8
+    pub item_count: u64,    // 8
+    pub mints: [Pubkey; 5], // 5 * 32
+    // 0-global mode, 1-fee on top, 2-fee inclusive
+    pub pricing_mode: u64, // 8
 }
 
 #[account]
 #[derive(Default)]
 pub struct OfferData {
-    // 8 + 88
+    // 8 + 112
     pub mint: Pubkey,            // 32
     pub buyer: Pubkey,           // 32
     pub offer_price: u64,        // 8
     pub offer_listing_date: i64, // 8
     pub active: u64,             // 8
+    // 0 for offers without expiry
+    pub expires_at: i64, // 8
+    // Seller proposed price, 0 without counter offer
+    pub counter_price: u64, // 8
+    // 0 until migrated, set on the init & the migration
+    pub version: u64, // 8
+}
+
+#[account]
+#[derive(Default)]
+pub struct CollectionOfferData {
+    // 8 + 112
+    pub buyer: Pubkey,      // 32
+    pub collection: Pubkey, // 32
+    pub price: u64,         // 8
+    // Remaining NFT count to buy, the PDA is closed when filled
+    pub quantity: u64,     // 8
+    pub created_date: i64, // 8
+    // Fee rates fixed on the creation, the fees on top are locked with the offer
+    pub pricing_mode: u64,         // 8
+    pub market_fee_sol: u64,       // 8
+    pub royalty_basis_points: u64, // 8
 }
 
 #[account]
 #[derive(Default)]
 pub struct AuctionData {
-    // 8 + 152
+    // 8 + 320
     pub mint: Pubkey,             // 32
     pub creator: Pubkey,          // 32
     pub start_price: u64,         // 8
@@ -49,19 +114,241 @@ pub struct AuctionData {
     pub duration: i64,            // 8
     // 0-canceled, 1-started, 2-claimed, 3-reserved
     pub status: u64, // 8
+    // Refunded with the rent when the PDA is closed
+    pub payer: Pubkey, // 32
+    // 0-global mode, 1-fee on top, 2-fee inclusive
+    pub pricing_mode: u64, // 8
+    // Bids placed within the window before the end push the end date by the extension length
+    pub extension_window: i64, // 8
+    pub extension_length: i64, // 8
+    // Current end date, 0 until the reserved auction is started
+    pub end_date: i64, // 8
+    // Minimum winning bid, 0 without reserve price
+    pub reserve_price: u64, // 8
+    // Hidden reserve commitment until revealed, zeroed for the public reserve
+    pub reserve_hash: [u8; 32], // 32
+    // Outbid bid stays locked until the next bid, the auction reverts to it when the last bid is retracted
+    pub prev_bidder: Pubkey, // 32
+    pub prev_bid: u64,       // 8
+    // Fee rates fixed on the creation, the fees on top are locked with the bids
+    pub market_fee_sol: u64,       // 8
+    pub royalty_basis_points: u64, // 8
+    // 0 until migrated, set on the init & the migration
+    pub version: u64, // 8
+}
+
+#[account]
+#[derive(Default)]
+pub struct SealedAuctionData {
+    // 8 + 184
+    pub mint: Pubkey,         // 32
+    pub creator: Pubkey,      // 32
+    pub min_price: u64,       // 8
+    pub commit_end_date: i64, // 8
+    pub reveal_end_date: i64, // 8
+    // 1-first price, 2-second price
+    pub price_rule: u64,        // 8
+    pub highest_bidder: Pubkey, // 32
+    pub highest_bid: u64,       // 8
+    pub second_bid: u64,        // 8
+    // Bid deposits not refunded yet, the PDA is closed after all deposits are released
+    pub open_bids: u64, // 8
+    // 0-canceled, 1-started, 2-claimed
+    pub status: u64, // 8
+    // Fixed on the creation, 1-fee on top, 2-fee inclusive
+    pub pricing_mode: u64, // 8
+    // Fee rates fixed on the creation, the deposits cover the bids with the fees on top
+    pub market_fee_sol: u64,       // 8
+    pub royalty_basis_points: u64, // 8
+}
+
+#[account]
+#[derive(Default)]
+pub struct SealedBidData {
+    // 8 + 112
+    pub mint: Pubkey,       // 32
+    pub bidder: Pubkey,     // 32
+    pub bid_hash: [u8; 32], // 32
+    pub deposit: u64,       // 8
+    // 0 until revealed
+    pub revealed_bid: u64, // 8
 }
 
 #[account]
 #[derive(Default)]
 pub struct UserData {
-    // 8 + 48
-    pub address: Pubkey,         // 32
-    pub traded_volume: u64,      // 8
+    // 8 + 72
+    pub address: Pubkey,    // 32
+    pub traded_volume: u64, // 8
+    // Available balance, withdrawable & spendable for purchases
     pub escrow_sol_balance: u64, // 8
+    // Locked in the live offers & bids
+    pub locked_sol_balance: u64, // 8
+    // 0 until migrated, set on the init & the migration
+    pub version: u64, // 8
+    // Escrow balance carried over by the migration until the admin reconciles it
+    pub legacy_balance: u64, // 8
+}
+
+// Compressed NFT leaf position & the tree root the proof is built against
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CNftLeaf {
+    pub root: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+// Dutch auction schedule of the listing
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DutchSchedule {
+    pub floor_price: u64,
+    pub duration: i64,
+    pub step: i64,
+}
+
+// Settlement breakdown of a sale at the given price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct SaleQuote {
+    pub price: u64,
+    pub pricing_mode: u64,
+    pub market_fee: u64,
+    pub royalty: u64,
+    pub seller_proceeds: u64,
+    pub buyer_total: u64,
+}
+
+impl SellData {
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        self.expires_at != 0 && self.expires_at <= timestamp
+    }
+
+    pub fn is_dutch(&self) -> bool {
+        self.dutch_duration != 0
+    }
+
+    // Current price of the Dutch auction, stepwise decay drops the price at every step
+    pub fn get_current_price(&self, timestamp: i64) -> u64 {
+        if !self.is_dutch() {
+            return self.price_sol;
+        }
+        let mut elapsed = (timestamp - self.listed_date).max(0);
+        if elapsed >= self.dutch_duration {
+            return self.dutch_floor_price;
+        }
+        if self.dutch_step > 0 {
+            elapsed -= elapsed % self.dutch_step;
+        }
+        let decay = (self.price_sol - self.dutch_floor_price) as u128 * elapsed as u128
+            / self.dutch_duration as u128;
+        self.price_sol - decay as u64
+    }
+
+    pub fn is_reserved_for_other(&self, buyer: &Pubkey) -> bool {
+        !self.reserved_buyer.eq(&Pubkey::default()) && !self.reserved_buyer.eq(buyer)
+    }
+}
+
+impl OfferData {
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        self.expires_at != 0 && self.expires_at <= timestamp
+    }
+}
+
+impl CollectionOfferData {
+    pub fn get_quote(&self) -> Result<SaleQuote> {
+        get_sale_quote(
+            self.price,
+            self.market_fee_sol,
+            self.royalty_basis_points as u16,
+            self.pricing_mode,
+        )
+    }
+
+    // Escrowed amount of the unfilled quantity, the price plus the fees on top for each NFT
+    pub fn get_locked_amount(&self) -> Result<u64> {
+        self.get_quote()?
+            .buyer_total
+            .checked_mul(self.quantity)
+            .ok_or(error!(MarketplaceError::MathOverflow))
+    }
 }
 
 impl AuctionData {
     pub fn get_end_date(&self) -> i64 {
         self.start_date + self.duration
     }
+
+    pub fn has_hidden_reserve(&self) -> bool {
+        self.reserve_hash != [0; 32]
+    }
+
+    // Unrevealed hidden reserve counts as met, the claim is only allowed after the reveal period
+    // So the creator withholding the reveal forfeits the reserve instead of locking the winning bid
+    pub fn is_reserve_met(&self) -> bool {
+        self.has_hidden_reserve() || self.highest_bid >= self.reserve_price
+    }
+
+    // Auctions created before the fee rates were fixed settle fee inclusive without the locked fees
+    pub fn get_quote(&self, bid: u64) -> Result<SaleQuote> {
+        get_sale_quote(
+            bid,
+            self.market_fee_sol,
+            self.royalty_basis_points as u16,
+            resolve_pricing_mode(
+                self.pricing_mode,
+                PRICING_MODE_DEFAULT,
+                PRICING_MODE_FEE_INCLUSIVE,
+            ),
+        )
+    }
+
+    // Escrowed amount of the bid, the price plus the fees on top
+    pub fn get_locked_amount(&self, bid: u64) -> Result<u64> {
+        Ok(self.get_quote(bid)?.buyer_total)
+    }
+
+    // Extend the auction so the bid placed at the timestamp has the extension length left
+    pub fn extend_for_bid(&mut self, timestamp: i64) -> bool {
+        let end_date = self.get_end_date();
+        if self.extension_window == 0 || end_date - timestamp > self.extension_window {
+            return false;
+        }
+        let new_end_date = timestamp + self.extension_length;
+        if new_end_date <= end_date {
+            return false;
+        }
+        self.duration = new_end_date - self.start_date;
+        self.end_date = new_end_date;
+        true
+    }
+}
+
+impl SealedAuctionData {
+    // Record the revealed bid, the earlier reveal wins the tie
+    pub fn record_bid(&mut self, bidder: Pubkey, amount: u64) {
+        if amount > self.highest_bid {
+            self.second_bid = self.highest_bid;
+            self.highest_bid = amount;
+            self.highest_bidder = bidder;
+        } else if amount > self.second_bid {
+            self.second_bid = amount;
+        }
+    }
+
+    pub fn get_quote(&self, bid: u64) -> Result<SaleQuote> {
+        get_sale_quote(
+            bid,
+            self.market_fee_sol,
+            self.royalty_basis_points as u16,
+            self.pricing_mode,
+        )
+    }
+
+    pub fn get_clearing_price(&self) -> u64 {
+        if self.price_rule == SEALED_SECOND_PRICE {
+            self.second_bid.max(self.min_price)
+        } else {
+            self.highest_bid
+        }
+    }
 }
@@ -4,5 +4,52 @@ pub const OFFER_DATA_SEED: &str = "offer-info-v1";
 pub const USER_DATA_SEED: &str = "user-info-v1";
 pub const AUCTION_DATA_SEED: &str = "auction-info-v1";
 pub const ESCROW_VAULT_SEED: &str = "escrow-vault";
+pub const BUNDLE_DATA_SEED: &str = "bundle-info-v1";
+pub const COLLECTION_OFFER_SEED: &str = "collection-offer-v1";
+pub const SEALED_AUCTION_SEED: &str = "sealed-auction-v1";
+pub const SEALED_BID_SEED: &str = "sealed-bid-v1";
 
 pub const PERMYRIAD: u64 = 10_000; // Permyriad Measure Unit
+
+// Bundle item count is bounded by the transaction size, remaining accounts per bundle item
+pub const MAX_BUNDLE_ITEMS: usize = 5;
+pub const BUNDLE_LIST_ACCOUNTS: usize = 5;
+pub const BUNDLE_PURCHASE_ACCOUNTS: usize = 7;
+
+// PDA sizes before the upgrade, grown to the current sizes by the migrate instructions
+pub const GLOBAL_POOL_V0_SIZE: usize = 8 + 368;
+pub const USER_DATA_V0_SIZE: usize = 8 + 48;
+pub const SELL_DATA_V0_SIZE: usize = 8 + 120;
+pub const OFFER_DATA_V0_SIZE: usize = 8 + 88;
+pub const AUCTION_DATA_V0_SIZE: usize = 8 + 152;
+// Layout version of the PDAs moving escrow balances on the migration, a migrated PDA is never migrated again
+pub const DATA_VERSION: u64 = 1;
+
+// Pricing modes, listings with the default mode follow the global pricing mode
+// Unset global mode keeps the legacy mode, fees on top of purchases & inside the offers and auctions
+pub const PRICING_MODE_DEFAULT: u64 = 0;
+pub const PRICING_MODE_FEE_ON_TOP: u64 = 1; // Buyer pays the price plus the fees & royalties
+pub const PRICING_MODE_FEE_INCLUSIVE: u64 = 2; // Seller receives the price minus the fees & royalties
+
+// Creator can reveal the hidden auction reserve until this period after the auction end
+pub const RESERVE_REVEAL_PERIOD: i64 = 86_400; // 1 day
+
+// Bids can be retracted shortly after bidding, not in the final period of the auction
+pub const BID_RETRACTION_WINDOW: i64 = 300; // 5 mins
+pub const BID_RETRACTION_CUTOFF: i64 = 3_600; // 1 hour
+
+// Sealed bid auction settlement price rules
+pub const SEALED_FIRST_PRICE: u64 = 1; // Winner pays the own bid
+pub const SEALED_SECOND_PRICE: u64 = 2; // Winner pays the second highest bid, at least the min price
+
+// SPL mint base layout shared by both token programs
+pub const MINT_SUPPLY_OFFSET: usize = 36;
+pub const MINT_DECIMALS_OFFSET: usize = 44;
+
+// Token-2022 mint layout, extensions follow the account type byte after the padded base mint
+pub const MINT_EXTENSIONS_START: usize = 166;
+pub const MINT_ACCOUNT_TYPE: u8 = 1;
+pub const EXTENSION_METADATA_POINTER: u16 = 18;
+pub const EXTENSION_TOKEN_METADATA: u16 = 19;
+pub const EXTENSION_GROUP_MEMBER_POINTER: u16 = 22;
+pub const EXTENSION_GROUP_MEMBER: u16 = 23;
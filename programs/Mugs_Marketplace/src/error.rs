@@ -127,4 +127,100 @@ pub enum MarketplaceError {
     // 0x1796
     #[msg("NFT Is Not In Escrow ATA")]
     NFTIsNotInEscrowATA,
-}
\ No newline at end of file
+
+    // 0x1797
+    #[msg("Payment Token Account Mismatch with Listing Payment Mint")]
+    InvalidPaymentTokenAccount,
+    // 0x1798
+    #[msg("Offer Is Only Available For SOL Listing")]
+    OfferForTokenListing,
+
+    // 0x1799
+    #[msg("Data Account Is Already Migrated")]
+    AccountAlreadyMigrated,
+
+    // 0x179a
+    #[msg("The NFT Listing Is Expired")]
+    ExpiredListing,
+    // 0x179b
+    #[msg("The NFT Listing Is Not Expired Yet")]
+    NotExpiredListing,
+
+    // 0x179c
+    #[msg("Trade Price Or Royalty Mismatch with Expected Terms")]
+    TradeTermsMismatch,
+    // 0x179d
+    #[msg("The NFT Listing Is Reserved For Another Buyer")]
+    ReservedListing,
+
+    // 0x179e
+    #[msg("Signer Is Not The Rent Payer Of The PDA")]
+    InvalidRentPayer,
+    // 0x179f
+    #[msg("The PDA Is Still In Use")]
+    DataAccountInUse,
+
+    // 0x17a0
+    #[msg("Remaining Account Mismatch with The NFT Creator")]
+    CreatorAddressMismatch,
+
+    // 0x17a1
+    #[msg("Remaining Accounts Mismatch with The Bundle Items")]
+    BundleAccountsMismatch,
+    // 0x17a2
+    #[msg("Bundle Item Should Be A Programmable NFT Held By The Seller")]
+    InvalidBundleItem,
+
+    // 0x17a3
+    #[msg("The NFT Is Not A Verified Member Of The Offer Collection")]
+    CollectionMismatch,
+    // 0x17a4
+    #[msg("The Offer Is Expired")]
+    ExpiredOffer,
+
+    // 0x17a5
+    #[msg("The Offer Is Still Valid")]
+    NotRefundableOffer,
+    // 0x17a6
+    #[msg("Escrow Vault Balance Is Below The Escrow Liabilities")]
+    EscrowShortfall,
+
+    // 0x17a7
+    #[msg("Revealed Reserve Price Doesn't Match The Commitment")]
+    InvalidReserveReveal,
+    // 0x17a8
+    #[msg("Hidden Reserve Is Still In The Reveal Period")]
+    ReserveNotRevealed,
+
+    // 0x17a9
+    #[msg("Bid Retraction Window Is Closed")]
+    BidRetractionClosed,
+
+    // 0x17aa
+    #[msg("Sealed Bid Auction Is Not In This Phase")]
+    InvalidSealedPhase,
+    // 0x17ab
+    #[msg("Revealed Bid Doesn't Match The Commitment")]
+    InvalidBidReveal,
+
+    // 0x17ac
+    #[msg("Winning Sealed Bid Is Settled With The Auction")]
+    WinningSealedBid,
+
+    // 0x17ad
+    #[msg("Token Standard Is Not A Non Fungible Standard")]
+    InvalidTokenStandard,
+
+    // 0x17ae
+    #[msg("Amount Overflow")]
+    MathOverflow,
+    // 0x17af
+    #[msg("Payment Mint Should Be A SPL Token Mint")]
+    InvalidPaymentMint,
+    // 0x17b0
+    #[msg("Payment Token Account Is Not Initialized")]
+    PaymentAccountNotInitialized,
+    // 0x17b1
+    #[msg("Same User Data Account Can Not Be Used Twice")]
+    DuplicateUserAccount,
+}
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 use anchor_spl::{
     associated_token,
-    token::{Mint, Token, TokenAccount},
+    token::{self, Token},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
+use mpl_bubblegum::{types::MetadataArgs, utils::get_asset_id};
 use mpl_token_metadata::{accounts::Metadata, types::Creator};
 use solana_program::program::{invoke, invoke_signed};
 use solana_program::system_instruction;
@@ -10,21 +13,18 @@ use solana_program::system_instruction;
 pub mod account;
 pub mod constants;
 pub mod error;
+pub mod utils;
 
 use account::*;
 use constants::*;
 use error::*;
+use utils::*;
 
 declare_id!("5J3fJvN67uWLo2uNaygTJjdRoJs5mxn9XgtXroiQkcwm");
 
 #[program]
 pub mod mugs_marketplace {
 
-    use mpl_token_metadata::instructions::{
-        DelegateLockedTransferV1CpiBuilder, LockV1CpiBuilder, RevokeLockedTransferV1CpiBuilder,
-        TransferV1CpiBuilder, UnlockV1CpiBuilder,
-    };
-
     use super::*;
 
     pub fn initialize(ctx: Context<Initialize>, _global_bump: u8, _escrow_bump: u8) -> Result<()> {
@@ -44,6 +44,25 @@ pub mod mugs_marketplace {
         global_authority.market_fee_sol = sol_fee;
         Ok(())
     }
+    pub fn update_pricing_mode(
+        ctx: Context<SetTreshold>,
+        _global_bump: u8,
+        pricing_mode: u64,
+    ) -> Result<()> {
+        let global_authority = &mut ctx.accounts.global_authority;
+        // Assert payer is the superadmin
+        require!(
+            global_authority.super_admin == ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        require!(
+            pricing_mode == PRICING_MODE_FEE_ON_TOP || pricing_mode == PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
+        );
+
+        global_authority.pricing_mode = pricing_mode;
+        Ok(())
+    }
     pub fn add_team_treasury(
         ctx: Context<AddTreasury>,
         _global_bump: u8,
@@ -122,10 +141,216 @@ pub mod mugs_marketplace {
         Ok(())
     }
 
+    // Migrate instructions grow the PDAs created before the upgrade, the Global PDA is migrated first
+    // then the User PDAs, the offers & auctions need the migrated User PDA of the buyer or bidder
+    pub fn migrate_global_pool(ctx: Context<MigrateGlobalPool>, _global_bump: u8) -> Result<()> {
+        let global_info = ctx.accounts.global_authority.to_account_info();
+        realloc_data_account(
+            &global_info,
+            &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            GlobalPool::DISCRIMINATOR,
+            GLOBAL_POOL_V0_SIZE,
+            8 + 384,
+        )?;
+        let global_authority = GlobalPool::try_deserialize(&mut &global_info.data.borrow()[..])?;
+        // Assert payer is the superadmin
+        require!(
+            global_authority.super_admin == ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        Ok(())
+    }
+
+    // Escrow balance before the upgrade is counted in the escrow liabilities
+    // It can be inflated by the offer refunds not deducted before, so the admin reconciles it
+    pub fn migrate_user_data(
+        ctx: Context<MigrateUserData>,
+        address: Pubkey,
+        _bump: u8,
+    ) -> Result<()> {
+        let user_info = ctx.accounts.user_pool.to_account_info();
+        realloc_data_account(
+            &user_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            UserData::DISCRIMINATOR,
+            USER_DATA_V0_SIZE,
+            8 + 72,
+        )?;
+        let mut user_pool = UserData::try_deserialize(&mut &user_info.data.borrow()[..])?;
+        msg!(
+            "User: {:?}, Escrow Balance: {}",
+            address,
+            user_pool.escrow_sol_balance
+        );
+        require!(
+            user_pool.version == 0,
+            MarketplaceError::AccountAlreadyMigrated
+        );
+        user_pool.version = DATA_VERSION;
+        user_pool.legacy_balance = user_pool.escrow_sol_balance;
+
+        ctx.accounts.global_authority.escrow_liabilities += user_pool.escrow_sol_balance;
+        user_pool.try_serialize(&mut &mut user_info.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    // Admin lowers the migrated escrow balance to the amount the vault really holds for the user
+    // Only the balance carried over by the migration can be deducted, once
+    pub fn reconcile_escrow_balance(
+        ctx: Context<ReconcileEscrowBalance>,
+        address: Pubkey,
+        _bump: u8,
+        deduction: u64,
+    ) -> Result<()> {
+        let user_pool = &mut ctx.accounts.user_pool;
+        let global_authority = &mut ctx.accounts.global_authority;
+        msg!(
+            "User: {:?}, Escrow Balance: {}, Deduction: {}",
+            address,
+            user_pool.escrow_sol_balance,
+            deduction
+        );
+
+        // Assert payer is the superadmin
+        require!(
+            global_authority.super_admin == ctx.accounts.admin.key(),
+            MarketplaceError::InvalidSuperOwner
+        );
+        // Assert Deduction from the unreconciled migrated balance still available
+        require!(
+            deduction <= user_pool.legacy_balance && deduction <= user_pool.escrow_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.escrow_sol_balance -= deduction;
+        user_pool.legacy_balance = 0;
+        global_authority.escrow_liabilities -= deduction;
+        Ok(())
+    }
+
+    // Listings before the upgrade are SOL priced, unverified & without expiry
+    pub fn migrate_sell_data(ctx: Context<MigrateSellData>, nft: Pubkey, _bump: u8) -> Result<()> {
+        let sell_data_account = ctx.accounts.sell_data_info.to_account_info();
+        realloc_data_account(
+            &sell_data_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            SellData::DISCRIMINATOR,
+            SELL_DATA_V0_SIZE,
+            8 + 304,
+        )?;
+        let mut sell_data_info =
+            SellData::try_deserialize(&mut &sell_data_account.data.borrow()[..])?;
+        msg!("Mint: {:?}, Active: {}", nft, sell_data_info.active);
+
+        sell_data_info.payer = ctx.accounts.payer.key();
+        sell_data_info.try_serialize(&mut &mut sell_data_account.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    // Offers before the upgrade are without expiry
+    // Active offer deposit is moved from the available to the locked escrow balance
+    // The offer is deactivated if the deposit was already withdrawn
+    pub fn migrate_offer_data(
+        ctx: Context<MigrateOfferData>,
+        nft: Pubkey,
+        buyer: Pubkey,
+        _bump: u8,
+    ) -> Result<()> {
+        let offer_data_account = ctx.accounts.offer_data_info.to_account_info();
+        realloc_data_account(
+            &offer_data_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            OfferData::DISCRIMINATOR,
+            OFFER_DATA_V0_SIZE,
+            8 + 112,
+        )?;
+        let mut offer_data_info =
+            OfferData::try_deserialize(&mut &offer_data_account.data.borrow()[..])?;
+        msg!(
+            "Mint: {:?}, Buyer: {:?}, Active: {}",
+            nft,
+            buyer,
+            offer_data_info.active
+        );
+        require!(
+            offer_data_info.version == 0,
+            MarketplaceError::AccountAlreadyMigrated
+        );
+        offer_data_info.version = DATA_VERSION;
+
+        if offer_data_info.active == 1 {
+            let user_pool = &mut ctx.accounts.user_pool;
+            if offer_data_info.offer_price <= user_pool.escrow_sol_balance {
+                user_pool.escrow_sol_balance -= offer_data_info.offer_price;
+                user_pool.locked_sol_balance += offer_data_info.offer_price;
+            } else {
+                offer_data_info.active = 0;
+            }
+        }
+        offer_data_info.try_serialize(&mut &mut offer_data_account.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
+    // Rent of the PDAs before the upgrade is refunded to the migration payer on close
+    // Highest bid before the upgrade was escrowed outside the User PDA, it's locked for the bidder
+    // The auction settles fee inclusive as before without the bid extension
+    pub fn migrate_auction_data(
+        ctx: Context<MigrateAuctionData>,
+        nft: Pubkey,
+        _bump: u8,
+    ) -> Result<()> {
+        let auction_data_account = ctx.accounts.auction_data_info.to_account_info();
+        realloc_data_account(
+            &auction_data_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            AuctionData::DISCRIMINATOR,
+            AUCTION_DATA_V0_SIZE,
+            8 + 320,
+        )?;
+        let mut auction_data_info =
+            AuctionData::try_deserialize(&mut &auction_data_account.data.borrow()[..])?;
+        msg!(
+            "Mint: {:?}, Status: {}, Last Bidder: {:?}",
+            nft,
+            auction_data_info.status,
+            auction_data_info.last_bidder
+        );
+        require!(
+            auction_data_info.version == 0,
+            MarketplaceError::AccountAlreadyMigrated
+        );
+        auction_data_info.version = DATA_VERSION;
+
+        if auction_data_info.status == 1 {
+            auction_data_info.end_date = auction_data_info.get_end_date();
+        }
+        if auction_data_info.status == 1 && !auction_data_info.last_bidder.eq(&Pubkey::default()) {
+            let bidder_user_pool = match &mut ctx.accounts.bidder_user_pool {
+                Some(bidder_user_pool) => bidder_user_pool,
+                None => return Err(error!(MarketplaceError::InvalidParamInput)),
+            };
+            // Assert Bidder User PDA Address with the Last Bidder
+            require!(
+                bidder_user_pool.address.eq(&auction_data_info.last_bidder),
+                MarketplaceError::OutBidderMismatch
+            );
+            bidder_user_pool.locked_sol_balance += auction_data_info.highest_bid;
+            ctx.accounts.global_authority.escrow_liabilities += auction_data_info.highest_bid;
+        }
+        auction_data_info.payer = ctx.accounts.payer.key();
+        auction_data_info.try_serialize(&mut &mut auction_data_account.data.borrow_mut()[..])?;
+        Ok(())
+    }
+
     // Initialize User PDA for Escrow & Traded Volume
     pub fn init_user_pool(ctx: Context<InitUserPool>, _bump: u8) -> Result<()> {
         let user_pool = &mut ctx.accounts.user_pool;
         user_pool.address = ctx.accounts.owner.key();
+        user_pool.version = DATA_VERSION;
         Ok(())
     }
 
@@ -133,10 +358,30 @@ pub mod mugs_marketplace {
     pub fn init_sell_data(ctx: Context<InitSellData>, nft: Pubkey, _bump: u8) -> Result<()> {
         let sell_data_info = &mut ctx.accounts.sell_data_info;
         sell_data_info.mint = nft;
+        sell_data_info.payer = ctx.accounts.payer.key();
+        Ok(())
+    }
+
+    // Close the inactive Sell Data PDA and refund the rent to its payer
+    pub fn close_sell_data(ctx: Context<CloseSellData>, _bump: u8) -> Result<()> {
+        let sell_data_info = &ctx.accounts.sell_data_info;
+        msg!("Close Sell Data: {:?}", sell_data_info.mint);
+
+        // Assert Not Listed NFT
+        require!(
+            sell_data_info.active == 0,
+            MarketplaceError::DataAccountInUse
+        );
         Ok(())
     }
 
-    pub fn set_price(ctx: Context<SetPrice>, _sell_bump: u8, price: u64) -> Result<()> {
+    // expires_at: None keeps the current expiry, Some(0) removes it
+    pub fn set_price(
+        ctx: Context<SetPrice>,
+        _sell_bump: u8,
+        price: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
         let sell_data_info = &mut ctx.accounts.sell_data_info;
         msg!("Mint: {:?}", sell_data_info.mint);
 
@@ -153,11 +398,86 @@ pub mod mugs_marketplace {
         // Assert Already Delisted NFT
         require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
 
+        // Assert Dutch Auction Start Price is above the Floor Price
+        if sell_data_info.is_dutch() {
+            require!(
+                price > sell_data_info.dutch_floor_price,
+                MarketplaceError::InvalidParamInput
+            );
+        }
+
         sell_data_info.price_sol = price;
+        if let Some(expires_at) = expires_at {
+            let timestamp = Clock::get()?.unix_timestamp;
+            // Assert Expiry is in the future
+            require!(
+                expires_at == 0 || expires_at > timestamp,
+                MarketplaceError::InvalidParamInput
+            );
+            sell_data_info.expires_at = expires_at;
+        }
 
         Ok(())
     }
 
+    // View the exact settlement breakdown, returned as the transaction return data
+    // from_auction: false-listing at its current price, true-auction at its highest bid
+    // Price overrides the listing or auction price, e.g. for an offer
+    pub fn quote_sale(
+        ctx: Context<QuoteSale>,
+        from_auction: bool,
+        price: Option<u64>,
+    ) -> Result<SaleQuote> {
+        let global_authority = &ctx.accounts.global_authority;
+        let sell_data_info = &ctx.accounts.sell_data_info;
+        let auction_data_info = &ctx.accounts.auction_data_info;
+
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == ctx.accounts.mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (nft_metadata, _) = load_metadata(
+            &ctx.accounts.mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        let (listing_price, listing_mode, legacy_mode) = if from_auction {
+            (
+                auction_data_info.highest_bid,
+                auction_data_info.pricing_mode,
+                PRICING_MODE_FEE_INCLUSIVE,
+            )
+        } else {
+            (
+                sell_data_info.get_current_price(Clock::get()?.unix_timestamp),
+                sell_data_info.pricing_mode,
+                PRICING_MODE_FEE_ON_TOP,
+            )
+        };
+        // Auctions settle at the fee rates fixed on the creation
+        let quote = if from_auction && listing_mode != PRICING_MODE_DEFAULT {
+            auction_data_info.get_quote(price.unwrap_or(listing_price))?
+        } else {
+            get_sale_quote(
+                price.unwrap_or(listing_price),
+                global_authority.market_fee_sol,
+                nft_metadata.seller_fee_basis_points,
+                resolve_pricing_mode(listing_mode, global_authority.pricing_mode, legacy_mode),
+            )?
+        };
+        msg!(
+            "Price: {}, Market Fee: {}, Royalty: {}, Seller Proceeds: {}, Buyer Total: {}",
+            quote.price,
+            quote.market_fee,
+            quote.royalty,
+            quote.seller_proceeds,
+            quote.buyer_total
+        );
+
+        Ok(quote)
+    }
+
     pub fn deposit_to_escrow(
         ctx: Context<Deposit>,
         _user_bump: u8,
@@ -188,6 +508,7 @@ pub mod mugs_marketplace {
             ],
         )?;
         user_pool.escrow_sol_balance += sol;
+        ctx.accounts.global_authority.escrow_liabilities += sol;
 
         Ok(())
     }
@@ -208,6 +529,11 @@ pub mod mugs_marketplace {
             ctx.accounts.owner.key().eq(&user_pool.address),
             MarketplaceError::InvalidOwner
         );
+        // Assert Withdraw Amount, funds locked in offers & bids can't be withdrawn
+        require!(
+            sol <= user_pool.escrow_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
 
         let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
         let signer = &[&seeds[..]];
@@ -226,17 +552,41 @@ pub mod mugs_marketplace {
             signer,
         )?;
         user_pool.escrow_sol_balance -= sol;
+        ctx.accounts.global_authority.escrow_liabilities -= sol;
 
         Ok(())
     }
 
+    // Auditor view, proves the vault covers every user escrow balance
+    // Returns the vault surplus over the escrow liabilities
+    pub fn audit_escrow(ctx: Context<AuditEscrow>) -> Result<u64> {
+        let vault_lamports = ctx.accounts.escrow_vault.lamports();
+        let liabilities = ctx.accounts.global_authority.escrow_liabilities;
+        msg!(
+            "Vault Lamports: {}, Escrow Liabilities: {}",
+            vault_lamports,
+            liabilities
+        );
+
+        // Assert Vault Balance covers the Liabilities
+        require!(
+            vault_lamports >= liabilities,
+            MarketplaceError::EscrowShortfall
+        );
+
+        Ok(vault_lamports - liabilities)
+    }
+
     pub fn init_offer_data(ctx: Context<InitOfferData>, nft: Pubkey, _bump: u8) -> Result<()> {
         let offer_data_info = &mut ctx.accounts.offer_data_info;
         offer_data_info.mint = nft;
         offer_data_info.buyer = ctx.accounts.payer.key();
+        offer_data_info.version = DATA_VERSION;
         Ok(())
     }
 
+    // Offer on a listed NFT is bound to the listing, otherwise a standing offer is made
+    // Standing offers are accepted by the NFT owner with accept_standing_offer
     pub fn make_offer(
         ctx: Context<MakeOffer>,
         _sell_bump: u8,
@@ -244,19 +594,22 @@ pub mod mugs_marketplace {
         _user_bump: u8,
         _escrow_bump: u8,
         price: u64,
+        expires_at: Option<i64>,
     ) -> Result<()> {
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
         msg!(
-            "Mint: {:?}, buyer: {:?}",
-            sell_data_info.mint,
-            ctx.accounts.owner.key()
+            "Mint: {:?}, buyer: {:?}, Expires At: {:?}",
+            ctx.accounts.nft_mint.key(),
+            ctx.accounts.owner.key(),
+            expires_at
         );
 
-        // Assert NFT Pubkey with Sell Data PDA Mint
-        require!(
-            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
-        );
+        // Assert Expiry is in the future
+        if let Some(expires_at) = expires_at {
+            require!(
+                expires_at > Clock::get()?.unix_timestamp,
+                MarketplaceError::InvalidParamInput
+            );
+        }
 
         let offer_data_info = &mut ctx.accounts.offer_data_info;
         require!(
@@ -268,19 +621,58 @@ pub mod mugs_marketplace {
             ctx.accounts.owner.key().eq(&offer_data_info.buyer),
             MarketplaceError::InvalidOfferDataBuyer
         );
-        // Assert Already delisted NFT
-        require!(
-            sell_data_info.active == 1,
-            MarketplaceError::OfferForNotListedNFT
-        );
-        // Offer price range is from x1 to x0.5
+        // Assert No Live Offer, its escrow should be released by cancel_offer first
         require!(
-            sell_data_info.price_sol > price && sell_data_info.price_sol / 2 <= price,
-            MarketplaceError::InvalidOfferPrice
+            offer_data_info.active == 0,
+            MarketplaceError::DataAccountInUse
         );
 
-        offer_data_info.offer_listing_date = sell_data_info.listed_date;
+        // Sell Data PDA is empty for the never listed NFTs
+        let sell_data_info = if ctx.accounts.sell_data_info.data_is_empty() {
+            None
+        } else {
+            Some(SellData::try_deserialize(
+                &mut &ctx.accounts.sell_data_info.data.borrow()[..],
+            )?)
+        };
+        match &sell_data_info {
+            Some(sell_data_info) if sell_data_info.active == 1 => {
+                // Assert Listing Expiry
+                require!(
+                    !sell_data_info.is_expired(Clock::get()?.unix_timestamp),
+                    MarketplaceError::ExpiredListing
+                );
+                // Assert Private Listing Buyer
+                require!(
+                    !sell_data_info.is_reserved_for_other(&ctx.accounts.owner.key()),
+                    MarketplaceError::ReservedListing
+                );
+                // Assert Offers are escrowed in SOL
+                require!(
+                    sell_data_info.payment_mint.eq(&Pubkey::default()),
+                    MarketplaceError::OfferForTokenListing
+                );
+                // Assert Not Compressed NFT Listing, offers are settled with the NFT token accounts
+                require!(
+                    sell_data_info.merkle_tree.eq(&Pubkey::default()),
+                    MarketplaceError::ListingNotAvailable
+                );
+                // Offer price range is from x1 to x0.5 of the current price
+                let current_price = sell_data_info.get_current_price(Clock::get()?.unix_timestamp);
+                require!(
+                    current_price > price && current_price / 2 <= price,
+                    MarketplaceError::InvalidOfferPrice
+                );
+                offer_data_info.offer_listing_date = sell_data_info.listed_date;
+            }
+            _ => {
+                require!(price > 0, MarketplaceError::InvalidOfferPrice);
+                offer_data_info.offer_listing_date = 0;
+            }
+        }
         offer_data_info.offer_price = price;
+        offer_data_info.expires_at = expires_at.unwrap_or(0);
+        offer_data_info.counter_price = 0;
         offer_data_info.active = 1;
 
         let user_pool = &mut ctx.accounts.user_pool;
@@ -304,7 +696,8 @@ pub mod mugs_marketplace {
                 ctx.accounts.system_program.to_account_info().clone(),
             ],
         )?;
-        user_pool.escrow_sol_balance += price;
+        user_pool.locked_sol_balance += price;
+        ctx.accounts.global_authority.escrow_liabilities += price;
 
         Ok(())
     }
@@ -334,6 +727,14 @@ pub mod mugs_marketplace {
         );
         require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
 
+        let user_pool = &mut ctx.accounts.user_pool;
+        require!(
+            offer_data_info.offer_price <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.locked_sol_balance -= offer_data_info.offer_price;
+        ctx.accounts.global_authority.escrow_liabilities -= offer_data_info.offer_price;
+
         let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[_escrow_bump]];
         let signer = &[&seeds[..]];
 
@@ -355,62 +756,491 @@ pub mod mugs_marketplace {
         Ok(())
     }
 
-    pub fn init_auction_data(ctx: Context<InitAuctionData>, nft: Pubkey, _bump: u8) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        auction_data_info.mint = nft;
+    // Permissionless crank refunding an expired offer, or a listing offer whose listing is gone
+    pub fn refund_offer(ctx: Context<RefundOffer>, escrow_bump: u8) -> Result<()> {
+        let offer_data_info = &ctx.accounts.offer_data_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Mint: {:?}, buyer: {:?}",
+            offer_data_info.mint,
+            offer_data_info.buyer
+        );
+
+        // Listing offer is stale once the NFT is delisted, sold or listed again
+        let is_stale = offer_data_info.offer_listing_date != 0
+            && (ctx.accounts.sell_data_info.data_is_empty() || {
+                let sell_data_info =
+                    SellData::try_deserialize(&mut &ctx.accounts.sell_data_info.data.borrow()[..])?;
+                sell_data_info.active == 0
+                    || sell_data_info.listed_date != offer_data_info.offer_listing_date
+            });
+        // Assert Expired Or Stale Offer
+        require!(
+            offer_data_info.active == 1
+                && (offer_data_info.is_expired(Clock::get()?.unix_timestamp) || is_stale),
+            MarketplaceError::NotRefundableOffer
+        );
+        require!(
+            offer_data_info.offer_price <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.locked_sol_balance -= offer_data_info.offer_price;
+        ctx.accounts.global_authority.escrow_liabilities -= offer_data_info.offer_price;
+
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        transfer_sol(
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            offer_data_info.offer_price,
+            signer,
+        )?;
+
         Ok(())
     }
 
-    pub fn place_bid(
-        ctx: Context<PlaceBid>,
-        _auction_bump: u8,
-        escrow_bump: u8,
+    // Standing offer for any NFT of the verified collection, the price & fees on top x quantity are escrowed
+    pub fn make_collection_offer(
+        ctx: Context<MakeCollectionOffer>,
+        _user_bump: u8,
+        _escrow_bump: u8,
+        collection: Pubkey,
         price: u64,
+        quantity: u64,
     ) -> Result<()> {
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        let collection_offer_info = &mut ctx.accounts.collection_offer_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Collection: {:?}, Buyer: {:?}, Price: {}, Quantity: {}",
+            collection,
+            ctx.accounts.owner.key(),
+            price,
+            quantity
+        );
 
-        let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Place Date: {}", timestamp);
-        // Assert NFT Pubkey with Auction Data PDA Mint
+        // Assert User Pubkey with User Data PDA Address
         require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            ctx.accounts.owner.key().eq(&user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Already Disabled Auction
         require!(
-            auction_data_info.status == 1 || auction_data_info.status == 3,
-            MarketplaceError::NotListedNFT
+            price > 0 && quantity > 0,
+            MarketplaceError::InvalidParamInput
         );
-        // Assert Auction Already Ended
+
+        collection_offer_info.buyer = ctx.accounts.owner.key();
+        collection_offer_info.collection = collection;
+        collection_offer_info.price = price;
+        collection_offer_info.quantity = quantity;
+        collection_offer_info.created_date = Clock::get()?.unix_timestamp;
+
+        // Fee rates are fixed with the royalty of the collection NFT, zero without Metaplex metadata
+        let (metadata, _) = Metadata::find_pda(&collection);
+        let collection_metadata = &ctx.accounts.collection_metadata;
         require!(
-            auction_data_info.status == 3 || auction_data_info.get_end_date() > timestamp,
-            MarketplaceError::EndedAuction
+            metadata == collection_metadata.key(),
+            MarketplaceError::InvaliedMetadata
         );
-        // New Bid should be increased more than min_increase_amount
-        require!(
-            auction_data_info.highest_bid + auction_data_info.min_increase_amount <= price,
-            MarketplaceError::InvalidBidPrice
+        let royalty_basis_points = if collection_metadata.data_is_empty() {
+            0
+        } else {
+            Metadata::safe_deserialize(&collection_metadata.data.borrow())
+                .map_err(|_| error!(MarketplaceError::InvaliedMetadata))?
+                .seller_fee_basis_points
+        };
+        let global_authority = &ctx.accounts.global_authority;
+        collection_offer_info.pricing_mode = resolve_pricing_mode(
+            PRICING_MODE_DEFAULT,
+            global_authority.pricing_mode,
+            PRICING_MODE_FEE_INCLUSIVE,
         );
-        // Assert OutBidder Address with the Last Bidder
-        require!(
-            Pubkey::default().eq(&auction_data_info.last_bidder)
-                || ctx
-                    .accounts
-                    .out_bidder
-                    .key()
-                    .eq(&auction_data_info.last_bidder),
-            MarketplaceError::OutBidderMismatch
+        collection_offer_info.market_fee_sol = global_authority.market_fee_sol;
+        collection_offer_info.royalty_basis_points = royalty_basis_points as u64;
+
+        let deposit = collection_offer_info.get_locked_amount()?;
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.owner.key,
+                ctx.accounts.escrow_vault.key,
+                deposit,
+            ),
+            &[
+                ctx.accounts.owner.to_account_info().clone(),
+                ctx.accounts.escrow_vault.to_account_info().clone(),
+                ctx.accounts.system_program.to_account_info().clone(),
+            ],
+        )?;
+        user_pool.locked_sol_balance += deposit;
+        ctx.accounts.global_authority.escrow_liabilities += deposit;
+
+        Ok(())
+    }
+
+    pub fn cancel_collection_offer(
+        ctx: Context<CancelCollectionOffer>,
+        _user_bump: u8,
+        escrow_bump: u8,
+    ) -> Result<()> {
+        let collection_offer_info = &ctx.accounts.collection_offer_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Collection: {:?}, Buyer: {:?}",
+            collection_offer_info.collection,
+            collection_offer_info.buyer
         );
-        // Assert New Bidder is same with the Last Bidder
+
+        // Unfilled quantity is refunded from the escrow
+        let refund = collection_offer_info.get_locked_amount()?;
         require!(
-            !ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
-            MarketplaceError::DoubleBidFromOneBidder
+            refund <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
         );
-        // Assert Bid from Auction Creator
-        require!(
-            !ctx.accounts.bidder.key().eq(&auction_data_info.creator),
-            MarketplaceError::BidFromAuctionCreator
+        user_pool.locked_sol_balance -= refund;
+        ctx.accounts.global_authority.escrow_liabilities -= refund;
+
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        transfer_sol(
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            refund,
+            signer,
+        )?;
+
+        Ok(())
+    }
+
+    // NFT holder fills one quantity of the collection offer
+    // Remaining accounts are the team treasuries and then the creators
+    pub fn accept_collection_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptCollectionOffer<'info>>,
+        escrow_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+    ) -> Result<()> {
+        let collection_offer_info = &mut ctx.accounts.collection_offer_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        let global_authority = &mut ctx.accounts.global_authority;
+        msg!(
+            "Collection: {:?}, Mint: {:?}, Buyer: {:?}",
+            collection_offer_info.collection,
+            ctx.accounts.nft_mint.key(),
+            collection_offer_info.buyer
+        );
+
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Seller User PDA Address
+        require!(
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        require!(
+            collection_offer_info.quantity > 0,
+            MarketplaceError::DisabledOffer
+        );
+
+        let mint_metadata = &ctx.accounts.mint_metadata;
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        // Assert NFT is a verified member of the offer collection
+        // Creator fallback is not accepted since the candy machine creator keys are shared across collections
+        let (collection, collection_source) = get_collection(&nft_metadata)?;
+        require!(
+            collection_source == 1 && collection.eq(&collection_offer_info.collection),
+            MarketplaceError::CollectionMismatch
+        );
+
+        let creators: &Vec<Creator>;
+        if let Some(cts) = &nft_metadata.creators {
+            creators = cts;
+        } else {
+            return Err(error!(MarketplaceError::MetadataCreatorParseError));
+        };
+        // Offer settles at the fee rates fixed on the creation
+        let quote = collection_offer_info.get_quote()?;
+        // Assert Offer terms are not changed after the seller signed
+        require!(
+            quote.price == expected_price && quote.royalty == expected_royalty,
+            MarketplaceError::TradeTermsMismatch
+        );
+        // Price & fees on top are locked with the offer
+        require!(
+            quote.buyer_total <= buyer_user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        buyer_user_pool.locked_sol_balance -= quote.buyer_total;
+        global_authority.escrow_liabilities -= quote.buyer_total;
+        buyer_user_pool.traded_volume += quote.price;
+        seller_user_pool.traded_volume += quote.price;
+        collection_offer_info.quantity -= 1;
+
+        let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        settle_from_escrow(
+            &escrow_vault,
+            &ctx.accounts.seller.to_account_info(),
+            &system_program,
+            global_authority,
+            creators,
+            ctx.remaining_accounts,
+            &quote,
+            signer,
+        )?;
+
+        let seller = &ctx.accounts.seller;
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: system_program.clone(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: ctx.accounts.token_mint_edition.to_account_info(),
+            token: ctx.accounts.dest_nft_token_account.to_account_info(),
+            token_owner: seller.to_account_info(),
+            token_record: ctx.accounts.dest_token_mint_record.to_account_info(),
+            delegate: ctx.accounts.global_authority.to_account_info(),
+            payer: seller.to_account_info(),
+        };
+        nft.transfer_by_owner(
+            &ctx.accounts.user_nft_token_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.token_mint_record.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+
+        // Filled offer is closed and the rent goes back to the buyer
+        if collection_offer_info.quantity == 0 {
+            collection_offer_info.close(ctx.accounts.buyer.to_account_info())?;
+        }
+
+        Ok(())
+    }
+
+    // NFT owner accepts a standing offer without listing, the NFT is transferred with the owner signature
+    // Remaining accounts are the team treasuries and then the creators
+    pub fn accept_standing_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptStandingOffer<'info>>,
+        escrow_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+    ) -> Result<()> {
+        let offer_data_info = &mut ctx.accounts.offer_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        let global_authority = &mut ctx.accounts.global_authority;
+        msg!(
+            "Offer Mint: {:?}, Seller: {:?}, Buyer: {:?}, Price: {}",
+            offer_data_info.mint,
+            ctx.accounts.seller.key(),
+            offer_data_info.buyer,
+            offer_data_info.offer_price,
+        );
+
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Seller User PDA Address
+        require!(
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Already Disabled Offer
+        require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
+        // Assert Standing Offer, listing offers are accepted with accept_offer_pnft
+        require!(
+            offer_data_info.offer_listing_date == 0,
+            MarketplaceError::OfferForExpiredListingNFT
+        );
+        // Assert Offer Expiry
+        require!(
+            !offer_data_info.is_expired(Clock::get()?.unix_timestamp),
+            MarketplaceError::ExpiredOffer
+        );
+
+        let mint_metadata = &ctx.accounts.mint_metadata;
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        let creators: &Vec<Creator>;
+        if let Some(cts) = &nft_metadata.creators {
+            creators = cts;
+        } else {
+            return Err(error!(MarketplaceError::MetadataCreatorParseError));
+        };
+
+        let quote = get_sale_quote(
+            offer_data_info.offer_price,
+            global_authority.market_fee_sol,
+            nft_metadata.seller_fee_basis_points,
+            resolve_pricing_mode(
+                PRICING_MODE_DEFAULT,
+                global_authority.pricing_mode,
+                PRICING_MODE_FEE_INCLUSIVE,
+            ),
+        )?;
+        // Assert Offer terms are not changed after the seller signed
+        require!(
+            quote.price == expected_price && quote.royalty == expected_royalty,
+            MarketplaceError::TradeTermsMismatch
+        );
+        // Offer price is locked, fees on top of it are paid from the buyer available balance
+        let fees_on_top = quote.buyer_total - quote.price;
+        require!(
+            quote.price <= buyer_user_pool.locked_sol_balance
+                && fees_on_top <= buyer_user_pool.escrow_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        buyer_user_pool.locked_sol_balance -= quote.price;
+        buyer_user_pool.escrow_sol_balance -= fees_on_top;
+        global_authority.escrow_liabilities -= quote.buyer_total;
+        buyer_user_pool.traded_volume += quote.price;
+        seller_user_pool.traded_volume += quote.price;
+        offer_data_info.active = 0;
+
+        let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        settle_from_escrow(
+            &escrow_vault,
+            &ctx.accounts.seller.to_account_info(),
+            &system_program,
+            global_authority,
+            creators,
+            ctx.remaining_accounts,
+            &quote,
+            signer,
+        )?;
+
+        let seller = &ctx.accounts.seller;
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program,
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: ctx.accounts.token_mint_edition.to_account_info(),
+            token: ctx.accounts.dest_nft_token_account.to_account_info(),
+            token_owner: seller.to_account_info(),
+            token_record: ctx.accounts.dest_token_mint_record.to_account_info(),
+            delegate: ctx.accounts.global_authority.to_account_info(),
+            payer: seller.to_account_info(),
+        };
+        nft.transfer_by_owner(
+            &ctx.accounts.user_nft_token_account.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.token_mint_record.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    pub fn init_auction_data(ctx: Context<InitAuctionData>, nft: Pubkey, _bump: u8) -> Result<()> {
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        auction_data_info.mint = nft;
+        auction_data_info.payer = ctx.accounts.payer.key();
+        auction_data_info.version = DATA_VERSION;
+        Ok(())
+    }
+
+    // Close the canceled or claimed Auction Data PDA and refund the rent to its payer
+    pub fn close_auction_data(ctx: Context<CloseAuctionData>, _bump: u8) -> Result<()> {
+        let auction_data_info = &ctx.accounts.auction_data_info;
+        msg!("Close Auction Data: {:?}", auction_data_info.mint);
+
+        // Assert Canceled Or Claimed Auction
+        require!(
+            auction_data_info.status == 0 || auction_data_info.status == 2,
+            MarketplaceError::DataAccountInUse
+        );
+        Ok(())
+    }
+
+    pub fn place_bid(
+        ctx: Context<PlaceBid>,
+        _auction_bump: u8,
+        escrow_bump: u8,
+        price: u64,
+    ) -> Result<()> {
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Place Date: {}", timestamp);
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Already Disabled Auction
+        require!(
+            auction_data_info.status == 1 || auction_data_info.status == 3,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert Auction Already Ended
+        require!(
+            auction_data_info.status == 3 || auction_data_info.get_end_date() > timestamp,
+            MarketplaceError::EndedAuction
+        );
+        // New Bid should be increased more than min_increase_amount
+        require!(
+            auction_data_info.highest_bid + auction_data_info.min_increase_amount <= price,
+            MarketplaceError::InvalidBidPrice
+        );
+        // Assert OutBidder Address with the Previous Bidder released by this bid
+        require!(
+            Pubkey::default().eq(&auction_data_info.prev_bidder)
+                || ctx
+                    .accounts
+                    .out_bidder
+                    .key()
+                    .eq(&auction_data_info.prev_bidder),
+            MarketplaceError::OutBidderMismatch
+        );
+        // Assert New Bidder is same with the Last Bidder
+        require!(
+            !ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
+            MarketplaceError::DoubleBidFromOneBidder
+        );
+        // Assert Bid from Auction Creator
+        require!(
+            !ctx.accounts.bidder.key().eq(&auction_data_info.creator),
+            MarketplaceError::BidFromAuctionCreator
         );
 
         // Assert NFT Pubkey with Sell Data PDA Mint
@@ -435,13 +1265,30 @@ pub mod mugs_marketplace {
         let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
         let signer = &[&seeds[..]];
 
-        // Refund Last Bidder Escrow
-        if !Pubkey::default().eq(&auction_data_info.last_bidder) {
+        // Refund Previous Bidder Escrow, the Last Bidder stays locked for the bid retraction
+        if !Pubkey::default().eq(&auction_data_info.prev_bidder) {
+            let prev_locked = auction_data_info.get_locked_amount(auction_data_info.prev_bid)?;
+            // Bidder outbid earlier is bidding again
+            if ctx.accounts.bidder.key().eq(&auction_data_info.prev_bidder) {
+                // Same User PDA shouldn't be passed twice
+                require!(
+                    ctx.accounts.out_bidder_user_pool.is_none(),
+                    MarketplaceError::InvalidParamInput
+                );
+                ctx.accounts.bidder_user_pool.locked_sol_balance -= prev_locked;
+            } else {
+                let out_bidder_user_pool = match &mut ctx.accounts.out_bidder_user_pool {
+                    Some(out_bidder_user_pool) => out_bidder_user_pool,
+                    None => return Err(error!(MarketplaceError::InvalidParamInput)),
+                };
+                out_bidder_user_pool.locked_sol_balance -= prev_locked;
+            }
+            ctx.accounts.global_authority.escrow_liabilities -= prev_locked;
             invoke_signed(
                 &system_instruction::transfer(
                     ctx.accounts.escrow_vault.key,
                     ctx.accounts.out_bidder.key,
-                    auction_data_info.highest_bid,
+                    prev_locked,
                 ),
                 &[
                     ctx.accounts.out_bidder.to_account_info().clone(),
@@ -451,12 +1298,13 @@ pub mod mugs_marketplace {
                 signer,
             )?;
         }
-        // Escrow New Bidder funds
+        // Escrow New Bidder funds with the fees on top of the bid
+        let locked = auction_data_info.get_locked_amount(price)?;
         invoke(
             &system_instruction::transfer(
                 ctx.accounts.bidder.key,
                 ctx.accounts.escrow_vault.key,
-                price,
+                locked,
             ),
             &[
                 ctx.accounts.bidder.to_account_info().clone(),
@@ -465,14 +1313,30 @@ pub mod mugs_marketplace {
             ],
         )?;
 
+        let bidder_user_pool = &mut ctx.accounts.bidder_user_pool;
+        // Assert Bidder User PDA Address
+        require!(
+            ctx.accounts.bidder.key().eq(&bidder_user_pool.address),
+            MarketplaceError::BidderAccountMismatch
+        );
+        bidder_user_pool.locked_sol_balance += locked;
+        ctx.accounts.global_authority.escrow_liabilities += locked;
+
+        auction_data_info.prev_bidder = auction_data_info.last_bidder;
+        auction_data_info.prev_bid = auction_data_info.highest_bid;
         auction_data_info.last_bid_date = timestamp;
         auction_data_info.last_bidder = ctx.accounts.bidder.key();
         auction_data_info.highest_bid = price;
         if auction_data_info.status == 3 {
             auction_data_info.status = 1;
             auction_data_info.start_date = timestamp;
+            auction_data_info.end_date = auction_data_info.get_end_date();
             sell_data_info.active = 0;
         }
+        // Anti-sniping extension for the bids at the end of the auction
+        if auction_data_info.extend_for_bid(timestamp) {
+            msg!("Auction Extended, End Date: {}", auction_data_info.end_date);
+        }
 
         Ok(())
     }
@@ -511,16 +1375,79 @@ pub mod mugs_marketplace {
         Ok(())
     }
 
+    // Reveal the hidden reserve price committed on the auction creation
+    pub fn reveal_reserve(
+        ctx: Context<RevealReserve>,
+        reserve_price: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", auction_data_info.mint);
+
+        // Assert Creator Pubkey is same with the Auction Data Creator
+        require!(
+            ctx.accounts.creator.key().eq(&auction_data_info.creator),
+            MarketplaceError::CreatorAccountMismatch
+        );
+        // Assert Auction is not Claimed or Canceled
+        require!(
+            auction_data_info.status == 1 || auction_data_info.status == 3,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert Committed Hidden Reserve
+        require!(
+            auction_data_info.has_hidden_reserve(),
+            MarketplaceError::InvalidParamInput
+        );
+        require!(
+            get_reserve_hash(
+                &auction_data_info.mint,
+                &auction_data_info.creator,
+                reserve_price,
+                &salt
+            ) == auction_data_info.reserve_hash,
+            MarketplaceError::InvalidReserveReveal
+        );
+
+        auction_data_info.reserve_price = reserve_price;
+        auction_data_info.reserve_hash = [0; 32];
+        msg!("Reserve Price: {}", reserve_price);
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn list_pnft_for_sale(
         ctx: Context<ListPNftForSale>,
         _global_bump: u8,
         _sell_bump: u8,
         _auction_bump: u8,
         price_sol: u64,
+        payment_mint: Pubkey,
+        expires_at: Option<i64>,
+        reserved_buyer: Option<Pubkey>,
+        dutch: Option<DutchSchedule>,
+        pricing_mode: u64,
     ) -> Result<()> {
         let sell_data_info = &mut ctx.accounts.sell_data_info;
 
-        msg!("Mint: {:?}", sell_data_info.mint);
+        msg!(
+            "Mint: {:?}, Payment Mint: {:?}",
+            sell_data_info.mint,
+            payment_mint
+        );
+        // Assert Payment Mint is an existing SPL Token mint for the listing not priced in SOL
+        if !payment_mint.eq(&Pubkey::default()) {
+            let payment_mint_account = match &ctx.accounts.payment_mint_account {
+                Some(payment_mint_account) => payment_mint_account,
+                None => return Err(error!(MarketplaceError::InvalidPaymentMint)),
+            };
+            require!(
+                payment_mint_account.key().eq(&payment_mint)
+                    && !payment_mint.eq(&ctx.accounts.nft_mint.key()),
+                MarketplaceError::InvalidPaymentMint
+            );
+        }
 
         let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[_global_bump]];
         let signer = &[&seeds[..]];
@@ -563,43 +1490,68 @@ pub mod mugs_marketplace {
         );
 
         // verify metadata is legit
-        let nft_metadata =
-            Metadata::safe_deserialize(&mut mint_metadata.data.borrow_mut()).unwrap();
-
-        if let Some(creators) = nft_metadata.creators {
-            let mut collection: Pubkey = Pubkey::default();
-            for creator in creators {
-                if creator.verified {
-                    collection = creator.address;
-                    break;
-                }
-            }
-            sell_data_info.collection = collection;
-            msg!("Collection= {:?}", collection);
-        } else {
-            return Err(error!(MarketplaceError::MetadataCreatorParseError));
-        }
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        let (collection, collection_source) = get_collection(&nft_metadata)?;
+        sell_data_info.collection = collection;
+        sell_data_info.collection_source = collection_source;
+        msg!(
+            "Collection= {:?}, Source: {}",
+            collection,
+            collection_source
+        );
 
         // Save Sell Data info
         let timestamp = Clock::get()?.unix_timestamp;
-        msg!("Listed Date: {}", timestamp);
+        msg!("Listed Date: {}, Expires At: {:?}", timestamp, expires_at);
+
+        // Assert Expiry is in the future
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > timestamp, MarketplaceError::InvalidParamInput);
+        }
+        // Assert Pricing Mode
+        require!(
+            pricing_mode <= PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
+        );
 
         sell_data_info.seller = ctx.accounts.owner.key();
         sell_data_info.price_sol = price_sol;
+        sell_data_info.pricing_mode = pricing_mode;
+        sell_data_info.payment_mint = payment_mint;
         sell_data_info.listed_date = timestamp;
+        sell_data_info.expires_at = expires_at.unwrap_or(0);
+        sell_data_info.reserved_buyer = reserved_buyer.unwrap_or_default();
+        sell_data_info.dutch_floor_price = 0;
+        sell_data_info.dutch_duration = 0;
+        sell_data_info.dutch_step = 0;
+        if let Some(dutch) = dutch {
+            msg!(
+                "Dutch Auction Floor: {}, Duration: {}, Step: {}",
+                dutch.floor_price,
+                dutch.duration,
+                dutch.step
+            );
+            // Assert Dutch Auction Schedule
+            require!(
+                dutch.floor_price < price_sol
+                    && dutch.duration > 0
+                    && dutch.step >= 0
+                    && dutch.step <= dutch.duration,
+                MarketplaceError::InvalidParamInput
+            );
+            sell_data_info.dutch_floor_price = dutch.floor_price;
+            sell_data_info.dutch_duration = dutch.duration;
+            sell_data_info.dutch_step = dutch.step;
+        }
         sell_data_info.active = 1;
 
         let token_account_info = &ctx.accounts.user_token_account;
         let dest_token_account_info = &ctx.accounts.dest_nft_token_account;
-        let owner: &Signer = &ctx.accounts.owner;
         let nft_mint = &ctx.accounts.nft_mint;
-        let token_mint_record = &ctx.accounts.token_mint_record;
-        let token_mint_edition = &ctx.accounts.token_mint_edition;
-        let token_program = &ctx.accounts.token_program;
-        let system_program = &ctx.accounts.system_program;
-        let sysvar_instructions = &ctx.accounts.sysvar_instructions;
-        let auth_rules_program = &ctx.accounts.auth_rules_program;
-        let auth_rules = &ctx.accounts.auth_rules;
         let global_authority = &ctx.accounts.global_authority;
 
         // Transfer NFT only if Not in Reserved Auction
@@ -610,48 +1562,34 @@ pub mod mugs_marketplace {
                 MarketplaceError::NFTIsNotInUserATA
             );
 
-            let expected_token_account = anchor_spl::associated_token::get_associated_token_address(
-                &global_authority.key(),
-                &nft_mint.key(),
-            );
+            let expected_token_account =
+                anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                    &global_authority.key(),
+                    &nft_mint.key(),
+                    &ctx.accounts.token_program.key(),
+                );
 
             if expected_token_account == dest_token_account_info.key() {
                 msg!("list pnft start");
 
-                DelegateLockedTransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                    .master_edition(Some(&token_mint_edition.to_account_info()))
-                    .spl_token_program(Some(&token_program.to_account_info()))
-                    .system_program(&system_program.to_account_info())
-                    .authority(&owner.to_account_info())
-                    .delegate(&global_authority.to_account_info())
-                    .payer(&owner.to_account_info())
-                    .mint(&nft_mint.to_account_info())
-                    .metadata(&mint_metadata.to_account_info())
-                    .token(&token_account_info.to_account_info())
-                    .token_record(Some(&token_mint_record.to_account_info()))
-                    .amount(1)
-                    .authorization_rules(Some(&auth_rules.to_account_info()))
-                    .sysvar_instructions(&sysvar_instructions.to_account_info())
-                    .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                    .system_program(&system_program.to_account_info())
-                    .locked_address(token_account_info.key())
-                    .invoke_signed(signer)?;
-                LockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                    .edition(Some(&token_mint_edition.to_account_info()))
-                    .spl_token_program(Some(&token_program.to_account_info()))
-                    .system_program(&system_program.to_account_info())
-                    .authority(&global_authority.to_account_info())
-                    .payer(&owner.to_account_info())
-                    .mint(&nft_mint.to_account_info())
-                    .metadata(&mint_metadata.to_account_info())
-                    .token(&token_account_info.to_account_info())
-                    .token_owner(Some(&owner.to_account_info()))
-                    .token_record(Some(&token_mint_record.to_account_info()))
-                    .authorization_rules(Some(&auth_rules.to_account_info()))
-                    .sysvar_instructions(&sysvar_instructions.to_account_info())
-                    .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                    .system_program(&system_program.to_account_info())
-                    .invoke_signed(signer)?;
+                let nft = NftContext {
+                    standard: nft_standard,
+                    token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                    auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                    auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                    mint: nft_mint.to_account_info(),
+                    metadata: mint_metadata.to_account_info(),
+                    edition: ctx.accounts.token_mint_edition.to_account_info(),
+                    token: token_account_info.to_account_info(),
+                    token_owner: ctx.accounts.owner.to_account_info(),
+                    token_record: ctx.accounts.token_mint_record.to_account_info(),
+                    delegate: global_authority.to_account_info(),
+                    payer: ctx.accounts.owner.to_account_info(),
+                };
+                nft.lock(signer)?;
                 msg!("list pnft ix end");
             } else {
 
@@ -700,7 +1638,6 @@ pub mod mugs_marketplace {
 
         sell_data_info.active = 0;
 
-        let token_program = &mut &ctx.accounts.token_program;
         let seeds: &[&[u8]; 2] = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
         let signer = &[&seeds[..]];
 
@@ -714,16 +1651,18 @@ pub mod mugs_marketplace {
             MarketplaceError::InvaliedMetadata
         );
 
-        let global_authority = &ctx.accounts.global_authority;
+        // verify metadata is legit
+        let (_, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
         let owner = &ctx.accounts.owner;
         let token_account_info = &ctx.accounts.user_token_account;
         let nft_mint = &ctx.accounts.nft_mint;
 
         let token_mint_edition = &ctx.accounts.token_mint_edition;
         let token_mint_record = &ctx.accounts.token_mint_record;
-        let system_program = &ctx.accounts.system_program;
-        let sysvar_instructions = &ctx.accounts.sysvar_instructions;
-        let auth_rules_program = &ctx.accounts.auth_rules_program;
         let auth_rules = &ctx.accounts.auth_rules;
 
         if auction_data_info.status != 3 {
@@ -751,49 +1690,130 @@ pub mod mugs_marketplace {
             if auth_rules.owner != ctx.accounts.auth_rules_program.key {
                 msg!("Auth Rules O");
             }
-            UnlockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token(&token_account_info.to_account_info())
-                .token_owner(Some(&owner.to_account_info()))
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .invoke_signed(signer)?;
-            RevokeLockedTransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .master_edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&owner.to_account_info())
-                .delegate(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .token(&token_account_info.to_account_info())
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .invoke_signed(signer)?;
+            let nft = NftContext {
+                standard: nft_standard,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: auth_rules.to_account_info(),
+                mint: nft_mint.to_account_info(),
+                metadata: mint_metadata.to_account_info(),
+                edition: token_mint_edition.to_account_info(),
+                token: token_account_info.to_account_info(),
+                token_owner: owner.to_account_info(),
+                token_record: token_mint_record.to_account_info(),
+                delegate: ctx.accounts.global_authority.to_account_info(),
+                payer: owner.to_account_info(),
+            };
+            nft.unlock_and_revoke(&owner.to_account_info(), signer)?;
             msg!("Create token account end");
         }
 
         Ok(())
     }
 
+    // Permissionless unlock of the expired listing back to the seller
+    pub fn delist_expired_pnft(
+        ctx: Context<DelistExpiredPNft>,
+        global_bump: u8,
+        _sell_bump: u8,
+    ) -> Result<()> {
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        msg!("Mint: {:?}", sell_data_info.mint);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Delist Date: {}", timestamp);
+        // Assert NFT Pubkey with Sell Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        // Assert Seller Pubkey with Sell Data PDA Seller Address
+        require!(
+            ctx.accounts.seller.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerAccountMismatch
+        );
+        // Assert Already Delisted NFT
+        require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
+        // Assert Listing Expiry
+        require!(
+            sell_data_info.is_expired(timestamp),
+            MarketplaceError::NotExpiredListing
+        );
+        // Assert NFT Pubkey with Auction Data PDA Mint
+        require!(
+            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+            MarketplaceError::InvalidNFTDataAcount
+        );
+
+        sell_data_info.active = 0;
+
+        let seeds: &[&[u8]; 2] = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+
+        let mint_metadata: &AccountInfo = &ctx.accounts.mint_metadata;
+        msg!("Metadata Account: {:?}", ctx.accounts.mint_metadata.key());
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+
+        // verify metadata is legit
+        let (_, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        // NFT stays locked while it is in the Reserved Auction
+        if auction_data_info.status != 3 {
+            let global_authority = ctx.accounts.global_authority.to_account_info();
+            let nft = NftContext {
+                standard: nft_standard,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                metadata: mint_metadata.to_account_info(),
+                edition: ctx.accounts.token_mint_edition.to_account_info(),
+                token: ctx.accounts.user_token_account.to_account_info(),
+                token_owner: ctx.accounts.seller.to_account_info(),
+                token_record: ctx.accounts.token_mint_record.to_account_info(),
+                delegate: global_authority.clone(),
+                payer: ctx.accounts.payer.to_account_info(),
+            };
+            // Delegate clears itself as the seller is not signing
+            nft.unlock_and_revoke_by_delegate(
+                &ctx.accounts.global_nft_token_account.to_account_info(),
+                &ctx.accounts.associated_token_program.to_account_info(),
+                signer,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // from_escrow: pay the SOL listing from the buyer escrow balance in the escrow vault
+    // credit_recipient: credit the traded volume to the recipient instead of the buyer
+    #[allow(clippy::too_many_arguments)]
     pub fn purchase_pnft<'info>(
         ctx: Context<'_, '_, '_, 'info, PurchasePNft<'info>>,
         global_bump: u8,
         _nft_bump: u8,
         _seller_bump: u8,
         _buyer_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+        from_escrow: bool,
+        escrow_bump: u8,
+        credit_recipient: bool,
     ) -> Result<()> {
         // By Token should be zero or one
         let sell_data_info: &mut Box<Account<'info, SellData>> = &mut ctx.accounts.sell_data_info;
@@ -832,10 +1852,17 @@ pub mod mugs_marketplace {
         );
 
         // verify metadata is legit
-        let nft_metadata =
-            Metadata::safe_deserialize(&mut mint_metadata.data.borrow_mut()).unwrap();
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
 
         require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
+        // Assert Listing Expiry
+        require!(
+            !sell_data_info.is_expired(Clock::get()?.unix_timestamp),
+            MarketplaceError::ExpiredListing
+        );
         // Assert Seller Sell Data Address
         require!(
             ctx.accounts.seller.key().eq(&sell_data_info.seller),
@@ -851,11 +1878,32 @@ pub mod mugs_marketplace {
             ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
             MarketplaceError::InvalidOwner
         );
+        // Assert Escrow Payment Buyer Is Not The Seller, the same User PDA can't be written twice
+        require!(
+            !from_escrow || !ctx.accounts.buyer.key().eq(&ctx.accounts.seller.key()),
+            MarketplaceError::DuplicateUserAccount
+        );
+        // Assert Credited Recipient Is Not The Buyer Or The Seller, their User PDAs are written too
+        require!(
+            !credit_recipient
+                || !(ctx.accounts.recipient.key().eq(&ctx.accounts.buyer.key())
+                    || ctx.accounts.recipient.key().eq(&ctx.accounts.seller.key())),
+            MarketplaceError::DuplicateUserAccount
+        );
+        // Assert Private Listing Buyer, the reserved buyer should pay even for a gift purchase
+        require!(
+            !sell_data_info.is_reserved_for_other(&ctx.accounts.buyer.key()),
+            MarketplaceError::ReservedListing
+        );
 
         sell_data_info.active = 0;
         if auction_data_info.status == 3 {
             auction_data_info.status = 0;
         }
+        // Dutch auction settles at the current price
+        let is_dutch = sell_data_info.is_dutch();
+        let price_sol = sell_data_info.get_current_price(Clock::get()?.unix_timestamp);
+        msg!("Purchase Price: {}", price_sol);
 
         let dest_nft_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
         let token_program = &mut &ctx.accounts.token_program;
@@ -895,364 +1943,932 @@ pub mod mugs_marketplace {
             MarketplaceError::TeamTreasuryCountMismatch
         );
 
-        let total_share_fee =
-            sell_data_info.price_sol * (nft_metadata.seller_fee_basis_points as u64) / PERMYRIAD;
-        let fee_amount: u64 =
-            sell_data_info.price_sol * global_authority.market_fee_sol / PERMYRIAD;
-        // let total_fee_amount: u64 = total_share_fee + fee_amount;
-
-        invoke(
-            &system_instruction::transfer(
-                ctx.accounts.buyer.key,
-                ctx.accounts.seller.key,
-                sell_data_info.price_sol,
+        let quote = get_sale_quote(
+            price_sol,
+            global_authority.market_fee_sol,
+            nft_metadata.seller_fee_basis_points,
+            resolve_pricing_mode(
+                sell_data_info.pricing_mode,
+                global_authority.pricing_mode,
+                PRICING_MODE_FEE_ON_TOP,
             ),
-            &[
-                ctx.accounts.buyer.to_account_info().clone(),
-                ctx.accounts.seller.to_account_info().clone(),
-                ctx.accounts.system_program.to_account_info().clone(),
-            ],
         )?;
+        let total_share_fee = quote.royalty;
+        let fee_amount: u64 = quote.market_fee;
+        // Assert Listing Terms are not changed after the buyer signed
+        // Dutch auction price can only go down until the purchase lands
+        let terms_match = if is_dutch {
+            price_sol <= expected_price && total_share_fee <= expected_royalty
+        } else {
+            price_sol == expected_price && total_share_fee == expected_royalty
+        };
+        require!(terms_match, MarketplaceError::TradeTermsMismatch);
+        let payment_mint = sell_data_info.payment_mint;
+        let is_sol_payment = payment_mint.eq(&Pubkey::default());
+
+        let escrow_seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let escrow_signer = &[&escrow_seeds[..]];
+
+        // SOL is paid from the buyer wallet or escrow balance, SPL Token from the buyer payment ATA
+        let (payment_source, seller_payment_account) = if from_escrow {
+            // Assert Escrow Payment for SOL Listing
+            require!(is_sol_payment, MarketplaceError::InvalidPaymentTokenAccount);
+            let escrow_vault = match &ctx.accounts.escrow_vault {
+                Some(escrow_vault) => escrow_vault.to_account_info(),
+                None => return Err(error!(MarketplaceError::InvalidParamInput)),
+            };
+            require!(
+                quote.buyer_total <= buyer_user_pool.escrow_sol_balance,
+                MarketplaceError::InsufficientBuyerSolBalance
+            );
+            buyer_user_pool.escrow_sol_balance -= quote.buyer_total;
+            (escrow_vault, seller.to_account_info())
+        } else if is_sol_payment {
+            (owner.to_account_info(), seller.to_account_info())
+        } else {
+            let (buyer_payment_token_account, seller_payment_token_account) = match (
+                &ctx.accounts.buyer_payment_token_account,
+                &ctx.accounts.seller_payment_token_account,
+                &ctx.accounts.payment_token_program,
+            ) {
+                (Some(buyer_account), Some(seller_account), Some(_)) => {
+                    (buyer_account, seller_account)
+                }
+                _ => return Err(error!(MarketplaceError::InvalidPaymentTokenAccount)),
+            };
+            // Assert Payment Token Accounts with Listing Payment Mint
+            require!(
+                buyer_payment_token_account.mint.eq(&payment_mint)
+                    && buyer_payment_token_account.owner.eq(&owner.key()),
+                MarketplaceError::InvalidPaymentTokenAccount
+            );
+            require!(
+                seller_payment_token_account.mint.eq(&payment_mint)
+                    && seller_payment_token_account.owner.eq(&seller.key()),
+                MarketplaceError::InvalidPaymentTokenAccount
+            );
+            require!(
+                buyer_payment_token_account.amount >= quote.buyer_total,
+                MarketplaceError::InsufficientBuyerTokenBalance
+            );
+            (
+                buyer_payment_token_account.to_account_info(),
+                seller_payment_token_account.to_account_info(),
+            )
+        };
+        let payment_token_program = match &ctx.accounts.payment_token_program {
+            Some(program) => program.to_account_info(),
+            None => token_program.to_account_info(),
+        };
+        let sol_signer: &[&[&[u8]]] = if from_escrow { escrow_signer } else { &[] };
+        let pay = |to: &AccountInfo<'info>, amount: u64| -> Result<()> {
+            if is_sol_payment {
+                transfer_sol(
+                    &payment_source,
+                    to,
+                    &system_program.to_account_info(),
+                    amount,
+                    sol_signer,
+                )
+            } else {
+                // Assert Payout ATA exists, the buyer creates the missing ATAs with the purchase
+                require!(
+                    !to.data_is_empty(),
+                    MarketplaceError::PaymentAccountNotInitialized
+                );
+                transfer_token(
+                    &payment_source,
+                    to,
+                    &owner.to_account_info(),
+                    &payment_token_program,
+                    amount,
+                    &[],
+                )
+            }
+        };
 
-        let mut i = 0;
+        pay(&seller_payment_account, quote.seller_proceeds)?;
+
+        let treasury_shares = get_treasury_shares(global_authority, fee_amount);
+        let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+        let royalty_shares = get_royalty_shares(&creator_shares, total_share_fee);
         // This is not expensive cuz the max count is 8
-        for team_account in remaining_accounts {
-            if i < global_authority.team_count {
+        for (i, team_account) in remaining_accounts.iter().enumerate() {
+            if (i as u64) < global_authority.team_count {
                 require!(
-                    team_account
-                        .key()
-                        .eq(&global_authority.team_treasury[i as usize]),
+                    team_account.key().eq(&get_payment_address(
+                        &global_authority.team_treasury[i],
+                        &payment_mint
+                    )),
                     MarketplaceError::TeamTreasuryAddressMismatch
                 );
-                invoke(
-                    &system_instruction::transfer(
-                        ctx.accounts.buyer.key,
-                        &global_authority.team_treasury[i as usize],
-                        fee_amount * global_authority.treasury_rate[i as usize] / PERMYRIAD,
-                    ),
-                    &[
-                        ctx.accounts.buyer.to_account_info().clone(),
-                        team_account.clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                )?;
+                pay(team_account, treasury_shares[i])?;
             } else {
-                for creator in creators {
-                    if creator.address == team_account.key() && creator.share != 0 {
-                        let share_amount: u64 = total_share_fee * (creator.share as u64) / 100;
-                        invoke(
-                            &system_instruction::transfer(
-                                ctx.accounts.buyer.key,
-                                &team_account.key(),
-                                share_amount,
-                            ),
-                            &[
-                                ctx.accounts.buyer.to_account_info().clone(),
-                                team_account.clone(),
-                                ctx.accounts.system_program.to_account_info().clone(),
-                            ],
-                        )?;
-                    }
-                }
+                let j = i - global_authority.team_count as usize;
+                // Assert Remaining Account is the Creator in the Metadata order
+                require!(
+                    team_account
+                        .key()
+                        .eq(&get_payment_address(&creators[j].address, &payment_mint)),
+                    MarketplaceError::CreatorAddressMismatch
+                );
+                pay(team_account, royalty_shares[j])?;
             }
-
-            i += 1;
         }
-        buyer_user_pool.traded_volume += sell_data_info.price_sol;
-        seller_user_pool.traded_volume += sell_data_info.price_sol;
-        UnlockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .authority(&global_authority.to_account_info())
-            .payer(&owner.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(Some(&seller.to_account_info()))
-            .token(&dest_nft_token_account_info.to_account_info())
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .spl_token_program(Some(&token_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
-        TransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .authority(&global_authority.to_account_info())
-            .payer(&owner.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .destination_token(&token_account_info.to_account_info())
-            .destination_owner(&owner.to_account_info())
-            .destination_token_record(Some(&token_mint_record.to_account_info()))
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(&seller.to_account_info())
-            .token(&dest_nft_token_account_info.to_account_info())
-            .amount(1)
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .spl_ata_program(&associated_token_program.to_account_info())
-            .spl_token_program(&token_program.to_account_info())
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
+        if credit_recipient {
+            match &mut ctx.accounts.recipient_user_pool {
+                Some(recipient_user_pool) => recipient_user_pool.traded_volume += price_sol,
+                None => return Err(error!(MarketplaceError::InvalidParamInput)),
+            }
+        } else {
+            buyer_user_pool.traded_volume += price_sol;
+        }
+        seller_user_pool.traded_volume += price_sol;
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+            sysvar_instructions: sysvar_instructions.to_account_info(),
+            auth_rules_program: auth_rules_program.to_account_info(),
+            auth_rules: auth_rules.to_account_info(),
+            mint: nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: token_mint_edition.to_account_info(),
+            token: dest_nft_token_account_info.to_account_info(),
+            token_owner: seller.to_account_info(),
+            token_record: dest_token_mint_record.to_account_info(),
+            delegate: global_authority.to_account_info(),
+            payer: owner.to_account_info(),
+        };
+        nft.transfer(
+            &token_account_info.to_account_info(),
+            &ctx.accounts.recipient.to_account_info(),
+            &token_mint_record.to_account_info(),
+            &associated_token_program.to_account_info(),
+            signer,
+        )?;
+        if from_escrow {
+            ctx.accounts.global_authority.escrow_liabilities -= quote.buyer_total;
+        }
 
         Ok(())
     }
 
-    pub fn accept_offer_pnft<'info>(
-        ctx: Context<'_, '_, '_, 'info, AcceptOfferPNft<'info>>,
-        global_bump: u8,
-        _nft_bump: u8,
-        _offer_bump: u8,
-        _buyer_bump: u8,
-        _seller_bump: u8,
-        escrow_bump: u8,
+    // Compressed NFT is listed by delegating the leaf to the global authority, SOL only
+    // Remaining accounts are the Merkle proof nodes
+    pub fn list_cnft_for_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListCNftForSale<'info>>,
+        _global_bump: u8,
+        price_sol: u64,
+        expires_at: Option<i64>,
+        pricing_mode: u64,
+        metadata: MetadataArgs,
+        leaf: CNftLeaf,
     ) -> Result<()> {
         let sell_data_info = &mut ctx.accounts.sell_data_info;
-        let auction_data_info = &mut ctx.accounts.auction_data_info;
-        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
-        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
-
-        let buyer = &ctx.accounts.buyer;
-        let token_account_info = &ctx.accounts.user_nft_token_account;
-        let nft_mint = &ctx.accounts.nft_mint;
-
-        let token_mint_edition = &ctx.accounts.token_mint_edition;
-        let token_mint_record = &ctx.accounts.token_mint_record;
-        let dest_token_mint_record = &ctx.accounts.dest_token_mint_record;
-        let system_program = &ctx.accounts.system_program;
-        let sysvar_instructions = &ctx.accounts.sysvar_instructions;
-        let associated_token_program = &ctx.accounts.associated_token_program;
-        let auth_rules_program = &ctx.accounts.auth_rules_program;
-        let auth_rules = &ctx.accounts.auth_rules;
+        let asset_id = get_asset_id(&ctx.accounts.merkle_tree.key(), leaf.nonce);
+        msg!("Asset Id: {:?}, Nonce: {}", asset_id, leaf.nonce);
 
-        // Assert NFT Pubkey with Sell Data PDA Mint
+        // Assert Asset Id with Sell Data PDA Mint
         require!(
-            ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+            asset_id.eq(&sell_data_info.mint),
             MarketplaceError::InvalidNFTDataAcount
         );
+        // Assert Not Listed Asset
+        require!(
+            sell_data_info.active == 0,
+            MarketplaceError::DataAccountInUse
+        );
 
-        // Assert NFT Pubkey with Auction Data PDA Mint
+        let auction_data_info = &ctx.accounts.auction_data_info;
+        // Assert Reserved Or Not Started Auction
         require!(
-            ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-            MarketplaceError::InvalidNFTDataAcount
+            auction_data_info.status != 1,
+            MarketplaceError::ListingNotAvailable
         );
+        // Assert Owner Pubkey is same with the Auction Data Creator if Asset is in Reserved Auction
         if auction_data_info.status == 3 {
-            // Assert Creator Pubkey is same with the Auction Data Creator
             require!(
-                ctx.accounts.seller.key().eq(&auction_data_info.creator),
+                ctx.accounts.owner.key().eq(&auction_data_info.creator),
                 MarketplaceError::CreatorAccountMismatch
             );
         }
 
-        // Get Collection address from Metadata
-        let mint_metadata = &mut &ctx.accounts.mint_metadata;
-        msg!("Metadata Account: {:?}", ctx.accounts.mint_metadata.key());
-        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Listed Date: {}, Expires At: {:?}", timestamp, expires_at);
+
+        // Assert Expiry is in the future
+        if let Some(expires_at) = expires_at {
+            require!(expires_at > timestamp, MarketplaceError::InvalidParamInput);
+        }
+        // Assert Pricing Mode
         require!(
-            metadata == mint_metadata.key(),
-            MarketplaceError::InvaliedMetadata
+            pricing_mode <= PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
         );
 
-        // verify metadata is legit
-        let nft_metadata =
-            Metadata::safe_deserialize(&mut mint_metadata.data.borrow_mut()).unwrap();
+        let (collection, collection_source) = get_cnft_collection(&metadata);
+        msg!(
+            "Collection= {:?}, Source: {}",
+            collection,
+            collection_source
+        );
 
-        // Assert Buyer User PDA Address
+        sell_data_info.seller = ctx.accounts.owner.key();
+        sell_data_info.collection = collection;
+        sell_data_info.collection_source = collection_source;
+        sell_data_info.price_sol = price_sol;
+        sell_data_info.pricing_mode = pricing_mode;
+        sell_data_info.payment_mint = Pubkey::default();
+        sell_data_info.listed_date = timestamp;
+        sell_data_info.expires_at = expires_at.unwrap_or(0);
+        sell_data_info.merkle_tree = ctx.accounts.merkle_tree.key();
+        sell_data_info.leaf_nonce = leaf.nonce;
+        sell_data_info.reserved_buyer = Pubkey::default();
+        sell_data_info.dutch_duration = 0;
+        sell_data_info.active = 1;
+
+        let (data_hash, creator_hash) = CNftContext::hash_leaf(&metadata)?;
+        let cnft = CNftContext {
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            proof: ctx.remaining_accounts.to_vec(),
+            root: leaf.root,
+            data_hash,
+            creator_hash,
+            nonce: leaf.nonce,
+            index: leaf.index,
+        };
+        let owner = ctx.accounts.owner.to_account_info();
+        cnft.delegate(
+            &owner,
+            &owner,
+            &ctx.accounts.global_authority.to_account_info(),
+        )?;
+
+        Ok(())
+    }
+
+    // Remaining accounts are the Merkle proof nodes
+    pub fn delist_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelistCNft<'info>>,
+        _global_bump: u8,
+        metadata: MetadataArgs,
+        leaf: CNftLeaf,
+    ) -> Result<()> {
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        msg!("Asset Id: {:?}", sell_data_info.mint);
+
+        // Assert Tree & Leaf Nonce with Sell Data
         require!(
-            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
-            MarketplaceError::InvalidOwner
+            ctx.accounts
+                .merkle_tree
+                .key()
+                .eq(&sell_data_info.merkle_tree)
+                && leaf.nonce == sell_data_info.leaf_nonce,
+            MarketplaceError::InvalidNFTDataAcount
         );
-        // Assert Seller User PDA Address
+        // Assert NFT seller is payer
         require!(
-            ctx.accounts.seller.key().eq(&seller_user_pool.address),
-            MarketplaceError::InvalidOwner
+            ctx.accounts.owner.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerMismatch
         );
-
         // Assert Already Delisted NFT
         require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
-        // Assert Seller Pubkey with Sell Data PDA Seller Address
+
+        sell_data_info.active = 0;
+
+        let (data_hash, creator_hash) = CNftContext::hash_leaf(&metadata)?;
+        let cnft = CNftContext {
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            proof: ctx.remaining_accounts.to_vec(),
+            root: leaf.root,
+            data_hash,
+            creator_hash,
+            nonce: leaf.nonce,
+            index: leaf.index,
+        };
+        // Leaf delegate goes back to the owner
+        let owner = ctx.accounts.owner.to_account_info();
+        cnft.delegate(
+            &owner,
+            &ctx.accounts.global_authority.to_account_info(),
+            &owner,
+        )?;
+
+        Ok(())
+    }
+
+    // Remaining accounts are the team treasuries, the creators and then the Merkle proof nodes
+    pub fn purchase_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseCNft<'info>>,
+        global_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+        metadata: MetadataArgs,
+        leaf: CNftLeaf,
+    ) -> Result<()> {
+        let sell_data_info = &mut ctx.accounts.sell_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        let global_authority = &ctx.accounts.global_authority;
+        msg!("Purchase Asset Id: {:?}", sell_data_info.mint);
+
+        // Assert Tree & Leaf Nonce with Sell Data
+        require!(
+            ctx.accounts
+                .merkle_tree
+                .key()
+                .eq(&sell_data_info.merkle_tree)
+                && leaf.nonce == sell_data_info.leaf_nonce,
+            MarketplaceError::InvalidNFTDataAcount
+        );
+        require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
+        // Assert Listing Expiry
+        require!(
+            !sell_data_info.is_expired(Clock::get()?.unix_timestamp),
+            MarketplaceError::ExpiredListing
+        );
+        // Assert Seller Sell Data Address
         require!(
             ctx.accounts.seller.key().eq(&sell_data_info.seller),
             MarketplaceError::SellerAccountMismatch
         );
-
-        let offer_data_info = &mut ctx.accounts.offer_data_info;
-        // Assert NFT Pubkey with Offer Data PDA Mint
+        // Assert Seller User PDA Address
         require!(
-            ctx.accounts.nft_mint.key().eq(&offer_data_info.mint),
-            MarketplaceError::InvalidOfferDataMint
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Buyer Pubkey with Offer Data PDA Buyer Address
+        // Assert Buyer User PDA Address
         require!(
-            ctx.accounts.buyer.key().eq(&offer_data_info.buyer),
-            MarketplaceError::InvalidOfferDataBuyer
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
         );
-        // Assert Already Disabled Offer
-        require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
-        // Assert Offer provided date with the NFT Listed Date
+        // At least one treasury should exist to trade NFT
         require!(
-            offer_data_info.offer_listing_date == sell_data_info.listed_date,
-            MarketplaceError::OfferForExpiredListingNFT
-        );
-
-        msg!(
-            "Offer Mint: {:?}, Seller: {:?}, Buyer: {:?}, Price: {}",
-            offer_data_info.mint,
-            sell_data_info.seller,
-            offer_data_info.buyer,
-            offer_data_info.offer_price,
+            global_authority.team_count > 0,
+            MarketplaceError::NoTeamTreasuryYet
         );
 
-        offer_data_info.active = 0;
         sell_data_info.active = 0;
 
-        if auction_data_info.status == 3 {
-            auction_data_info.status = 0;
-        }
+        let creators = &metadata.creators;
+        let payee_count = (global_authority.team_count as usize) + creators.len();
+        require!(
+            payee_count <= ctx.remaining_accounts.len(),
+            MarketplaceError::TeamTreasuryCountMismatch
+        );
+        let (payee_accounts, proof) = ctx.remaining_accounts.split_at(payee_count);
+
+        let quote = get_sale_quote(
+            sell_data_info.price_sol,
+            global_authority.market_fee_sol,
+            metadata.seller_fee_basis_points,
+            resolve_pricing_mode(
+                sell_data_info.pricing_mode,
+                global_authority.pricing_mode,
+                PRICING_MODE_FEE_ON_TOP,
+            ),
+        )?;
+        let total_share_fee = quote.royalty;
+        let fee_amount: u64 = quote.market_fee;
+        // Assert Listing Terms are not changed after the buyer signed
+        require!(
+            sell_data_info.price_sol == expected_price && total_share_fee == expected_royalty,
+            MarketplaceError::TradeTermsMismatch
+        );
+
+        let buyer = ctx.accounts.buyer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        transfer_sol(
+            &buyer,
+            &ctx.accounts.seller.to_account_info(),
+            &system_program,
+            quote.seller_proceeds,
+            &[],
+        )?;
+
+        let treasury_shares = get_treasury_shares(global_authority, fee_amount);
+        let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+        let royalty_shares = get_royalty_shares(&creator_shares, total_share_fee);
+        for (i, team_account) in payee_accounts.iter().enumerate() {
+            if (i as u64) < global_authority.team_count {
+                require!(
+                    team_account.key().eq(&global_authority.team_treasury[i]),
+                    MarketplaceError::TeamTreasuryAddressMismatch
+                );
+                transfer_sol(
+                    &buyer,
+                    team_account,
+                    &system_program,
+                    treasury_shares[i],
+                    &[],
+                )?;
+            } else {
+                let j = i - global_authority.team_count as usize;
+                // Assert Remaining Account is the Creator in the Metadata order
+                require!(
+                    team_account.key().eq(&creators[j].address),
+                    MarketplaceError::CreatorAddressMismatch
+                );
+                transfer_sol(
+                    &buyer,
+                    team_account,
+                    &system_program,
+                    royalty_shares[j],
+                    &[],
+                )?;
+            }
+        }
+        buyer_user_pool.traded_volume += sell_data_info.price_sol;
+        seller_user_pool.traded_volume += sell_data_info.price_sol;
+
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+        let (data_hash, creator_hash) = CNftContext::hash_leaf(&metadata)?;
+        let cnft = CNftContext {
+            bubblegum_program: ctx.accounts.bubblegum_program.to_account_info(),
+            tree_config: ctx.accounts.tree_config.to_account_info(),
+            merkle_tree: ctx.accounts.merkle_tree.to_account_info(),
+            log_wrapper: ctx.accounts.log_wrapper.to_account_info(),
+            compression_program: ctx.accounts.compression_program.to_account_info(),
+            system_program,
+            proof: proof.to_vec(),
+            root: leaf.root,
+            data_hash,
+            creator_hash,
+            nonce: leaf.nonce,
+            index: leaf.index,
+        };
+        cnft.transfer(
+            &ctx.accounts.seller.to_account_info(),
+            &global_authority.to_account_info(),
+            &buyer,
+            signer,
+        )?;
+
+        Ok(())
+    }
+
+    // Remaining accounts are per bundle item the mint, metadata, edition, token account & token record
+    pub fn list_bundle_for_sale<'info>(
+        ctx: Context<'_, '_, '_, 'info, ListBundleForSale<'info>>,
+        global_bump: u8,
+        bundle_id: u64,
+        price_sol: u64,
+        pricing_mode: u64,
+    ) -> Result<()> {
+        let bundle_data_info = &mut ctx.accounts.bundle_data_info;
+        let item_count = ctx.remaining_accounts.len() / BUNDLE_LIST_ACCOUNTS;
+        msg!("Bundle Id: {}, Items: {}", bundle_id, item_count);
 
+        // Assert Bundle Item Count
         require!(
-            offer_data_info.offer_price <= buyer_user_pool.escrow_sol_balance,
-            MarketplaceError::InsufficientBuyerSolBalance
+            ctx.remaining_accounts.len() == item_count * BUNDLE_LIST_ACCOUNTS
+                && item_count > 1
+                && item_count <= MAX_BUNDLE_ITEMS,
+            MarketplaceError::BundleAccountsMismatch
+        );
+        // Assert Pricing Mode
+        require!(
+            pricing_mode <= PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
         );
-        buyer_user_pool.escrow_sol_balance -= offer_data_info.offer_price;
-        buyer_user_pool.traded_volume += offer_data_info.offer_price;
-        seller_user_pool.traded_volume += offer_data_info.offer_price;
 
-        let token_program = &mut &ctx.accounts.token_program;
-        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let timestamp = Clock::get()?.unix_timestamp;
+        bundle_data_info.seller = ctx.accounts.owner.key();
+        bundle_data_info.bundle_id = bundle_id;
+        bundle_data_info.price_sol = price_sol;
+        bundle_data_info.listed_date = timestamp;
+        bundle_data_info.item_count = item_count as u64;
+        bundle_data_info.pricing_mode = pricing_mode;
+
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
         let signer = &[&seeds[..]];
+        let owner = ctx.accounts.owner.to_account_info();
+        for (k, item) in ctx
+            .remaining_accounts
+            .chunks(BUNDLE_LIST_ACCOUNTS)
+            .enumerate()
+        {
+            let (mint, metadata, edition, token, token_record) =
+                (&item[0], &item[1], &item[2], &item[3], &item[4]);
+            load_bundle_item(mint, metadata, token, &owner.key())?;
+            // Assert Duplicated Bundle Item
+            require!(
+                !bundle_data_info.mints[..k].contains(mint.key),
+                MarketplaceError::BundleAccountsMismatch
+            );
+            bundle_data_info.mints[k] = mint.key();
+
+            let nft = NftContext {
+                standard: NftStandard::Programmable,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                mint: mint.clone(),
+                metadata: metadata.clone(),
+                edition: edition.clone(),
+                token: token.clone(),
+                token_owner: owner.clone(),
+                token_record: token_record.clone(),
+                delegate: ctx.accounts.global_authority.to_account_info(),
+                payer: owner.clone(),
+            };
+            nft.lock(signer)?;
+        }
 
-        let global_authority = &mut ctx.accounts.global_authority;
-        let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
+        Ok(())
+    }
+
+    // Remaining accounts are per bundle item the mint, metadata, edition, token account & token record
+    pub fn delist_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, DelistBundle<'info>>,
+        global_bump: u8,
+    ) -> Result<()> {
+        let bundle_data_info = &ctx.accounts.bundle_data_info;
+        msg!("Bundle Id: {}", bundle_data_info.bundle_id);
+
+        // Assert Bundle Item Accounts
+        require!(
+            ctx.remaining_accounts.len()
+                == bundle_data_info.item_count as usize * BUNDLE_LIST_ACCOUNTS,
+            MarketplaceError::BundleAccountsMismatch
+        );
+
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+        let owner = ctx.accounts.owner.to_account_info();
+        for (k, item) in ctx
+            .remaining_accounts
+            .chunks(BUNDLE_LIST_ACCOUNTS)
+            .enumerate()
+        {
+            let (mint, metadata, edition, token, token_record) =
+                (&item[0], &item[1], &item[2], &item[3], &item[4]);
+            // Assert Bundle Item Mint
+            require!(
+                mint.key().eq(&bundle_data_info.mints[k]),
+                MarketplaceError::InvalidNFTDataAcount
+            );
+            load_bundle_item(mint, metadata, token, &owner.key())?;
+
+            let nft = NftContext {
+                standard: NftStandard::Programmable,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                mint: mint.clone(),
+                metadata: metadata.clone(),
+                edition: edition.clone(),
+                token: token.clone(),
+                token_owner: owner.clone(),
+                token_record: token_record.clone(),
+                delegate: ctx.accounts.global_authority.to_account_info(),
+                payer: owner.clone(),
+            };
+            nft.unlock_and_revoke(&owner, signer)?;
+        }
+
+        Ok(())
+    }
+
+    // Bundle price is split evenly over the items, fees & royalties are prorated per item metadata
+    // Remaining accounts are the team treasuries and then per bundle item the mint, metadata, edition,
+    // seller token account & token record, buyer token account & token record and the creators
+    pub fn purchase_bundle<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseBundle<'info>>,
+        global_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+    ) -> Result<()> {
+        let bundle_data_info = &ctx.accounts.bundle_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+        let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+        let global_authority = &ctx.accounts.global_authority;
+        msg!(
+            "Bundle Id: {}, Seller: {:?}",
+            bundle_data_info.bundle_id,
+            bundle_data_info.seller
+        );
+
+        // Assert Seller User PDA Address
+        require!(
+            ctx.accounts.seller.key().eq(&seller_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // Assert Buyer User PDA Address
+        require!(
+            ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+            MarketplaceError::InvalidOwner
+        );
+        // At least one treasury should exist to trade NFT
         require!(
             global_authority.team_count > 0,
             MarketplaceError::NoTeamTreasuryYet
         );
+        // Assert Bundle Price is not changed after the buyer signed
+        require!(
+            bundle_data_info.price_sol == expected_price,
+            MarketplaceError::TradeTermsMismatch
+        );
 
-        let creators: &Vec<Creator>;
-        if let Some(cts) = &nft_metadata.creators {
-            creators = cts;
-        } else {
-            return Err(error!(MarketplaceError::MetadataCreatorParseError));
-        };
+        let team_count = global_authority.team_count as usize;
         require!(
-            global_authority.team_count + creators.len() as u64 == remaining_accounts.len() as u64,
+            team_count <= ctx.remaining_accounts.len(),
             MarketplaceError::TeamTreasuryCountMismatch
         );
+        let (team_accounts, mut item_accounts) = ctx.remaining_accounts.split_at(team_count);
+
+        let item_count = bundle_data_info.item_count;
+        let pricing_mode = resolve_pricing_mode(
+            bundle_data_info.pricing_mode,
+            global_authority.pricing_mode,
+            PRICING_MODE_FEE_ON_TOP,
+        );
+        let buyer = ctx.accounts.buyer.to_account_info();
+        let seller = ctx.accounts.seller.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
 
-        let total_share_fee =
-            offer_data_info.offer_price * (nft_metadata.seller_fee_basis_points as u64) / PERMYRIAD;
-        let fee_amount: u64 =
-            offer_data_info.offer_price * global_authority.market_fee_sol / PERMYRIAD;
-        let total_fee_amount: u64 = fee_amount + total_share_fee;
+        let mut fee_amount: u64 = 0;
+        let mut total_share_fee: u64 = 0;
+        let mut seller_proceeds: u64 = 0;
+        for k in 0..item_count as usize {
+            // Assert Bundle Item Accounts
+            require!(
+                BUNDLE_PURCHASE_ACCOUNTS <= item_accounts.len(),
+                MarketplaceError::BundleAccountsMismatch
+            );
+            let (item, rest) = item_accounts.split_at(BUNDLE_PURCHASE_ACCOUNTS);
+            let (mint, metadata, edition, token, token_record, dest_token, dest_token_record) = (
+                &item[0], &item[1], &item[2], &item[3], &item[4], &item[5], &item[6],
+            );
+            // Assert Bundle Item Mint
+            require!(
+                mint.key().eq(&bundle_data_info.mints[k]),
+                MarketplaceError::InvalidNFTDataAcount
+            );
+            let nft_metadata = load_bundle_item(mint, metadata, token, &seller.key())?;
 
-        invoke_signed(
-            &system_instruction::transfer(
-                ctx.accounts.escrow_vault.key,
-                ctx.accounts.seller.key,
-                offer_data_info.offer_price - total_fee_amount,
-            ),
-            &[
-                ctx.accounts.seller.to_account_info().clone(),
-                ctx.accounts.escrow_vault.to_account_info().clone(),
-                ctx.accounts.system_program.to_account_info().clone(),
-            ],
-            signer,
-        )?;
+            let creators: &Vec<Creator>;
+            if let Some(cts) = &nft_metadata.creators {
+                creators = cts;
+            } else {
+                return Err(error!(MarketplaceError::MetadataCreatorParseError));
+            };
+            require!(
+                creators.len() <= rest.len(),
+                MarketplaceError::BundleAccountsMismatch
+            );
+            let (creator_accounts, rest) = rest.split_at(creators.len());
+            item_accounts = rest;
 
-        let mut i = 0;
-        // This is not expensive cuz the max count is 8
-        for team_account in remaining_accounts {
-            if i < global_authority.team_count {
-                // Assert Provided Remaining Account is Treasury
+            // Division dust of the bundle price goes to the first item
+            let mut item_price = bundle_data_info.price_sol / item_count;
+            if k == 0 {
+                item_price += bundle_data_info.price_sol % item_count;
+            }
+            let quote = get_sale_quote(
+                item_price,
+                global_authority.market_fee_sol,
+                nft_metadata.seller_fee_basis_points,
+                pricing_mode,
+            )?;
+            fee_amount += quote.market_fee;
+            total_share_fee += quote.royalty;
+            seller_proceeds += quote.seller_proceeds;
+
+            let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+            let royalty_shares = get_royalty_shares(&creator_shares, quote.royalty);
+            for (j, creator_account) in creator_accounts.iter().enumerate() {
+                // Assert Remaining Account is the Creator in the Metadata order
                 require!(
-                    team_account
-                        .key()
-                        .eq(&global_authority.team_treasury[i as usize]),
-                    MarketplaceError::TeamTreasuryAddressMismatch
+                    creator_account.key().eq(&creators[j].address),
+                    MarketplaceError::CreatorAddressMismatch
                 );
-                invoke_signed(
-                    &system_instruction::transfer(
-                        ctx.accounts.escrow_vault.key,
-                        &global_authority.team_treasury[i as usize],
-                        fee_amount * global_authority.treasury_rate[i as usize] / PERMYRIAD,
-                    ),
-                    &[
-                        ctx.accounts.escrow_vault.to_account_info().clone(),
-                        team_account.clone(),
-                        ctx.accounts.system_program.to_account_info().clone(),
-                    ],
-                    signer,
+                transfer_sol(
+                    &buyer,
+                    creator_account,
+                    &system_program,
+                    royalty_shares[j],
+                    &[],
                 )?;
-            } else {
-                for creator in creators {
-                    if creator.address == team_account.key() && creator.share != 0 {
-                        let share_amount: u64 = total_share_fee * (creator.share as u64) / 100;
-                        invoke_signed(
-                            &system_instruction::transfer(
-                                ctx.accounts.escrow_vault.key,
-                                &team_account.key(),
-                                share_amount,
-                            ),
-                            &[
-                                ctx.accounts.escrow_vault.to_account_info().clone(),
-                                team_account.clone(),
-                                ctx.accounts.system_program.to_account_info().clone(),
-                            ],
-                            signer,
-                        )?;
-                    }
-                }
             }
-            i += 1;
+
+            let nft = NftContext {
+                standard: NftStandard::Programmable,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: system_program.clone(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                mint: mint.clone(),
+                metadata: metadata.clone(),
+                edition: edition.clone(),
+                token: token.clone(),
+                token_owner: seller.clone(),
+                token_record: token_record.clone(),
+                delegate: global_authority.to_account_info(),
+                payer: buyer.clone(),
+            };
+            nft.transfer(
+                dest_token,
+                &buyer,
+                dest_token_record,
+                &ctx.accounts.associated_token_program.to_account_info(),
+                signer,
+            )?;
         }
-        let dest_nft_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        // Assert Bundle Item Accounts & Royalty Terms
+        require!(
+            item_accounts.is_empty(),
+            MarketplaceError::BundleAccountsMismatch
+        );
+        require!(
+            total_share_fee == expected_royalty,
+            MarketplaceError::TradeTermsMismatch
+        );
+
+        transfer_sol(&buyer, &seller, &system_program, seller_proceeds, &[])?;
+
+        let treasury_shares = get_treasury_shares(global_authority, fee_amount);
+        for (i, team_account) in team_accounts.iter().enumerate() {
+            require!(
+                team_account.key().eq(&global_authority.team_treasury[i]),
+                MarketplaceError::TeamTreasuryAddressMismatch
+            );
+            transfer_sol(
+                &buyer,
+                team_account,
+                &system_program,
+                treasury_shares[i],
+                &[],
+            )?;
+        }
+        buyer_user_pool.traded_volume += bundle_data_info.price_sol;
+        seller_user_pool.traded_volume += bundle_data_info.price_sol;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn accept_offer_pnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOfferPNft<'info>>,
+        global_bump: u8,
+        _nft_bump: u8,
+        _offer_bump: u8,
+        _buyer_bump: u8,
+        _seller_bump: u8,
+        escrow_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+    ) -> Result<()> {
+        // Assert Seller Signature
+        require!(
+            ctx.accounts.seller.is_signer,
+            MarketplaceError::SellerMismatch
+        );
+        settle_offer_pnft(
+            ctx,
+            global_bump,
+            escrow_bump,
+            expected_price,
+            expected_royalty,
+        )
+    }
+
+    // NFT holder rejects the offer, the buyer is refunded
+    pub fn reject_offer(ctx: Context<RejectOffer>, escrow_bump: u8) -> Result<()> {
+        let offer_data_info = &ctx.accounts.offer_data_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Mint: {:?}, Seller: {:?}, buyer: {:?}",
+            offer_data_info.mint,
+            ctx.accounts.seller.key(),
+            offer_data_info.buyer
+        );
+
+        require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
+        require!(
+            offer_data_info.offer_price <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.locked_sol_balance -= offer_data_info.offer_price;
+        ctx.accounts.global_authority.escrow_liabilities -= offer_data_info.offer_price;
+
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
         let signer = &[&seeds[..]];
-        let seller = &ctx.accounts.seller;
+        transfer_sol(
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            offer_data_info.offer_price,
+            signer,
+        )?;
+
+        Ok(())
+    }
+
+    // Seller proposes a higher price on the listing offer, accepted by the buyer with accept_counter_offer
+    pub fn counter_offer(ctx: Context<CounterOffer>, counter_price: u64) -> Result<()> {
+        let sell_data_info = &ctx.accounts.sell_data_info;
+        let offer_data_info = &mut ctx.accounts.offer_data_info;
+        msg!(
+            "Mint: {:?}, buyer: {:?}, Counter Price: {}",
+            offer_data_info.mint,
+            offer_data_info.buyer,
+            counter_price
+        );
 
-        UnlockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .authority(&global_authority.to_account_info())
-            .payer(&seller.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(Some(&seller.to_account_info()))
-            .token(&dest_nft_token_account_info.to_account_info())
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .spl_token_program(Some(&token_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
-        TransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .authority(&global_authority.to_account_info())
-            .payer(&seller.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .destination_token(&token_account_info.to_account_info())
-            .destination_owner(&buyer.to_account_info())
-            .destination_token_record(Some(&token_mint_record.to_account_info()))
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(&seller.to_account_info())
-            .token(&dest_nft_token_account_info.to_account_info())
-            .amount(1)
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .spl_ata_program(&associated_token_program.to_account_info())
-            .spl_token_program(&token_program.to_account_info())
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
+        // Assert NFT seller is payer
+        require!(
+            ctx.accounts.seller.key().eq(&sell_data_info.seller),
+            MarketplaceError::SellerMismatch
+        );
+        require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
+        require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
+        // Assert Offer provided date with the NFT Listed Date
+        require!(
+            offer_data_info.offer_listing_date == sell_data_info.listed_date,
+            MarketplaceError::OfferForExpiredListingNFT
+        );
+        // Counter price range is above the offer up to the listing price
+        require!(
+            counter_price > offer_data_info.offer_price
+                && counter_price <= sell_data_info.get_current_price(Clock::get()?.unix_timestamp),
+            MarketplaceError::InvalidOfferPrice
+        );
 
+        offer_data_info.counter_price = counter_price;
         Ok(())
     }
 
+    // Buyer accepts the counter offer, the price difference is escrowed and the offer is settled
+    // Remaining accounts are the team treasuries and then the creators
+    pub fn accept_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptOfferPNft<'info>>,
+        global_bump: u8,
+        escrow_bump: u8,
+        expected_price: u64,
+        expected_royalty: u64,
+    ) -> Result<()> {
+        let offer_data_info = &mut ctx.accounts.offer_data_info;
+        let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+
+        // Assert Buyer Signature
+        require!(
+            ctx.accounts.buyer.is_signer,
+            MarketplaceError::InvalidOfferDataBuyer
+        );
+        // Assert Counter Offer terms are not changed after the buyer signed
+        require!(
+            offer_data_info.counter_price != 0 && offer_data_info.counter_price == expected_price,
+            MarketplaceError::TradeTermsMismatch
+        );
+
+        let top_up = offer_data_info.counter_price - offer_data_info.offer_price;
+        transfer_sol(
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            top_up,
+            &[],
+        )?;
+        buyer_user_pool.locked_sol_balance += top_up;
+        ctx.accounts.global_authority.escrow_liabilities += top_up;
+        offer_data_info.offer_price = offer_data_info.counter_price;
+        offer_data_info.counter_price = 0;
+
+        settle_offer_pnft(
+            ctx,
+            global_bump,
+            escrow_bump,
+            expected_price,
+            expected_royalty,
+        )
+    }
+
+    // Permissionless settlement of the ended auction, the NFT goes to the winner & the proceeds to the creator
+    // Remaining accounts are the team treasuries and then the creators
     pub fn claim_auction_pnft<'info>(
         ctx: Context<'_, '_, '_, 'info, ClaimAuctionPNft<'info>>,
         global_bump: u8,
@@ -1278,8 +2894,10 @@ pub mod mugs_marketplace {
         );
 
         // verify metadata is legit
-        let nft_metadata =
-            Metadata::safe_deserialize(&mut mint_metadata.data.borrow_mut()).unwrap();
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
 
         let timestamp = Clock::get()?.unix_timestamp;
         msg!("Claim Date: {}", timestamp);
@@ -1289,10 +2907,10 @@ pub mod mugs_marketplace {
             MarketplaceError::InvalidNFTDataAcount
         );
         // Assert Auction End Date is Passed
-        // require!(
-        //     auction_data_info.get_end_date() <= timestamp,
-        //     MarketplaceError::NotEndedAuction
-        // );
+        require!(
+            auction_data_info.get_end_date() <= timestamp,
+            MarketplaceError::NotEndedAuction
+        );
         // Assert Already Ended or Not Started Auction
         require!(
             auction_data_info.status == 1,
@@ -1321,6 +2939,96 @@ pub mod mugs_marketplace {
             ctx.accounts.creator.key().eq(&creator_user_pool.address),
             MarketplaceError::CreatorAccountMismatch
         );
+        // Assert Reveal Period is Passed for the Hidden Reserve
+        require!(
+            !auction_data_info.has_hidden_reserve()
+                || auction_data_info.get_end_date() + RESERVE_REVEAL_PERIOD <= timestamp,
+            MarketplaceError::ReserveNotRevealed
+        );
+
+        // Refund the outbid bid kept for the bid retraction
+        if !Pubkey::default().eq(&auction_data_info.prev_bidder) {
+            let (prev_bidder, prev_bidder_user_pool) = match (
+                &ctx.accounts.prev_bidder,
+                &mut ctx.accounts.prev_bidder_user_pool,
+            ) {
+                (Some(prev_bidder), Some(prev_bidder_user_pool)) => {
+                    (prev_bidder, prev_bidder_user_pool)
+                }
+                _ => return Err(error!(MarketplaceError::InvalidParamInput)),
+            };
+            let prev_locked = auction_data_info.get_locked_amount(auction_data_info.prev_bid)?;
+            prev_bidder_user_pool.locked_sol_balance -= prev_locked;
+            ctx.accounts.global_authority.escrow_liabilities -= prev_locked;
+
+            let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+            let signer = &[&seeds[..]];
+            transfer_sol(
+                &ctx.accounts.escrow_vault.to_account_info(),
+                &prev_bidder.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                prev_locked,
+                signer,
+            )?;
+            auction_data_info.prev_bidder = Pubkey::default();
+            auction_data_info.prev_bid = 0;
+        }
+
+        // Reserve price not met, or the Token-2022 creator moved the NFT or revoked the delegate
+        // The winning bid is refunded & the NFT is unlocked back to the creator
+        let nft_locked = is_nft_locked(
+            &ctx.accounts.dest_nft_token_account,
+            &ctx.accounts.global_authority.key(),
+        );
+        if !auction_data_info.is_reserve_met() || !nft_locked {
+            msg!(
+                "Reserve Not Met: {} < {}",
+                auction_data_info.highest_bid,
+                auction_data_info.reserve_price
+            );
+            auction_data_info.status = 0;
+            let locked = auction_data_info.get_locked_amount(auction_data_info.highest_bid)?;
+            bidder_user_pool.locked_sol_balance -= locked;
+            ctx.accounts.global_authority.escrow_liabilities -= locked;
+
+            let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+            let signer = &[&seeds[..]];
+            transfer_sol(
+                &ctx.accounts.escrow_vault.to_account_info(),
+                &ctx.accounts.bidder.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                locked,
+                signer,
+            )?;
+
+            let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+            let signer = &[&seeds[..]];
+            let global_authority = ctx.accounts.global_authority.to_account_info();
+            let nft = NftContext {
+                standard: nft_standard,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+                auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+                auth_rules: ctx.accounts.auth_rules.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                metadata: mint_metadata.to_account_info(),
+                edition: ctx.accounts.token_mint_edition.to_account_info(),
+                token: ctx.accounts.dest_nft_token_account.to_account_info(),
+                token_owner: ctx.accounts.creator.to_account_info(),
+                token_record: ctx.accounts.dest_token_mint_record.to_account_info(),
+                delegate: global_authority.clone(),
+                payer: ctx.accounts.payer.to_account_info(),
+            };
+            // Delegate clears itself as the creator is not signing
+            nft.unlock_and_revoke_by_delegate(
+                &ctx.accounts.global_nft_token_account.to_account_info(),
+                &ctx.accounts.associated_token_program.to_account_info(),
+                signer,
+            )?;
+            return Ok(());
+        }
 
         // Set Flag as Claimed Auction
         auction_data_info.status = 2;
@@ -1349,18 +3057,32 @@ pub mod mugs_marketplace {
             global_authority.team_count + creators.len() as u64 == remaining_accounts.len() as u64,
             MarketplaceError::TeamTreasuryCountMismatch
         );
-        let total_share_fee = auction_data_info.highest_bid
-            * (nft_metadata.seller_fee_basis_points as u64)
-            / PERMYRIAD;
-        let fee_amount: u64 =
-            auction_data_info.highest_bid * global_authority.market_fee_sol / PERMYRIAD;
-        let total_fee_amount: u64 = total_share_fee + fee_amount;
+        // Auctions created before the fee rates were fixed settle fee inclusive at the current rates
+        let quote = if auction_data_info.pricing_mode == PRICING_MODE_DEFAULT {
+            get_sale_quote(
+                auction_data_info.highest_bid,
+                global_authority.market_fee_sol,
+                nft_metadata.seller_fee_basis_points,
+                PRICING_MODE_FEE_INCLUSIVE,
+            )?
+        } else {
+            auction_data_info.get_quote(auction_data_info.highest_bid)?
+        };
+        let total_share_fee = quote.royalty;
+        let fee_amount: u64 = quote.market_fee;
+        // Winning bid is locked with the fees on top of it
+        require!(
+            quote.buyer_total <= bidder_user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        bidder_user_pool.locked_sol_balance -= quote.buyer_total;
+        global_authority.escrow_liabilities -= quote.buyer_total;
         msg!("ix1");
         invoke_signed(
             &system_instruction::transfer(
                 ctx.accounts.escrow_vault.key,
                 ctx.accounts.creator.key,
-                auction_data_info.highest_bid - total_fee_amount,
+                quote.seller_proceeds,
             ),
             &[
                 ctx.accounts.escrow_vault.to_account_info().clone(),
@@ -1370,24 +3092,24 @@ pub mod mugs_marketplace {
             signer,
         )?;
 
-        let mut i = 0;
+        let treasury_shares = get_treasury_shares(global_authority, fee_amount);
+        let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+        let royalty_shares = get_royalty_shares(&creator_shares, total_share_fee);
         // This is not expensive cuz the max count is 8
-        for team_account in remaining_accounts {
+        for (i, team_account) in remaining_accounts.iter().enumerate() {
             msg!("ix:{}", i);
-            if i < global_authority.team_count {
+            if (i as u64) < global_authority.team_count {
                 // Assert Provided Remaining Account is Treasury
                 require!(
-                    team_account
-                        .key()
-                        .eq(&global_authority.team_treasury[i as usize]),
+                    team_account.key().eq(&global_authority.team_treasury[i]),
                     MarketplaceError::TeamTreasuryAddressMismatch
                 );
                 msg!("ix2.1exec");
                 invoke_signed(
                     &system_instruction::transfer(
                         ctx.accounts.escrow_vault.key,
-                        &global_authority.team_treasury[i as usize],
-                        fee_amount * global_authority.treasury_rate[i as usize] / PERMYRIAD,
+                        &global_authority.team_treasury[i],
+                        treasury_shares[i],
                     ),
                     &[
                         ctx.accounts.escrow_vault.to_account_info().clone(),
@@ -1397,29 +3119,29 @@ pub mod mugs_marketplace {
                     signer,
                 )?;
             } else {
-                for creator in creators {
-                    if creator.address == team_account.key() && creator.share != 0 {
-                        let share_amount: u64 = total_share_fee * (creator.share as u64) / 100;
-                        msg!("ix2.2exec");
-                        invoke_signed(
-                            &system_instruction::transfer(
-                                ctx.accounts.escrow_vault.key,
-                                &team_account.key(),
-                                share_amount,
-                            ),
-                            &[
-                                ctx.accounts.escrow_vault.to_account_info().clone(),
-                                team_account.clone(),
-                                ctx.accounts.system_program.to_account_info().clone(),
-                            ],
-                            signer,
-                        )?;
-                    }
-                }
-            }
-            i += 1;
-        }
-        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+                let j = i - global_authority.team_count as usize;
+                // Assert Remaining Account is the Creator in the Metadata order
+                require!(
+                    team_account.key().eq(&creators[j].address),
+                    MarketplaceError::CreatorAddressMismatch
+                );
+                msg!("ix2.2exec");
+                invoke_signed(
+                    &system_instruction::transfer(
+                        ctx.accounts.escrow_vault.key,
+                        &team_account.key(),
+                        royalty_shares[j],
+                    ),
+                    &[
+                        ctx.accounts.escrow_vault.to_account_info().clone(),
+                        team_account.clone(),
+                        ctx.accounts.system_program.to_account_info().clone(),
+                    ],
+                    signer,
+                )?;
+            }
+        }
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
         let signer = &[&seeds[..]];
         let global_authority = &ctx.accounts.global_authority;
         let owner = &ctx.accounts.bidder;
@@ -1436,47 +3158,36 @@ pub mod mugs_marketplace {
         let auth_rules = &ctx.accounts.auth_rules;
         let creator = &ctx.accounts.creator;
         msg!("ix3");
-        UnlockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .authority(&global_authority.to_account_info())
-            .payer(&owner.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(Some(&creator.to_account_info()))
-            .token(&dest_token_account_info.to_account_info())
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .spl_token_program(Some(&token_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
-        msg!("ix4");
-        TransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-            .authority(&global_authority.to_account_info())
-            .payer(&owner.to_account_info())
-            .mint(&nft_mint.to_account_info())
-            .metadata(&mint_metadata.to_account_info())
-            .edition(Some(&token_mint_edition.to_account_info()))
-            .destination_token(&token_account_info.to_account_info())
-            .destination_owner(&owner.to_account_info())
-            .destination_token_record(Some(&token_mint_record.to_account_info()))
-            .token_record(Some(&dest_token_mint_record.to_account_info()))
-            .token_owner(&creator.to_account_info())
-            .token(&dest_token_account_info.to_account_info())
-            .amount(1)
-            .authorization_rules(Some(&auth_rules.to_account_info()))
-            .sysvar_instructions(&sysvar_instructions.to_account_info())
-            .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-            .spl_ata_program(&associated_token_program.to_account_info())
-            .spl_token_program(&token_program.to_account_info())
-            .system_program(&system_program.to_account_info())
-            .invoke_signed(signer)?;
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+            sysvar_instructions: sysvar_instructions.to_account_info(),
+            auth_rules_program: auth_rules_program.to_account_info(),
+            auth_rules: auth_rules.to_account_info(),
+            mint: nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: token_mint_edition.to_account_info(),
+            token: dest_token_account_info.to_account_info(),
+            token_owner: creator.to_account_info(),
+            token_record: dest_token_mint_record.to_account_info(),
+            delegate: global_authority.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+        };
+        nft.transfer(
+            &token_account_info.to_account_info(),
+            &owner.to_account_info(),
+            &token_mint_record.to_account_info(),
+            &associated_token_program.to_account_info(),
+            signer,
+        )?;
 
         Ok(())
     }
 
+    // Closes the ended auction without bids, the creator can also cancel the reserved auction before the first bid
+    // Anyone can crank the started auctions ended without bids, the NFT is unlocked back to the creator
     pub fn cancel_auction_pnft(
         ctx: Context<CancelAuctionPNft>,
         global_bump: u8,
@@ -1515,6 +3226,11 @@ pub mod mugs_marketplace {
             ctx.accounts.creator.key().eq(&auction_data_info.creator),
             MarketplaceError::CreatorAccountMismatch
         );
+        // Reserved auction never ends before the first bid, only the creator can cancel it
+        require!(
+            ctx.accounts.creator.is_signer || auction_data_info.status == 1,
+            MarketplaceError::NotEndedAuction
+        );
 
         // Assert NFT Pubkey with Sell Data PDA Mint
         require!(
@@ -1546,6 +3262,12 @@ pub mod mugs_marketplace {
             MarketplaceError::InvaliedMetadata
         );
 
+        // verify metadata is legit
+        let (_, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
         let global_authority = &ctx.accounts.global_authority;
         let owner = &ctx.accounts.creator;
         let token_account_info = &ctx.accounts.user_token_account;
@@ -1581,91 +3303,99 @@ pub mod mugs_marketplace {
             if auth_rules.owner != ctx.accounts.auth_rules_program.key {
                 msg!("Auth Rules O");
             }
-            UnlockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token(&token_account_info.to_account_info())
-                .token_owner(Some(&owner.to_account_info()))
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .invoke_signed(signer)?;
-            RevokeLockedTransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .master_edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&owner.to_account_info())
-                .delegate(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .token(&token_account_info.to_account_info())
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .invoke_signed(signer)?;
+            let nft = NftContext {
+                standard: nft_standard,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+                sysvar_instructions: sysvar_instructions.to_account_info(),
+                auth_rules_program: auth_rules_program.to_account_info(),
+                auth_rules: auth_rules.to_account_info(),
+                mint: nft_mint.to_account_info(),
+                metadata: mint_metadata.to_account_info(),
+                edition: token_mint_edition.to_account_info(),
+                token: token_account_info.to_account_info(),
+                token_owner: owner.to_account_info(),
+                token_record: token_mint_record.to_account_info(),
+                delegate: global_authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+            };
+            // Delegate clears itself when the creator is not signing
+            if owner.is_signer {
+                nft.unlock_and_revoke(&owner.to_account_info(), signer)?;
+            } else {
+                nft.unlock_and_revoke_by_delegate(
+                    &ctx.accounts.global_nft_token_account.to_account_info(),
+                    &ctx.accounts.associated_token_program.to_account_info(),
+                    signer,
+                )?;
+            }
         }
 
         Ok(())
     }
 
-    // pub fn cancel_bid(
-    //     ctx: Context<CancelBid>,
-    //     _auction_bump: u8,
-    //     _escrow_bump: u8,
-    //     _user_bump: u8,
-    // ) -> Result<()> {
-    //     let auction_data_info = &mut ctx.accounts.auction_data_info;
-    //     msg!(
-    //         "Mint: {:?}, buyer: {:?}",
-    //         auction_data_info.mint,
-    //         ctx.accounts.owner.key()
-    //     );
-
-    //     // Assert NFT Pubkey with Offer Data PDA Mint
-    //     require!(
-    //         ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
-    //         MarketplaceError::InvalidOfferDataMint
-    //     );
-    //     // Asser Payer is the Offer Data Address
-    //     require!(
-    //         ctx.accounts.owner.key().eq(&auction_data_info.creator),
-    //         MarketplaceError::InvalidOfferDataBuyer
-    //     );
-    //     require!(
-    //         auction_data_info.status == 1,
-    //         MarketplaceError::DisabledOffer
-    //     );
-
-    //     let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[_escrow_bump]];
-    //     let signer = &[&seeds[..]];
-
-    //     invoke_signed(
-    //         &system_instruction::transfer(
-    //             ctx.accounts.escrow_vault.key,
-    //             ctx.accounts.owner.key,
-    //             auction_data_info.price,
-    //         ),
-    //         &[
-    //             ctx.accounts.owner.to_account_info().clone(),
-    //             ctx.accounts.escrow_vault.to_account_info().clone(),
-    //             ctx.accounts.system_program.to_account_info().clone(),
-    //         ],
-    //         signer,
-    //     )?;
-
-    //     auction_data_info.status = 0;
-    //     Ok(())
-    // }
+    // Retract the last bid shortly after bidding, the auction reverts to the previous highest bid
+    pub fn cancel_bid(ctx: Context<CancelBid>, escrow_bump: u8) -> Result<()> {
+        let auction_data_info = &mut ctx.accounts.auction_data_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Mint: {:?}, Bidder: {:?}",
+            auction_data_info.mint,
+            ctx.accounts.bidder.key()
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Cancel Date: {}", timestamp);
+        // Assert Started Auction
+        require!(
+            auction_data_info.status == 1,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert Bidder Pubkey with Auction Data Last Bidder Address
+        require!(
+            ctx.accounts.bidder.key().eq(&auction_data_info.last_bidder),
+            MarketplaceError::BidderAccountMismatch
+        );
+        // Assert Retraction Window after the Bid and before the Final Period
+        require!(
+            timestamp <= auction_data_info.last_bid_date + BID_RETRACTION_WINDOW
+                && timestamp + BID_RETRACTION_CUTOFF < auction_data_info.get_end_date(),
+            MarketplaceError::BidRetractionClosed
+        );
+
+        let bid = auction_data_info.get_locked_amount(auction_data_info.highest_bid)?;
+        require!(
+            bid <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.locked_sol_balance -= bid;
+        ctx.accounts.global_authority.escrow_liabilities -= bid;
+
+        // Restored bid is not retractable
+        auction_data_info.last_bidder = auction_data_info.prev_bidder;
+        auction_data_info.highest_bid = auction_data_info.prev_bid;
+        auction_data_info.last_bid_date = 0;
+        auction_data_info.prev_bidder = Pubkey::default();
+        auction_data_info.prev_bid = 0;
+
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        transfer_sol(
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            bid,
+            signer,
+        )?;
 
+        Ok(())
+    }
+
+    // Bids placed within the extension window before the end push the end date to the bid time plus the extension length
+    // Zero extension window disables the anti-sniping extension
+    // Reserve price is public, or hidden as the commitment hash of the price & salt revealed with reveal_reserve
+    #[allow(clippy::too_many_arguments)]
     pub fn create_auction_pnft(
         ctx: Context<CreateAuctionPNft>,
         _global_bump: u8,
@@ -1675,8 +3405,29 @@ pub mod mugs_marketplace {
         min_increase: u64,
         duration: i64,
         reserved: u8,
+        pricing_mode: u64,
+        extension_window: i64,
+        extension_length: i64,
+        reserve_price: u64,
+        reserve_hash: Option<[u8; 32]>,
     ) -> Result<()> {
         require!(reserved < 2, MarketplaceError::InvalidParamInput);
+        require!(
+            pricing_mode <= PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
+        );
+        // Assert Valid Extension Config
+        require!(
+            extension_window >= 0
+                && extension_length >= 0
+                && (extension_window == 0 || extension_length > 0),
+            MarketplaceError::InvalidParamInput
+        );
+        // Assert Hidden Reserve is not provided with the public reserve price
+        require!(
+            reserve_hash.is_none() || reserve_price == 0,
+            MarketplaceError::InvalidParamInput
+        );
 
         let auction_data_info = &mut ctx.accounts.auction_data_info;
         msg!("Mint: {:?}, Reserved: {}", auction_data_info.mint, reserved);
@@ -1717,22 +3468,19 @@ pub mod mugs_marketplace {
         );
 
         // verify metadata is legit
-        let nft_metadata =
-            Metadata::safe_deserialize(&mut mint_metadata.data.borrow_mut()).unwrap();
-
-        if let Some(creators) = nft_metadata.creators {
-            let mut collection: Pubkey = Pubkey::default();
-            for creator in creators {
-                if creator.verified {
-                    collection = creator.address;
-                    break;
-                }
-            }
-            sell_data_info.collection = collection;
-            msg!("Collection= {:?}", collection);
-        } else {
-            return Err(error!(MarketplaceError::MetadataCreatorParseError));
-        }
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        let (collection, collection_source) = get_collection(&nft_metadata)?;
+        sell_data_info.collection = collection;
+        sell_data_info.collection_source = collection_source;
+        msg!(
+            "Collection= {:?}, Source: {}",
+            collection,
+            collection_source
+        );
 
         let timestamp = Clock::get()?.unix_timestamp;
         msg!("Created Date: {}", timestamp);
@@ -1741,6 +3489,21 @@ pub mod mugs_marketplace {
         auction_data_info.start_price = start_price;
         auction_data_info.min_increase_amount = min_increase;
         auction_data_info.duration = duration;
+        // Pricing mode & fee rates are fixed for the bidders
+        auction_data_info.pricing_mode = resolve_pricing_mode(
+            pricing_mode,
+            ctx.accounts.global_authority.pricing_mode,
+            PRICING_MODE_FEE_INCLUSIVE,
+        );
+        auction_data_info.market_fee_sol = ctx.accounts.global_authority.market_fee_sol;
+        auction_data_info.royalty_basis_points = nft_metadata.seller_fee_basis_points as u64;
+        auction_data_info.extension_window = extension_window;
+        auction_data_info.extension_length = extension_length;
+        auction_data_info.end_date = 0;
+        auction_data_info.prev_bidder = Pubkey::default();
+        auction_data_info.prev_bid = 0;
+        auction_data_info.reserve_price = reserve_price;
+        auction_data_info.reserve_hash = reserve_hash.unwrap_or_default();
         auction_data_info.last_bidder = Pubkey::default();
         auction_data_info.highest_bid = start_price - auction_data_info.min_increase_amount;
         auction_data_info.status = 3;
@@ -1748,6 +3511,7 @@ pub mod mugs_marketplace {
         if reserved == 0 {
             auction_data_info.status = 1;
             auction_data_info.start_date = timestamp;
+            auction_data_info.end_date = auction_data_info.get_end_date();
         }
 
         let token_account_info = &ctx.accounts.user_token_account;
@@ -1788,41 +3552,24 @@ pub mod mugs_marketplace {
             if auth_rules.owner != ctx.accounts.auth_rules_program.key {
                 msg!("Auth Rules Owner Err: {:?}", auth_rules.owner);
             }
-            DelegateLockedTransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .amount(1)
-                .master_edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&owner.to_account_info())
-                .delegate(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .token(&token_account_info.to_account_info())
-                .amount(1)
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .locked_address(token_account_info.key())
-                .invoke_signed(signer)?;
-            LockV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-                .edition(Some(&token_mint_edition.to_account_info()))
-                .spl_token_program(Some(&token_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .authority(&global_authority.to_account_info())
-                .payer(&owner.to_account_info())
-                .mint(&nft_mint.to_account_info())
-                .metadata(&mint_metadata.to_account_info())
-                .token(&token_account_info.to_account_info())
-                .token_owner(Some(&owner.to_account_info()))
-                .token_record(Some(&token_mint_record.to_account_info()))
-                .authorization_rules(Some(&auth_rules.to_account_info()))
-                .sysvar_instructions(&sysvar_instructions.to_account_info())
-                .authorization_rules_program(Some(&auth_rules_program.to_account_info()))
-                .system_program(&system_program.to_account_info())
-                .invoke_signed(signer)?;
+            let nft = NftContext {
+                standard: nft_standard,
+                token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+                system_program: system_program.to_account_info(),
+                sysvar_instructions: sysvar_instructions.to_account_info(),
+                auth_rules_program: auth_rules_program.to_account_info(),
+                auth_rules: auth_rules.to_account_info(),
+                mint: nft_mint.to_account_info(),
+                metadata: mint_metadata.to_account_info(),
+                edition: token_mint_edition.to_account_info(),
+                token: token_account_info.to_account_info(),
+                token_owner: owner.to_account_info(),
+                token_record: token_mint_record.to_account_info(),
+                delegate: global_authority.to_account_info(),
+                payer: owner.to_account_info(),
+            };
+            nft.lock(signer)?;
             // TransferV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
             //     .authority(&owner.to_account_info())
             //     .payer(&owner.to_account_info())
@@ -1864,198 +3611,2214 @@ pub mod mugs_marketplace {
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
-        init,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
-        space = 8 + 368,
-        payer = admin
-    )]
-    pub global_authority: Account<'info, GlobalPool>,
-    #[account(
-        mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
-    )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct SetTreshold<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
-    )]
-    pub global_authority: Account<'info, GlobalPool>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct AddTreasury<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
-    )]
-    pub global_authority: Account<'info, GlobalPool>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct RemoveTreasury<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    #[account(
-        mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
-        bump,
-    )]
-    pub global_authority: Account<'info, GlobalPool>,
-}
+    // Sealed bid auction, bids are committed as the hash of the mint, bidder, amount & salt with a deposit covering the bid & fees
+    // Bids are revealed after the commit phase and the auction settles to the highest revealed bid
+    pub fn create_sealed_auction_pnft(
+        ctx: Context<CreateSealedAuctionPNft>,
+        global_bump: u8,
+        min_price: u64,
+        commit_duration: i64,
+        reveal_duration: i64,
+        price_rule: u64,
+        pricing_mode: u64,
+    ) -> Result<()> {
+        require!(
+            min_price > 0 && commit_duration > 0 && reveal_duration > 0,
+            MarketplaceError::InvalidParamInput
+        );
+        require!(
+            price_rule == SEALED_FIRST_PRICE || price_rule == SEALED_SECOND_PRICE,
+            MarketplaceError::InvalidParamInput
+        );
+        require!(
+            pricing_mode <= PRICING_MODE_FEE_INCLUSIVE,
+            MarketplaceError::InvalidParamInput
+        );
+        // Shouldn't listed to create sealed auction
+        require!(
+            ctx.accounts.sell_data_info.active == 0,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert NFT is in user Account
+        require!(
+            ctx.accounts.user_token_account.amount == 1,
+            MarketplaceError::NFTIsNotInUserATA
+        );
 
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct InitUserPool<'info> {
-    #[account(mut)]
-    pub owner: Signer<'info>,
-    #[account(
-        init,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
-        space = 8 + 48,
-        payer = owner,
-    )]
-    pub user_pool: Account<'info, UserData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct Deposit<'info> {
-    #[account(mut)]
-    pub owner: Signer<'info>,
+        let mint_metadata = &ctx.accounts.mint_metadata;
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
 
-    #[account(
-        mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
-    )]
-    pub user_pool: Account<'info, UserData>,
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Created Date: {}", timestamp);
 
-    #[account(
-        mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
-    )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
-}
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct Withdraw<'info> {
-    #[account(mut)]
-    pub owner: Signer<'info>,
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        sealed_auction_info.mint = ctx.accounts.nft_mint.key();
+        sealed_auction_info.creator = ctx.accounts.owner.key();
+        sealed_auction_info.min_price = min_price;
+        sealed_auction_info.commit_end_date = timestamp + commit_duration;
+        sealed_auction_info.reveal_end_date = timestamp + commit_duration + reveal_duration;
+        sealed_auction_info.price_rule = price_rule;
+        sealed_auction_info.highest_bidder = Pubkey::default();
+        // Pricing mode & fee rates are fixed for the bidders
+        sealed_auction_info.pricing_mode = resolve_pricing_mode(
+            pricing_mode,
+            ctx.accounts.global_authority.pricing_mode,
+            PRICING_MODE_FEE_INCLUSIVE,
+        );
+        sealed_auction_info.market_fee_sol = ctx.accounts.global_authority.market_fee_sol;
+        sealed_auction_info.royalty_basis_points = nft_metadata.seller_fee_basis_points as u64;
+        sealed_auction_info.status = 1;
 
-    #[account(
-        mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
-        bump,
-    )]
-    pub user_pool: Account<'info, UserData>,
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+        let owner = &ctx.accounts.owner;
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: ctx.accounts.token_mint_edition.to_account_info(),
+            token: ctx.accounts.user_token_account.to_account_info(),
+            token_owner: owner.to_account_info(),
+            token_record: ctx.accounts.token_mint_record.to_account_info(),
+            delegate: ctx.accounts.global_authority.to_account_info(),
+            payer: owner.to_account_info(),
+        };
+        nft.lock(signer)?;
 
-    #[account(
-        mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
-    )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
+        Ok(())
+    }
 
-    pub system_program: Program<'info, System>,
-}
+    // Commit the sealed bid hash, the deposit hides the bid amount and is locked in the escrow
+    pub fn commit_sealed_bid(
+        ctx: Context<CommitSealedBid>,
+        bid_hash: [u8; 32],
+        deposit: u64,
+    ) -> Result<()> {
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        let bidder_user_pool = &mut ctx.accounts.bidder_user_pool;
+        msg!(
+            "Mint: {:?}, Bidder: {:?}",
+            sealed_auction_info.mint,
+            ctx.accounts.bidder.key()
+        );
 
-#[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitSellData<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init,
-        seeds = [SELL_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
-        bump,
-        space = 8 + 120,
-        payer = payer,
-    )]
-    pub sell_data_info: Account<'info, SellData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Assert Started Auction in the Commit Phase
+        require!(
+            sealed_auction_info.status == 1,
+            MarketplaceError::NotListedNFT
+        );
+        require!(
+            timestamp < sealed_auction_info.commit_end_date,
+            MarketplaceError::InvalidSealedPhase
+        );
+        // Assert Bid from Auction Creator
+        require!(
+            !ctx.accounts.bidder.key().eq(&sealed_auction_info.creator),
+            MarketplaceError::BidFromAuctionCreator
+        );
+        // Deposit should cover the min price
+        require!(
+            deposit >= sealed_auction_info.min_price,
+            MarketplaceError::InvalidBidPrice
+        );
 
-#[derive(Accounts)]
+        transfer_sol(
+            &ctx.accounts.bidder.to_account_info(),
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            deposit,
+            &[],
+        )?;
+        bidder_user_pool.locked_sol_balance += deposit;
+        ctx.accounts.global_authority.escrow_liabilities += deposit;
+        sealed_auction_info.open_bids += 1;
+
+        let sealed_bid_info = &mut ctx.accounts.sealed_bid_info;
+        sealed_bid_info.mint = sealed_auction_info.mint;
+        sealed_bid_info.bidder = ctx.accounts.bidder.key();
+        sealed_bid_info.bid_hash = bid_hash;
+        sealed_bid_info.deposit = deposit;
+        sealed_bid_info.revealed_bid = 0;
+
+        Ok(())
+    }
+
+    // Reveal the committed bid amount & salt in the reveal phase
+    pub fn reveal_sealed_bid(
+        ctx: Context<RevealSealedBid>,
+        amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        let sealed_bid_info = &mut ctx.accounts.sealed_bid_info;
+        msg!(
+            "Mint: {:?}, Bidder: {:?}, Bid: {}",
+            sealed_auction_info.mint,
+            ctx.accounts.bidder.key(),
+            amount
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Assert Started Auction in the Reveal Phase
+        require!(
+            sealed_auction_info.status == 1,
+            MarketplaceError::NotListedNFT
+        );
+        require!(
+            sealed_auction_info.commit_end_date <= timestamp
+                && timestamp < sealed_auction_info.reveal_end_date,
+            MarketplaceError::InvalidSealedPhase
+        );
+        // Assert Not Revealed Bid
+        require!(
+            sealed_bid_info.revealed_bid == 0,
+            MarketplaceError::InvalidParamInput
+        );
+        require!(
+            get_sealed_bid_hash(
+                &sealed_auction_info.mint,
+                &ctx.accounts.bidder.key(),
+                amount,
+                &salt
+            ) == sealed_bid_info.bid_hash,
+            MarketplaceError::InvalidBidReveal
+        );
+        // Revealed bid with the fees on top should be covered by the deposit and above the min price
+        require!(
+            amount >= sealed_auction_info.min_price
+                && sealed_auction_info.get_quote(amount)?.buyer_total <= sealed_bid_info.deposit,
+            MarketplaceError::InvalidBidPrice
+        );
+
+        sealed_bid_info.revealed_bid = amount;
+        sealed_auction_info.record_bid(ctx.accounts.bidder.key(), amount);
+
+        Ok(())
+    }
+
+    // Permissionless settlement to the highest revealed bid, the NFT goes to the winner & the proceeds to the creator
+    // Remaining accounts are the team treasuries and then the creators
+    pub fn settle_sealed_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleSealedAuction<'info>>,
+        global_bump: u8,
+        escrow_bump: u8,
+    ) -> Result<()> {
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        let winner_bid_info = &ctx.accounts.winner_bid_info;
+        let winner_user_pool = &mut ctx.accounts.winner_user_pool;
+        let creator_user_pool = &mut ctx.accounts.creator_user_pool;
+        let global_authority = &mut ctx.accounts.global_authority;
+        msg!("Mint: {:?}", sealed_auction_info.mint);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Claim Date: {}", timestamp);
+        // Assert Reveal Phase is Ended
+        require!(
+            sealed_auction_info.reveal_end_date <= timestamp,
+            MarketplaceError::NotEndedAuction
+        );
+        // Assert Already Ended or Canceled Auction
+        require!(
+            sealed_auction_info.status == 1,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert Winner Pubkey with the Highest Revealed Bidder
+        require!(
+            !Pubkey::default().eq(&sealed_auction_info.highest_bidder)
+                && ctx
+                    .accounts
+                    .winner
+                    .key()
+                    .eq(&sealed_auction_info.highest_bidder),
+            MarketplaceError::BidderAccountMismatch
+        );
+        // Assert Winner User PDA Address
+        require!(
+            ctx.accounts.winner.key().eq(&winner_user_pool.address),
+            MarketplaceError::BidderAccountMismatch
+        );
+        // Assert Creator User PDA Address
+        require!(
+            ctx.accounts.creator.key().eq(&creator_user_pool.address),
+            MarketplaceError::CreatorAccountMismatch
+        );
+
+        let mint_metadata = &ctx.accounts.mint_metadata;
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (nft_metadata, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        // Token-2022 creator moved the NFT or revoked the delegate, the auction is canceled
+        // and the winner deposit is released with refund_sealed_bid
+        if !is_nft_locked(
+            &ctx.accounts.dest_nft_token_account,
+            &global_authority.key(),
+        ) {
+            sealed_auction_info.status = 0;
+            return Ok(());
+        }
+
+        let creators: &Vec<Creator>;
+        if let Some(cts) = &nft_metadata.creators {
+            creators = cts;
+        } else {
+            return Err(error!(MarketplaceError::MetadataCreatorParseError));
+        };
+
+        let quote = sealed_auction_info.get_quote(sealed_auction_info.get_clearing_price())?;
+        // Deposit covers the clearing price with the fees on top, the rest is refunded
+        let deposit = winner_bid_info.deposit;
+        require!(
+            deposit <= winner_user_pool.locked_sol_balance && quote.buyer_total <= deposit,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        winner_user_pool.locked_sol_balance -= deposit;
+        global_authority.escrow_liabilities -= deposit;
+        winner_user_pool.traded_volume += quote.price;
+        creator_user_pool.traded_volume += quote.price;
+        sealed_auction_info.open_bids -= 1;
+        sealed_auction_info.status = 2;
+        msg!("Clearing Price: {}", quote.price);
+
+        let escrow_vault = ctx.accounts.escrow_vault.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        settle_from_escrow(
+            &escrow_vault,
+            &ctx.accounts.creator.to_account_info(),
+            &system_program,
+            global_authority,
+            creators,
+            ctx.remaining_accounts,
+            &quote,
+            signer,
+        )?;
+        // Refund the deposit above the clearing price
+        transfer_sol(
+            &escrow_vault,
+            &ctx.accounts.winner.to_account_info(),
+            &system_program,
+            deposit - quote.buyer_total,
+            signer,
+        )?;
+
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program,
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: ctx.accounts.token_mint_edition.to_account_info(),
+            token: ctx.accounts.dest_nft_token_account.to_account_info(),
+            token_owner: ctx.accounts.creator.to_account_info(),
+            token_record: ctx.accounts.dest_token_mint_record.to_account_info(),
+            delegate: ctx.accounts.global_authority.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+        };
+        nft.transfer(
+            &ctx.accounts.user_token_account.to_account_info(),
+            &ctx.accounts.winner.to_account_info(),
+            &ctx.accounts.token_mint_record.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+            signer,
+        )?;
+
+        Ok(())
+    }
+
+    // Permissionless refund of the unrevealed & losing sealed bid deposits after the reveal phase
+    pub fn refund_sealed_bid(ctx: Context<RefundSealedBid>, escrow_bump: u8) -> Result<()> {
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        let sealed_bid_info = &ctx.accounts.sealed_bid_info;
+        let user_pool = &mut ctx.accounts.user_pool;
+        msg!(
+            "Mint: {:?}, Bidder: {:?}",
+            sealed_auction_info.mint,
+            sealed_bid_info.bidder
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        // Assert Reveal Phase is Ended
+        require!(
+            sealed_auction_info.reveal_end_date <= timestamp,
+            MarketplaceError::InvalidSealedPhase
+        );
+        // Assert Not the Winning Bid of the Unsettled Auction
+        require!(
+            sealed_auction_info.status != 1
+                || !sealed_bid_info
+                    .bidder
+                    .eq(&sealed_auction_info.highest_bidder),
+            MarketplaceError::WinningSealedBid
+        );
+
+        let deposit = sealed_bid_info.deposit;
+        require!(
+            deposit <= user_pool.locked_sol_balance,
+            MarketplaceError::InsufficientBuyerSolBalance
+        );
+        user_pool.locked_sol_balance -= deposit;
+        ctx.accounts.global_authority.escrow_liabilities -= deposit;
+        sealed_auction_info.open_bids -= 1;
+
+        let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+        let signer = &[&seeds[..]];
+        transfer_sol(
+            &ctx.accounts.escrow_vault.to_account_info(),
+            &ctx.accounts.bidder.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            deposit,
+            signer,
+        )?;
+
+        Ok(())
+    }
+
+    // Cancel the sealed auction ended without revealed bids, the NFT is unlocked back to the creator
+    // Anyone can crank it, the creator signature is not required
+    pub fn cancel_sealed_auction_pnft(
+        ctx: Context<CancelSealedAuctionPNft>,
+        global_bump: u8,
+    ) -> Result<()> {
+        let sealed_auction_info = &mut ctx.accounts.sealed_auction_info;
+        msg!("Mint: {:?}", sealed_auction_info.mint);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        msg!("Cancel Date: {}", timestamp);
+        // Assert Reveal Phase is Ended
+        require!(
+            sealed_auction_info.reveal_end_date <= timestamp,
+            MarketplaceError::NotEndedAuction
+        );
+        // Assert Already Ended or Canceled Auction
+        require!(
+            sealed_auction_info.status == 1,
+            MarketplaceError::NotListedNFT
+        );
+        // Assert Auction Has No Revealed Bid
+        require!(
+            Pubkey::default().eq(&sealed_auction_info.highest_bidder),
+            MarketplaceError::AuctionHasBid
+        );
+        sealed_auction_info.status = 0;
+
+        let mint_metadata = &ctx.accounts.mint_metadata;
+        let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+        require!(
+            metadata == mint_metadata.key(),
+            MarketplaceError::InvaliedMetadata
+        );
+        let (_, nft_standard) = load_metadata(
+            &mint_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.to_account_info(),
+        )?;
+
+        let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+        let signer = &[&seeds[..]];
+        let creator = &ctx.accounts.creator;
+        let global_authority = ctx.accounts.global_authority.to_account_info();
+        let nft = NftContext {
+            standard: nft_standard,
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            sysvar_instructions: ctx.accounts.sysvar_instructions.to_account_info(),
+            auth_rules_program: ctx.accounts.auth_rules_program.to_account_info(),
+            auth_rules: ctx.accounts.auth_rules.to_account_info(),
+            mint: ctx.accounts.nft_mint.to_account_info(),
+            metadata: mint_metadata.to_account_info(),
+            edition: ctx.accounts.token_mint_edition.to_account_info(),
+            token: ctx.accounts.user_token_account.to_account_info(),
+            token_owner: creator.to_account_info(),
+            token_record: ctx.accounts.token_mint_record.to_account_info(),
+            delegate: global_authority.clone(),
+            payer: ctx.accounts.payer.to_account_info(),
+        };
+        // Delegate clears itself when the creator is not signing
+        if creator.is_signer {
+            nft.unlock_and_revoke(&creator.to_account_info(), signer)?;
+        } else {
+            nft.unlock_and_revoke_by_delegate(
+                &ctx.accounts.global_nft_token_account.to_account_info(),
+                &ctx.accounts.associated_token_program.to_account_info(),
+                signer,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Close the settled or canceled sealed auction after all deposits are released, the rent goes to the creator
+    pub fn close_sealed_auction(ctx: Context<CloseSealedAuction>) -> Result<()> {
+        let sealed_auction_info = &ctx.accounts.sealed_auction_info;
+        msg!("Close Sealed Auction: {:?}", sealed_auction_info.mint);
+
+        // Assert Settled Or Canceled Auction
+        require!(
+            sealed_auction_info.status != 1,
+            MarketplaceError::NotEndedAuction
+        );
+        // Assert All Deposits are Released
+        require!(
+            sealed_auction_info.open_bids == 0,
+            MarketplaceError::AuctionHasBid
+        );
+
+        Ok(())
+    }
+}
+
+// Offer settlement shared by the seller accepted offers & the buyer accepted counter offers
+fn settle_offer_pnft<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptOfferPNft<'info>>,
+    global_bump: u8,
+    escrow_bump: u8,
+    expected_price: u64,
+    expected_royalty: u64,
+) -> Result<()> {
+    let sell_data_info = &mut ctx.accounts.sell_data_info;
+    let auction_data_info = &mut ctx.accounts.auction_data_info;
+    let buyer_user_pool = &mut ctx.accounts.buyer_user_pool;
+    let seller_user_pool = &mut ctx.accounts.seller_user_pool;
+
+    let buyer = &ctx.accounts.buyer;
+    let token_account_info = &ctx.accounts.user_nft_token_account;
+    let nft_mint = &ctx.accounts.nft_mint;
+
+    let token_mint_edition = &ctx.accounts.token_mint_edition;
+    let token_mint_record = &ctx.accounts.token_mint_record;
+    let dest_token_mint_record = &ctx.accounts.dest_token_mint_record;
+    let system_program = &ctx.accounts.system_program;
+    let sysvar_instructions = &ctx.accounts.sysvar_instructions;
+    let associated_token_program = &ctx.accounts.associated_token_program;
+    let auth_rules_program = &ctx.accounts.auth_rules_program;
+    let auth_rules = &ctx.accounts.auth_rules;
+
+    // Assert NFT Pubkey with Sell Data PDA Mint
+    require!(
+        ctx.accounts.nft_mint.key().eq(&sell_data_info.mint),
+        MarketplaceError::InvalidNFTDataAcount
+    );
+
+    // Assert NFT Pubkey with Auction Data PDA Mint
+    require!(
+        ctx.accounts.nft_mint.key().eq(&auction_data_info.mint),
+        MarketplaceError::InvalidNFTDataAcount
+    );
+    if auction_data_info.status == 3 {
+        // Assert Creator Pubkey is same with the Auction Data Creator
+        require!(
+            ctx.accounts.seller.key().eq(&auction_data_info.creator),
+            MarketplaceError::CreatorAccountMismatch
+        );
+    }
+
+    // Get Collection address from Metadata
+    let mint_metadata = &mut &ctx.accounts.mint_metadata;
+    msg!("Metadata Account: {:?}", ctx.accounts.mint_metadata.key());
+    let (metadata, _) = Metadata::find_pda(&ctx.accounts.nft_mint.key());
+    require!(
+        metadata == mint_metadata.key(),
+        MarketplaceError::InvaliedMetadata
+    );
+
+    // verify metadata is legit
+    let (nft_metadata, nft_standard) = load_metadata(
+        &mint_metadata.to_account_info(),
+        &ctx.accounts.nft_mint.to_account_info(),
+    )?;
+
+    // Assert Buyer User PDA Address
+    require!(
+        ctx.accounts.buyer.key().eq(&buyer_user_pool.address),
+        MarketplaceError::InvalidOwner
+    );
+    // Assert Seller User PDA Address
+    require!(
+        ctx.accounts.seller.key().eq(&seller_user_pool.address),
+        MarketplaceError::InvalidOwner
+    );
+
+    // Assert Already Delisted NFT
+    require!(sell_data_info.active == 1, MarketplaceError::NotListedNFT);
+    // Assert Listing Expiry
+    require!(
+        !sell_data_info.is_expired(Clock::get()?.unix_timestamp),
+        MarketplaceError::ExpiredListing
+    );
+    // Assert Seller Pubkey with Sell Data PDA Seller Address
+    require!(
+        ctx.accounts.seller.key().eq(&sell_data_info.seller),
+        MarketplaceError::SellerAccountMismatch
+    );
+
+    let offer_data_info = &mut ctx.accounts.offer_data_info;
+    // Assert NFT Pubkey with Offer Data PDA Mint
+    require!(
+        ctx.accounts.nft_mint.key().eq(&offer_data_info.mint),
+        MarketplaceError::InvalidOfferDataMint
+    );
+    // Assert Buyer Pubkey with Offer Data PDA Buyer Address
+    require!(
+        ctx.accounts.buyer.key().eq(&offer_data_info.buyer),
+        MarketplaceError::InvalidOfferDataBuyer
+    );
+    // Assert Already Disabled Offer
+    require!(offer_data_info.active == 1, MarketplaceError::DisabledOffer);
+    // Assert Offer provided date with the NFT Listed Date
+    require!(
+        offer_data_info.offer_listing_date == sell_data_info.listed_date,
+        MarketplaceError::OfferForExpiredListingNFT
+    );
+    // Assert Offer Expiry
+    require!(
+        !offer_data_info.is_expired(Clock::get()?.unix_timestamp),
+        MarketplaceError::ExpiredOffer
+    );
+
+    msg!(
+        "Offer Mint: {:?}, Seller: {:?}, Buyer: {:?}, Price: {}",
+        offer_data_info.mint,
+        sell_data_info.seller,
+        offer_data_info.buyer,
+        offer_data_info.offer_price,
+    );
+
+    offer_data_info.active = 0;
+    sell_data_info.active = 0;
+
+    if auction_data_info.status == 3 {
+        auction_data_info.status = 0;
+    }
+
+    buyer_user_pool.traded_volume += offer_data_info.offer_price;
+    seller_user_pool.traded_volume += offer_data_info.offer_price;
+
+    let token_program = &mut &ctx.accounts.token_program;
+    let seeds = &[ESCROW_VAULT_SEED.as_bytes(), &[escrow_bump]];
+    let signer = &[&seeds[..]];
+
+    let global_authority = &mut ctx.accounts.global_authority;
+    let remaining_accounts: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
+    require!(
+        global_authority.team_count > 0,
+        MarketplaceError::NoTeamTreasuryYet
+    );
+
+    let creators: &Vec<Creator>;
+    if let Some(cts) = &nft_metadata.creators {
+        creators = cts;
+    } else {
+        return Err(error!(MarketplaceError::MetadataCreatorParseError));
+    };
+    require!(
+        global_authority.team_count + creators.len() as u64 == remaining_accounts.len() as u64,
+        MarketplaceError::TeamTreasuryCountMismatch
+    );
+
+    let quote = get_sale_quote(
+        offer_data_info.offer_price,
+        global_authority.market_fee_sol,
+        nft_metadata.seller_fee_basis_points,
+        resolve_pricing_mode(
+            sell_data_info.pricing_mode,
+            global_authority.pricing_mode,
+            PRICING_MODE_FEE_INCLUSIVE,
+        ),
+    )?;
+    let total_share_fee = quote.royalty;
+    let fee_amount: u64 = quote.market_fee;
+    // Assert Offer terms are not changed after the seller signed
+    require!(
+        offer_data_info.offer_price == expected_price && total_share_fee == expected_royalty,
+        MarketplaceError::TradeTermsMismatch
+    );
+    // Offer price is locked, fees on top of it are paid from the buyer available balance
+    let fees_on_top = quote.buyer_total - quote.price;
+    require!(
+        quote.price <= buyer_user_pool.locked_sol_balance
+            && fees_on_top <= buyer_user_pool.escrow_sol_balance,
+        MarketplaceError::InsufficientBuyerSolBalance
+    );
+    buyer_user_pool.locked_sol_balance -= quote.price;
+    buyer_user_pool.escrow_sol_balance -= fees_on_top;
+    global_authority.escrow_liabilities -= quote.buyer_total;
+
+    invoke_signed(
+        &system_instruction::transfer(
+            ctx.accounts.escrow_vault.key,
+            ctx.accounts.seller.key,
+            quote.seller_proceeds,
+        ),
+        &[
+            ctx.accounts.seller.to_account_info().clone(),
+            ctx.accounts.escrow_vault.to_account_info().clone(),
+            ctx.accounts.system_program.to_account_info().clone(),
+        ],
+        signer,
+    )?;
+
+    let treasury_shares = get_treasury_shares(global_authority, fee_amount);
+    let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+    let royalty_shares = get_royalty_shares(&creator_shares, total_share_fee);
+    // This is not expensive cuz the max count is 8
+    for (i, team_account) in remaining_accounts.iter().enumerate() {
+        if (i as u64) < global_authority.team_count {
+            // Assert Provided Remaining Account is Treasury
+            require!(
+                team_account.key().eq(&global_authority.team_treasury[i]),
+                MarketplaceError::TeamTreasuryAddressMismatch
+            );
+            invoke_signed(
+                &system_instruction::transfer(
+                    ctx.accounts.escrow_vault.key,
+                    &global_authority.team_treasury[i],
+                    treasury_shares[i],
+                ),
+                &[
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    team_account.clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+                signer,
+            )?;
+        } else {
+            let j = i - global_authority.team_count as usize;
+            // Assert Remaining Account is the Creator in the Metadata order
+            require!(
+                team_account.key().eq(&creators[j].address),
+                MarketplaceError::CreatorAddressMismatch
+            );
+            invoke_signed(
+                &system_instruction::transfer(
+                    ctx.accounts.escrow_vault.key,
+                    &team_account.key(),
+                    royalty_shares[j],
+                ),
+                &[
+                    ctx.accounts.escrow_vault.to_account_info().clone(),
+                    team_account.clone(),
+                    ctx.accounts.system_program.to_account_info().clone(),
+                ],
+                signer,
+            )?;
+        }
+    }
+    let dest_nft_token_account_info = &mut &ctx.accounts.dest_nft_token_account;
+    let seeds = &[GLOBAL_AUTHORITY_SEED.as_bytes(), &[global_bump]];
+    let signer = &[&seeds[..]];
+    let seller = &ctx.accounts.seller;
+    // Counter offers are accepted by the buyer, who pays the transfer rent then
+    let payer = if seller.is_signer {
+        seller.to_account_info()
+    } else {
+        buyer.to_account_info()
+    };
+
+    let nft = NftContext {
+        standard: nft_standard,
+        token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        token_program: token_program.to_account_info(),
+        system_program: system_program.to_account_info(),
+        sysvar_instructions: sysvar_instructions.to_account_info(),
+        auth_rules_program: auth_rules_program.to_account_info(),
+        auth_rules: auth_rules.to_account_info(),
+        mint: nft_mint.to_account_info(),
+        metadata: mint_metadata.to_account_info(),
+        edition: token_mint_edition.to_account_info(),
+        token: dest_nft_token_account_info.to_account_info(),
+        token_owner: seller.to_account_info(),
+        token_record: dest_token_mint_record.to_account_info(),
+        delegate: global_authority.to_account_info(),
+        payer,
+    };
+    nft.transfer(
+        &token_account_info.to_account_info(),
+        &buyer.to_account_info(),
+        &token_mint_record.to_account_info(),
+        &associated_token_program.to_account_info(),
+        signer,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+        space = 8 + 384,
+        payer = admin
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct SetTreshold<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+}
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct AddTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+}
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct RemoveTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Account<'info, GlobalPool>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct MigrateGlobalPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: Global PDA before the upgrade, checked on the realloc
+    pub global_authority: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey, bump: u8)]
+pub struct MigrateUserData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), address.as_ref()],
+        bump,
+    )]
+    /// CHECK: User PDA before the upgrade, checked on the realloc
+    pub user_pool: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey, bump: u8)]
+pub struct ReconcileEscrowBalance<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+}
+
+#[derive(Accounts)]
+#[instruction(nft: Pubkey, bump: u8)]
+pub struct MigrateSellData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: Sell Data PDA before the upgrade, checked on the realloc
+    pub sell_data_info: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nft: Pubkey, buyer: Pubkey, bump: u8)]
+pub struct MigrateOfferData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft.to_bytes().as_ref(), buyer.to_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: Offer Data PDA before the upgrade, checked on the realloc
+    pub offer_data_info: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nft: Pubkey, bump: u8)]
+pub struct MigrateAuctionData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
+        bump,
+    )]
+    /// CHECK: Auction Data PDA before the upgrade, checked on the realloc
+    pub auction_data_info: UncheckedAccount<'info>,
+    // Required if the auction has a bid
+    #[account(mut)]
+    pub bidder_user_pool: Option<Box<Account<'info, UserData>>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct InitUserPool<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        init,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+        space = 8 + 72,
+        payer = owner,
+    )]
+    pub user_pool: Account<'info, UserData>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AuditEscrow<'info> {
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(nft: Pubkey, bump: u8)]
+pub struct InitSellData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [SELL_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
+        bump,
+        space = 8 + 304,
+        payer = payer,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSellData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), sell_data_info.mint.to_bytes().as_ref()],
+        bump,
+        constraint = sell_data_info.payer == payer.key() @ MarketplaceError::InvalidRentPayer,
+        close = payer,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct ListPNftForSale<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *owner.key,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // #[account(
+    //     init_if_needed,
+    //     associated_token::mint = nft_mint,
+    //     associated_token::authority = global_authority,
+    //     payer = owner,
+    // )]
+    // pub dest_nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: legacy pre delegates will be removed
+    pub dest_nft_token_account: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+    /// the mint metadata
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub dest_token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK: this account is safe
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Account<'info, AuctionData>,
+
+    /// Only required for the listings priced in SPL Token
+    pub payment_mint_account: Option<Box<Account<'info, token::Mint>>>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct DelistPNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *owner.key,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // #[account(
+    //     mut,
+    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
+    //     constraint = dest_nft_token_account.owner == global_authority.key(),
+    //     constraint = dest_nft_token_account.amount == 1,
+    // )]
+    // pub dest_nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: legacy pre delegates will be removed
+    pub dest_nft_token_account: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+    /// the mint metadata
+    #[account(
+            mut,
+            constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+        )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub dest_token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK: this account is safe
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct DelistExpiredPNft<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub seller: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *seller.key,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+    /// the mint metadata
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+
+    /// CHECK: Global authority NFT ATA, created & closed while clearing the SPL delegate
+    #[account(mut)]
+    pub global_nft_token_account: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSale<'info> {
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+    #[account(
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct SetPrice<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct PurchasePNft<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_user_pool: Account<'info, UserData>,
+
+    // #[account(
+    //     mut,
+    //     constraint = auction_data_info.creator==creator.key(),
+    // )]
+    // /// CHECK: This is not dangerous because we don't read or write from this account
+    // pub creator: AccountInfo<'info>,
+    /// NFT receiver, same as the buyer for the normal purchase
+    pub recipient: SystemAccount<'info>,
+
+    /// Only required to credit the traded volume to the recipient
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), recipient.key().as_ref()],
+        bump,
+    )]
+    pub recipient_user_pool: Option<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        constraint = user_nft_token_account.mint == nft_mint.key(),
+        constraint = user_nft_token_account.owner == *recipient.key,
+    )]
+    pub user_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == sell_data_info.seller.key(),
+        constraint = dest_nft_token_account.amount == 1,
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    /// Only required for the listings priced in SPL Token
+    #[account(mut)]
+    pub buyer_payment_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Only required for the listings priced in SPL Token
+    #[account(mut)]
+    pub seller_payment_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+
+    /// Only required for the listings priced in SPL Token
+    /// NFT token program can be Token-2022 so the payment goes through its own program
+    pub payment_token_program: Option<Program<'info, Token>>,
+
+    /// Only required for the purchase paid from the escrow balance
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_user_pool: Account<'info, UserData>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+    /// the mint metadata
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub dest_token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK: this account is safe
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+}
+
+#[derive(Accounts)]
+pub struct ListCNftForSale<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), sell_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK instruction will fail if wrong tree config is supplied
+    pub tree_config: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong tree is supplied
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = bubblegum_program.key == &mpl_bubblegum::ID)]
+    pub bubblegum_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        seeds = [AUCTION_DATA_SEED.as_ref(), sell_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub auction_data_info: Account<'info, AuctionData>,
+}
+
+#[derive(Accounts)]
+pub struct DelistCNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), sell_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Account<'info, SellData>,
+
+    /// CHECK instruction will fail if wrong tree config is supplied
+    pub tree_config: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong tree is supplied
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = bubblegum_program.key == &mpl_bubblegum::ID)]
+    pub bubblegum_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseCNft<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), sell_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_user_pool: Account<'info, UserData>,
+
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_user_pool: Account<'info, UserData>,
+
+    /// CHECK instruction will fail if wrong tree config is supplied
+    pub tree_config: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong tree is supplied
+    #[account(mut)]
+    pub merkle_tree: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub log_wrapper: AccountInfo<'info>,
+    /// CHECK instruction will fail if wrong program is supplied
+    pub compression_program: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = bubblegum_program.key == &mpl_bubblegum::ID)]
+    pub bubblegum_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(global_bump: u8, bundle_id: u64)]
+pub struct ListBundleForSale<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), owner.key().to_bytes().as_ref(), bundle_id.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + 232,
+        payer = owner,
+    )]
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
+
+    pub token_program: Program<'info, Token>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DelistBundle<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_data_info.seller.to_bytes().as_ref(), bundle_data_info.bundle_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = bundle_data_info.seller == owner.key() @ MarketplaceError::SellerMismatch,
+        close = owner,
+    )]
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
+
+    pub token_program: Program<'info, Token>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseBundle<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [BUNDLE_DATA_SEED.as_ref(), bundle_data_info.seller.to_bytes().as_ref(), bundle_data_info.bundle_id.to_le_bytes().as_ref()],
+        bump,
+        constraint = bundle_data_info.seller == seller.key() @ MarketplaceError::SellerAccountMismatch,
+        close = seller,
+    )]
+    pub bundle_data_info: Box<Account<'info, BundleData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_user_pool: Account<'info, UserData>,
+
+    #[account(mut)]
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_user_pool: Account<'info, UserData>,
+
+    pub token_program: Program<'info, Token>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nft: Pubkey, bump: u8)]
+pub struct InitOfferData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft.to_bytes().as_ref(), payer.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 112,
+        payer = payer,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct ListPNftForSale<'info> {
+pub struct MakeOffer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    /// CHECK: Listing of the NFT, empty when the NFT is never listed
+    #[account(
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        bump,
+        close = owner,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundOffer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), offer_data_info.mint.to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump,
+        close = buyer,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+
+    /// CHECK: Listing of the offer, empty when the Sell Data PDA is closed
+    #[account(
+        seeds = [SELL_DATA_SEED.as_ref(), offer_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), offer_data_info.mint.to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump,
+        close = buyer,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+
+    // Offers are rejected by the NFT holder
+    #[account(
+        constraint = seller_nft_token_account.mint == offer_data_info.mint,
+        constraint = seller_nft_token_account.owner == seller.key(),
+        constraint = seller_nft_token_account.amount == 1,
+    )]
+    pub seller_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CounterOffer<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [SELL_DATA_SEED.as_ref(), offer_data_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), offer_data_info.mint.to_bytes().as_ref(), offer_data_info.buyer.to_bytes().as_ref()],
+        bump,
+    )]
+    pub offer_data_info: Account<'info, OfferData>,
+}
+
+#[derive(Accounts)]
+#[instruction(user_bump: u8, escrow_bump: u8, collection: Pubkey)]
+pub struct MakeCollectionOffer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        init,
+        seeds = [COLLECTION_OFFER_SEED.as_ref(), collection.to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 112,
+        payer = owner,
+    )]
+    pub collection_offer_info: Account<'info, CollectionOfferData>,
+
+    /// CHECK: Metadata PDA of the collection NFT, checked in the instruction
+    pub collection_metadata: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CancelCollectionOffer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [COLLECTION_OFFER_SEED.as_ref(), collection_offer_info.collection.to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        bump,
+        close = owner,
+    )]
+    pub collection_offer_info: Account<'info, CollectionOfferData>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Account<'info, UserData>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptCollectionOffer<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub seller: Signer<'info>,
+
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
 
     #[account(
         mut,
-        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        seeds = [COLLECTION_OFFER_SEED.as_ref(), collection_offer_info.collection.to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
         bump,
     )]
-    pub global_authority: Box<Account<'info, GlobalPool>>,
+    pub collection_offer_info: Box<Account<'info, CollectionOfferData>>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
         bump,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub seller_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *owner.key,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
-    // #[account(
-    //     init_if_needed,
-    //     associated_token::mint = nft_mint,
-    //     associated_token::authority = global_authority,
-    //     payer = owner,
-    // )]
-    // pub dest_nft_token_account: Account<'info, TokenAccount>,
-    /// CHECK: legacy pre delegates will be removed
-    pub dest_nft_token_account: AccountInfo<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = user_nft_token_account.mint == nft_mint.key(),
+        constraint = user_nft_token_account.owner == *buyer.key,
+    )]
+    pub user_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == seller.key(),
+        constraint = dest_nft_token_account.amount == 1,
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
     /// the mint metadata
     #[account(
         mut,
-        constraint = mint_metadata.owner == &mpl_token_metadata::ID
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
     )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub dest_token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
     pub token_metadata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptStandingOffer<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump,
+        close = buyer,
+    )]
+    pub offer_data_info: Box<Account<'info, OfferData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = user_nft_token_account.mint == nft_mint.key(),
+        constraint = user_nft_token_account.owner == *buyer.key,
+    )]
+    pub user_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == seller.key(),
+        constraint = dest_nft_token_account.amount == 1,
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    /// the mint metadata
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint_metadata: AccountInfo<'info>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
@@ -2073,26 +5836,89 @@ pub struct ListPNftForSale<'info> {
     /// CHECK instruction will fail if wrong sysvar ixns are supplied
     pub sysvar_instructions: AccountInfo<'info>,
 
-    /// CHECK: this account is safe
     pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
 
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBid<'info> {
+    #[account(mut)]
+    pub bidder: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
+        bump,
+    )]
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION_DATA_SEED.as_ref(), auction_data_info.mint.to_bytes().as_ref()],
         bump,
     )]
     pub auction_data_info: Account<'info, AuctionData>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct DelistPNft<'info> {
+pub struct AcceptOfferPNft<'info> {
+    /// Signs when accepting an offer, the buyer signs when accepting a counter offer
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub seller: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
+
+    #[account(mut)]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub buyer: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        bump,
+        close = buyer,
+    )]
+    pub offer_data_info: Box<Account<'info, OfferData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
+        bump,
+    )]
+    pub seller_user_pool: Box<Account<'info, UserData>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
 
     #[account(
         mut,
@@ -2103,37 +5929,42 @@ pub struct DelistPNft<'info> {
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
         bump,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub buyer_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *owner.key,
+        constraint = user_nft_token_account.mint == nft_mint.key(),
+        constraint = user_nft_token_account.owner == *buyer.key,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // #[account(
-    //     mut,
-    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
-    //     constraint = dest_nft_token_account.owner == global_authority.key(),
-    //     constraint = dest_nft_token_account.amount == 1,
-    // )]
-    // pub dest_nft_token_account: Account<'info, TokenAccount>,
-    /// CHECK: legacy pre delegates will be removed
-    pub dest_nft_token_account: AccountInfo<'info>,
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == seller.key(),
+        constraint = dest_nft_token_account.amount == 1,
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    pub escrow_vault: AccountInfo<'info>,
+
     /// the mint metadata
     #[account(
-            mut,
-            constraint = mint_metadata.owner == &mpl_token_metadata::ID
-        )]
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
@@ -2156,8 +5987,8 @@ pub struct DelistPNft<'info> {
 
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
-    pub system_program: Program<'info, System>,
 
+    pub system_program: Program<'info, System>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
     pub token_metadata_program: AccountInfo<'info>,
@@ -2171,27 +6002,42 @@ pub struct DelistPNft<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct SetPrice<'info> {
+#[instruction(nft: Pubkey, bump: u8)]
+pub struct InitAuctionData<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
+        bump,
+        space = 8 + 320,
+        payer = payer,
+    )]
+    pub auction_data_info: Account<'info, AuctionData>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAuctionData<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), auction_data_info.mint.to_bytes().as_ref()],
         bump,
+        constraint = auction_data_info.payer == payer.key() @ MarketplaceError::InvalidRentPayer,
+        close = payer,
     )]
-    pub sell_data_info: Account<'info, SellData>,
-
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    pub auction_data_info: Account<'info, AuctionData>,
 }
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct PurchasePNft<'info> {
+pub struct CreateAuctionPNft<'info> {
     #[account(mut)]
-    pub buyer: Signer<'info>,
+    pub owner: Signer<'info>,
 
     #[account(
         mut,
@@ -2202,60 +6048,41 @@ pub struct PurchasePNft<'info> {
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub sell_data_info: Box<Account<'info, SellData>>,
+    pub auction_data_info: Account<'info, AuctionData>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
-        bump,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *owner.key,
     )]
-    pub buyer_user_pool: Account<'info, UserData>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     // #[account(
     //     mut,
-    //     constraint = auction_data_info.creator==creator.key(),
+    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
+    //     constraint = dest_nft_token_account.owner == global_authority.key(),
     // )]
-    // /// CHECK: This is not dangerous because we don't read or write from this account
-    // pub creator: AccountInfo<'info>,
-    #[account(
-        mut,
-        constraint = user_nft_token_account.mint == nft_mint.key(),
-        constraint = user_nft_token_account.owner == *buyer.key,
-    )]
-    pub user_nft_token_account: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == sell_data_info.seller.key(),
-        constraint = dest_nft_token_account.amount == 1,
-    )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
-
-    #[account(mut)]
-    pub seller: SystemAccount<'info>,
-
-    #[account(
-        mut,
-        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
-        bump,
-    )]
-    pub seller_user_pool: Account<'info, UserData>,
-
+    // pub dest_nft_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: legacy pre delegates will be removed
+    pub dest_nft_token_account: AccountInfo<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
     /// the mint metadata
     #[account(
-        mut,
-        constraint = mint_metadata.owner == &mpl_token_metadata::ID
-    )]
+    mut,
+    constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+)]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
@@ -2278,179 +6105,234 @@ pub struct PurchasePNft<'info> {
 
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
-
     pub system_program: Program<'info, System>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
-    pub token_metadata_program: AccountInfo<'info>,
-
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
-    )]
-    pub auction_data_info: Box<Account<'info, AuctionData>>,
-}
-
-#[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitOfferData<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft.to_bytes().as_ref(), payer.key().to_bytes().as_ref()],
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
-        space = 8 + 88,
-        payer = payer,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    pub sell_data_info: Account<'info, SellData>,
 }
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct MakeOffer<'info> {
+pub struct PlaceBid<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub bidder: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
+    pub auction_data_info: Account<'info, AuctionData>,
 
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
         bump,
     )]
-    pub user_pool: Account<'info, UserData>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub escrow_vault: AccountInfo<'info>,
+
+    /// Previous bidder released by this bid
+    #[account(mut)]
+    pub out_bidder: SystemAccount<'info>,
 
     #[account(
         mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
         bump,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
+    pub bidder_user_pool: Box<Account<'info, UserData>>,
+
+    /// Only required when the auction has a bid to refund
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), out_bidder.key().as_ref()],
+        bump,
+    )]
+    pub out_bidder_user_pool: Option<Box<Account<'info, UserData>>>,
 
     pub system_program: Program<'info, System>,
+
+    #[account(
+        mut,
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sell_data_info: Box<Account<'info, SellData>>,
 }
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct CancelOffer<'info> {
+pub struct ClaimAuctionPNft<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub bidder: SystemAccount<'info>,
 
     #[account(
         mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
-    pub offer_data_info: Account<'info, OfferData>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub user_pool: Box<Account<'info, UserData>>,
+    pub auction_data_info: Account<'info, AuctionData>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *bidder.key,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == auction_data_info.creator.key(),
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = auction_data_info.creator==creator.key(),
+    )]
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub creator: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub nft_mint: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [ESCROW_VAULT_SEED.as_ref()],
         bump,
     )]
-
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub escrow_vault: AccountInfo<'info>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
-}
 
-#[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct CancelBid<'info> {
-    #[account(mut)]
-    pub owner: Signer<'info>,
+    /// Only required when the auction keeps an outbid bid to refund
+    #[account(
+        mut,
+        constraint = prev_bidder.key() == auction_data_info.prev_bidder,
+    )]
+    pub prev_bidder: Option<SystemAccount<'info>>,
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), owner.key().to_bytes().as_ref()],
-        bump,
+        constraint = prev_bidder_user_pool.address == auction_data_info.prev_bidder,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
+    pub prev_bidder_user_pool: Option<Box<Account<'info, UserData>>>,
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), owner.key().as_ref()],
+        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
+        constraint = bidder_user_pool.address == bidder.key(),
         bump,
     )]
-    pub user_pool: Box<Account<'info, UserData>>,
-
-    pub system_program: Program<'info, System>,
+    pub bidder_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        seeds = [USER_DATA_SEED.as_ref(), creator.key().as_ref()],
         bump,
     )]
+    pub creator_user_pool: Box<Account<'info, UserData>>,
 
+    /// the mint metadata
+    #[account(
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
+    pub mint_metadata: AccountInfo<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong edition is supplied
+    pub token_mint_edition: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong record is supplied
+    #[account(mut)]
+    pub dest_token_mint_record: AccountInfo<'info>,
+
+    /// CHECK instruction will fail if wrong rules are supplied
+    pub auth_rules: UncheckedAccount<'info>,
+    /// CHECK instruction will fail if wrong sysvar ixns are supplied
+    pub sysvar_instructions: AccountInfo<'info>,
+
+    /// CHECK: this account is safe
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+
+    /// CHECK intstruction will fail if wrong program is supplied
+    pub auth_rules_program: AccountInfo<'info>,
+
+    /// CHECK: Global authority NFT ATA, created & closed while clearing the SPL delegate
+    #[account(mut)]
+    pub global_nft_token_account: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
 #[instruction(bump: u8)]
-pub struct AcceptOfferPNft<'info> {
+pub struct UpdateReserve<'info> {
     #[account(mut)]
-    pub seller: Signer<'info>,
+    pub creator: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub sell_data_info: Box<Account<'info, SellData>>,
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
 
-    #[account(mut)]
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub buyer: SystemAccount<'info>,
+    pub nft_mint: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealReserve<'info> {
+    pub creator: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [OFFER_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), buyer.key().to_bytes().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), auction_data_info.mint.to_bytes().as_ref()],
         bump,
     )]
-    pub offer_data_info: Box<Account<'info, OfferData>>,
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
+}
 
-    #[account(
-        mut,
-        seeds = [USER_DATA_SEED.as_ref(), seller.key().as_ref()],
-        bump,
-    )]
-    pub seller_user_pool: Box<Account<'info, UserData>>,
+#[derive(Accounts)]
+#[instruction(bump: u8)]
+pub struct CancelAuctionPNft<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    /// Signs when canceling the auction, not required for the crank
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
 
     #[account(
         mut,
@@ -2461,42 +6343,37 @@ pub struct AcceptOfferPNft<'info> {
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), buyer.key().as_ref()],
+        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub buyer_user_pool: Box<Account<'info, UserData>>,
+    pub auction_data_info: Box<Account<'info, AuctionData>>,
 
     #[account(
         mut,
-        constraint = user_nft_token_account.mint == nft_mint.key(),
-        constraint = user_nft_token_account.owner == *buyer.key,
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *creator.key,
     )]
-    pub user_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(
-        mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == seller.key(),
-        constraint = dest_nft_token_account.amount == 1,
-    )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
-    #[account(
-        mut,
-        seeds = [ESCROW_VAULT_SEED.as_ref()],
-        bump,
-    )]
+    // #[account(
+    //     mut,
+    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
+    //     constraint = dest_nft_token_account.owner == global_authority.key(),
+    //     constraint = dest_nft_token_account.amount == 1,
+    // )]
+    // pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: legacy pre delegates will be removed
+    pub dest_nft_token_account: InterfaceAccount<'info, TokenAccount>,
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub escrow_vault: AccountInfo<'info>,
-
+    pub nft_mint: AccountInfo<'info>,
     /// the mint metadata
     #[account(
-        mut,
-        constraint = mint_metadata.owner == &mpl_token_metadata::ID
-    )]
+            mut,
+            constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+        )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
-
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
@@ -2519,88 +6396,71 @@ pub struct AcceptOfferPNft<'info> {
 
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
-
     pub system_program: Program<'info, System>,
+
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
     pub token_metadata_program: AccountInfo<'info>,
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub auction_data_info: Box<Account<'info, AuctionData>>,
-}
+    pub sell_data_info: Account<'info, SellData>,
 
-#[derive(Accounts)]
-#[instruction(nft: Pubkey, bump: u8)]
-pub struct InitAuctionData<'info> {
+    /// CHECK: Global authority NFT ATA, created & closed while clearing the SPL delegate
     #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft.to_bytes().as_ref()],
-        bump,
-        space = 8 + 152,
-        payer = payer,
-    )]
-    pub auction_data_info: Account<'info, AuctionData>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
+    pub global_nft_token_account: AccountInfo<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct CreateAuctionPNft<'info> {
+pub struct CreateSealedAuctionPNft<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
-        mut,
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
     pub global_authority: Box<Account<'info, GlobalPool>>,
 
     #[account(
-        mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        init,
+        seeds = [SEALED_AUCTION_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 184,
+        payer = owner,
+    )]
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
+
+    #[account(
+        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
+    pub sell_data_info: Box<Account<'info, SellData>>,
 
     #[account(
         mut,
         constraint = user_token_account.mint == nft_mint.key(),
         constraint = user_token_account.owner == *owner.key,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // #[account(
-    //     mut,
-    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
-    //     constraint = dest_nft_token_account.owner == global_authority.key(),
-    // )]
-    // pub dest_nft_token_account: Account<'info, TokenAccount>,
-    /// CHECK: legacy pre delegates will be removed
-    pub dest_nft_token_account: AccountInfo<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
     /// the mint metadata
     #[account(
-    mut,
-    constraint = mint_metadata.owner == &mpl_token_metadata::ID
-)]
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
     pub token_metadata_program: AccountInfo<'info>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
-
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
 
@@ -2608,44 +6468,51 @@ pub struct CreateAuctionPNft<'info> {
     #[account(mut)]
     pub token_mint_record: AccountInfo<'info>,
 
-    /// CHECK instruction will fail if wrong record is supplied
-    #[account(mut)]
-    pub dest_token_mint_record: AccountInfo<'info>,
-
     /// CHECK instruction will fail if wrong rules are supplied
     pub auth_rules: UncheckedAccount<'info>,
     /// CHECK instruction will fail if wrong sysvar ixns are supplied
     pub sysvar_instructions: AccountInfo<'info>,
 
-    /// CHECK: this account is safe
-    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
-
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
-    #[account(
-        mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
-        bump,
-    )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct PlaceBid<'info> {
+pub struct CommitSealedBid<'info> {
     #[account(mut)]
     pub bidder: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
 
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [SEALED_AUCTION_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
+
+    #[account(
+        init,
+        seeds = [SEALED_BID_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref(), bidder.key().to_bytes().as_ref()],
+        bump,
+        space = 8 + 112,
+        payer = bidder,
+    )]
+    pub sealed_bid_info: Box<Account<'info, SealedBidData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
@@ -2655,25 +6522,33 @@ pub struct PlaceBid<'info> {
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub escrow_vault: AccountInfo<'info>,
 
-    #[account(mut)]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub out_bidder: SystemAccount<'info>,
-
     pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct RevealSealedBid<'info> {
+    pub bidder: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [SEALED_AUCTION_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref()],
         bump,
     )]
-    pub sell_data_info: Box<Account<'info, SellData>>,
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
+
+    #[account(
+        mut,
+        seeds = [SEALED_BID_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref(), bidder.key().to_bytes().as_ref()],
+        bump,
+    )]
+    pub sealed_bid_info: Box<Account<'info, SealedBidData>>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct ClaimAuctionPNft<'info> {
+pub struct SettleSealedAuction<'info> {
     #[account(mut)]
-    pub bidder: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
@@ -2684,30 +6559,40 @@ pub struct ClaimAuctionPNft<'info> {
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [SEALED_AUCTION_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub auction_data_info: Account<'info, AuctionData>,
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
+
+    #[account(mut)]
+    pub winner: SystemAccount<'info>,
 
     #[account(
         mut,
-        constraint = user_token_account.mint == nft_mint.key(),
-        constraint = user_token_account.owner == *bidder.key,
+        seeds = [SEALED_BID_SEED.as_ref(), nft_mint.key().to_bytes().as_ref(), winner.key().to_bytes().as_ref()],
+        bump,
+        close = winner,
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub winner_bid_info: Box<Account<'info, SealedBidData>>,
+
     #[account(
         mut,
-        constraint = dest_nft_token_account.mint == nft_mint.key(),
-        constraint = dest_nft_token_account.owner == auction_data_info.creator.key(),
-        constraint = dest_nft_token_account.amount == 1,
+        constraint = sealed_auction_info.creator == creator.key(),
     )]
-    pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
+    pub creator: SystemAccount<'info>,
+
     #[account(
         mut,
-        constraint = auction_data_info.creator==creator.key(),
+        constraint = user_token_account.mint == nft_mint.key(),
+        constraint = user_token_account.owner == *winner.key,
     )]
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    pub creator: AccountInfo<'info>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = dest_nft_token_account.mint == nft_mint.key(),
+        constraint = dest_nft_token_account.owner == sealed_auction_info.creator.key(),
+    )]
+    pub dest_nft_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
 
@@ -2721,11 +6606,10 @@ pub struct ClaimAuctionPNft<'info> {
 
     #[account(
         mut,
-        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
-        constraint = bidder_user_pool.address == bidder.key(),
+        seeds = [USER_DATA_SEED.as_ref(), winner.key().as_ref()],
         bump,
     )]
-    pub bidder_user_pool: Box<Account<'info, UserData>>,
+    pub winner_user_pool: Box<Account<'info, UserData>>,
 
     #[account(
         mut,
@@ -2737,12 +6621,12 @@ pub struct ClaimAuctionPNft<'info> {
     /// the mint metadata
     #[account(
         mut,
-        constraint = mint_metadata.owner == &mpl_token_metadata::ID
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
     )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
@@ -2764,7 +6648,6 @@ pub struct ClaimAuctionPNft<'info> {
     /// CHECK instruction will fail if wrong sysvar ixns are supplied
     pub sysvar_instructions: AccountInfo<'info>,
 
-    /// CHECK: this account is safe
     pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
 
     /// CHECK intstruction will fail if wrong program is supplied
@@ -2772,30 +6655,66 @@ pub struct ClaimAuctionPNft<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct UpdateReserve<'info> {
+pub struct RefundSealedBid<'info> {
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub payer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
-    pub auction_data_info: Box<Account<'info, AuctionData>>,
+    pub global_authority: Box<Account<'info, GlobalPool>>,
+
+    #[account(
+        mut,
+        seeds = [SEALED_AUCTION_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref()],
+        bump,
+    )]
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
+
+    #[account(mut)]
+    pub bidder: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEALED_BID_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref(), bidder.key().to_bytes().as_ref()],
+        bump,
+        close = bidder,
+    )]
+    pub sealed_bid_info: Box<Account<'info, SealedBidData>>,
+
+    #[account(
+        mut,
+        seeds = [USER_DATA_SEED.as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserData>>,
 
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED.as_ref()],
+        bump,
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
-    pub nft_mint: AccountInfo<'info>,
+    pub escrow_vault: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(bump: u8)]
-pub struct CancelAuctionPNft<'info> {
+pub struct CancelSealedAuctionPNft<'info> {
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub payer: Signer<'info>,
 
+    /// Signs when canceling the auction, not required for the crank
     #[account(
         mut,
+        constraint = sealed_auction_info.creator == creator.key() @ MarketplaceError::CreatorAccountMismatch,
+    )]
+    pub creator: SystemAccount<'info>,
+
+    #[account(
         seeds = [GLOBAL_AUTHORITY_SEED.as_ref()],
         bump,
     )]
@@ -2803,37 +6722,28 @@ pub struct CancelAuctionPNft<'info> {
 
     #[account(
         mut,
-        seeds = [AUCTION_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [SEALED_AUCTION_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
         bump,
     )]
-    pub auction_data_info: Box<Account<'info, AuctionData>>,
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
 
     #[account(
         mut,
         constraint = user_token_account.mint == nft_mint.key(),
         constraint = user_token_account.owner == *creator.key,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    // #[account(
-    //     mut,
-    //     constraint = dest_nft_token_account.mint == nft_mint.key(),
-    //     constraint = dest_nft_token_account.owner == global_authority.key(),
-    //     constraint = dest_nft_token_account.amount == 1,
-    // )]
-    // pub dest_nft_token_account: Box<Account<'info, TokenAccount>>,
-    /// CHECK: legacy pre delegates will be removed
-    pub dest_nft_token_account: Account<'info, TokenAccount>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub nft_mint: AccountInfo<'info>,
     /// the mint metadata
     #[account(
-            mut,
-            constraint = mint_metadata.owner == &mpl_token_metadata::ID
-        )]
+        mut,
+        constraint = mint_metadata.owner == &mpl_token_metadata::ID || mint_metadata.data_is_empty()
+    )]
     /// CHECK: This is not dangerous because we don't read or write from this account
     pub mint_metadata: AccountInfo<'info>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 
     /// CHECK instruction will fail if wrong edition is supplied
     pub token_mint_edition: AccountInfo<'info>,
@@ -2842,18 +6752,11 @@ pub struct CancelAuctionPNft<'info> {
     #[account(mut)]
     pub token_mint_record: AccountInfo<'info>,
 
-    /// CHECK instruction will fail if wrong record is supplied
-    #[account(mut)]
-    pub dest_token_mint_record: AccountInfo<'info>,
-
     /// CHECK instruction will fail if wrong rules are supplied
     pub auth_rules: UncheckedAccount<'info>,
     /// CHECK instruction will fail if wrong sysvar ixns are supplied
     pub sysvar_instructions: AccountInfo<'info>,
 
-    /// CHECK: this account is safe
-    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
-
     /// CHECK intstruction will fail if wrong program is supplied
     pub auth_rules_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
@@ -2862,10 +6765,23 @@ pub struct CancelAuctionPNft<'info> {
     #[account(constraint = token_metadata_program.key == &mpl_token_metadata::ID)]
     pub token_metadata_program: AccountInfo<'info>,
 
+    /// CHECK: Global authority NFT ATA, created & closed while clearing the SPL delegate
+    #[account(mut)]
+    pub global_nft_token_account: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSealedAuction<'info> {
+    #[account(mut)]
+    pub creator: SystemAccount<'info>,
+
     #[account(
         mut,
-        seeds = [SELL_DATA_SEED.as_ref(), nft_mint.key().to_bytes().as_ref()],
+        seeds = [SEALED_AUCTION_SEED.as_ref(), sealed_auction_info.mint.to_bytes().as_ref()],
         bump,
+        constraint = sealed_auction_info.creator == creator.key() @ MarketplaceError::CreatorAccountMismatch,
+        close = creator,
     )]
-    pub sell_data_info: Account<'info, SellData>,
+    pub sealed_auction_info: Box<Account<'info, SealedAuctionData>>,
 }
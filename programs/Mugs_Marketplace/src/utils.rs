@@ -0,0 +1,879 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token;
+use anchor_spl::token::{self, Transfer};
+use anchor_spl::token_interface::{
+    self, Approve, CloseAccount, Revoke, TokenAccount, TransferChecked,
+};
+use mpl_bubblegum::{
+    hash::{hash_creators, hash_metadata},
+    instructions::{DelegateCpiBuilder, TransferCpiBuilder as LeafTransferCpiBuilder},
+    types::MetadataArgs,
+};
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{
+        DelegateLockedTransferV1CpiBuilder, FreezeDelegatedAccountCpiBuilder, LockV1CpiBuilder,
+        RevokeLockedTransferV1CpiBuilder, ThawDelegatedAccountCpiBuilder, TransferV1CpiBuilder,
+        UnlockV1CpiBuilder,
+    },
+    types::{Collection, Creator, Key as MetadataKey, TokenStandard},
+};
+use solana_program::program::{invoke, invoke_signed};
+use solana_program::program_option::COption;
+use solana_program::system_instruction;
+
+use crate::account::{GlobalPool, SaleQuote};
+use crate::constants::*;
+use crate::error::*;
+
+// Grow the PDA created before the upgrade to the current size, the payer covers the extra rent
+// The added fields are zeroed so the PDA deserializes with the current layout
+pub fn realloc_data_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    discriminator: [u8; 8],
+    old_size: usize,
+    new_size: usize,
+) -> Result<()> {
+    // Assert Program Data Account with the size before the upgrade
+    require!(
+        account.owner.eq(&crate::ID)
+            && account.data_len() == old_size
+            && account.data.borrow()[..8] == discriminator,
+        MarketplaceError::AccountAlreadyMigrated
+    );
+    let rent = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+    if rent > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, rent),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    }
+    account.realloc(new_size, true)?;
+    Ok(())
+}
+
+// Hidden reserve commitment is bound to the auction mint & the creator, copied commitments can't be revealed
+pub fn get_reserve_hash(mint: &Pubkey, creator: &Pubkey, amount: u64, salt: &[u8; 32]) -> [u8; 32] {
+    solana_program::hash::hashv(&[mint.as_ref(), creator.as_ref(), &amount.to_le_bytes(), salt])
+        .to_bytes()
+}
+
+// Sealed bid commitment is bound to the auction mint & the bidder, copied commitments can't be revealed
+pub fn get_sealed_bid_hash(
+    mint: &Pubkey,
+    bidder: &Pubkey,
+    amount: u64,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    solana_program::hash::hashv(&[mint.as_ref(), bidder.as_ref(), &amount.to_le_bytes(), salt])
+        .to_bytes()
+}
+
+// Transfer SOL, signer seeds are only needed when the sender is a PDA
+pub fn transfer_sol<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    invoke_signed(
+        &system_instruction::transfer(from.key, to.key, amount),
+        &[from.clone(), to.clone(), system_program.clone()],
+        signer,
+    )?;
+    Ok(())
+}
+
+// Transfer SPL Token, signer seeds are only needed when the authority is a PDA
+pub fn transfer_token<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = Transfer {
+        from: from.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program.clone(), cpi_accounts, signer),
+        amount,
+    )
+}
+
+// Wallet itself for SOL payments, otherwise the wallet's ATA of the payment mint
+pub fn get_payment_address(wallet: &Pubkey, payment_mint: &Pubkey) -> Pubkey {
+    if payment_mint.eq(&Pubkey::default()) {
+        *wallet
+    } else {
+        anchor_spl::associated_token::get_associated_token_address(wallet, payment_mint)
+    }
+}
+
+// Listing pricing mode takes precedence, unset global mode keeps the legacy mode of the settlement path
+pub fn resolve_pricing_mode(listing_mode: u64, global_mode: u64, legacy_mode: u64) -> u64 {
+    if listing_mode != PRICING_MODE_DEFAULT {
+        listing_mode
+    } else if global_mode != PRICING_MODE_DEFAULT {
+        global_mode
+    } else {
+        legacy_mode
+    }
+}
+
+// Fees & royalties are always computed on the price, the mode decides who bears them
+pub fn get_sale_quote(
+    price: u64,
+    market_fee_sol: u64,
+    seller_fee_basis_points: u16,
+    pricing_mode: u64,
+) -> Result<SaleQuote> {
+    // Fees are computed in u128, overflowing amounts are rejected
+    let market_fee = get_permyriad_share(price, market_fee_sol)?;
+    let royalty = get_permyriad_share(price, seller_fee_basis_points as u64)?;
+    let (seller_proceeds, buyer_total) = if pricing_mode == PRICING_MODE_FEE_INCLUSIVE {
+        // Assert Fees & Royalties fit in the price
+        require!(
+            market_fee + royalty <= price,
+            MarketplaceError::InvalidFeePercent
+        );
+        (price - market_fee - royalty, price)
+    } else {
+        let buyer_total = price
+            .checked_add(market_fee)
+            .and_then(|total| total.checked_add(royalty))
+            .ok_or(MarketplaceError::MathOverflow)?;
+        (price, buyer_total)
+    };
+    Ok(SaleQuote {
+        price,
+        pricing_mode,
+        market_fee,
+        royalty,
+        seller_proceeds,
+        buyer_total,
+    })
+}
+
+// Share of the amount by the permyriad rate
+fn get_permyriad_share(amount: u64, rate: u64) -> Result<u64> {
+    u64::try_from(amount as u128 * rate as u128 / PERMYRIAD as u128)
+        .map_err(|_| error!(MarketplaceError::MathOverflow))
+}
+
+// Split the market fee by the treasury rates, rounding dust goes to the first treasury
+pub fn get_treasury_shares(global_authority: &GlobalPool, fee_amount: u64) -> Vec<u64> {
+    let count = global_authority.team_count as usize;
+    let rate_sum: u64 = global_authority.treasury_rate[..count].iter().sum();
+    let mut shares: Vec<u64> = global_authority.treasury_rate[..count]
+        .iter()
+        .map(|rate| fee_amount * rate / PERMYRIAD)
+        .collect();
+    let distributed: u64 = shares.iter().sum();
+    if let Some(first) = shares.first_mut() {
+        *first += fee_amount * rate_sum / PERMYRIAD - distributed;
+    }
+    shares
+}
+
+// Split the royalty by the creator shares, rounding dust goes to the first creator with a share
+pub fn get_royalty_shares(creator_shares: &[u8], royalty: u64) -> Vec<u64> {
+    let mut shares: Vec<u64> = creator_shares
+        .iter()
+        .map(|share| royalty * (*share as u64) / 100)
+        .collect();
+    let distributed: u64 = shares.iter().sum();
+    if let Some(index) = creator_shares.iter().position(|share| *share != 0) {
+        shares[index] += royalty - distributed;
+    }
+    shares
+}
+
+// Settle an escrowed offer, the seller proceeds, team treasuries & creators are paid from the vault
+// Payee accounts are the team treasuries and then the creators in the metadata order
+#[allow(clippy::too_many_arguments)]
+pub fn settle_from_escrow<'info>(
+    escrow_vault: &AccountInfo<'info>,
+    seller: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    global_authority: &GlobalPool,
+    creators: &[Creator],
+    payee_accounts: &[AccountInfo<'info>],
+    quote: &SaleQuote,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        global_authority.team_count > 0,
+        MarketplaceError::NoTeamTreasuryYet
+    );
+    require!(
+        global_authority.team_count + creators.len() as u64 == payee_accounts.len() as u64,
+        MarketplaceError::TeamTreasuryCountMismatch
+    );
+    transfer_sol(
+        escrow_vault,
+        seller,
+        system_program,
+        quote.seller_proceeds,
+        signer,
+    )?;
+
+    let treasury_shares = get_treasury_shares(global_authority, quote.market_fee);
+    let creator_shares: Vec<u8> = creators.iter().map(|creator| creator.share).collect();
+    let royalty_shares = get_royalty_shares(&creator_shares, quote.royalty);
+    // This is not expensive cuz the max count is 8
+    for (i, payee_account) in payee_accounts.iter().enumerate() {
+        if (i as u64) < global_authority.team_count {
+            // Assert Provided Remaining Account is Treasury
+            require!(
+                payee_account.key().eq(&global_authority.team_treasury[i]),
+                MarketplaceError::TeamTreasuryAddressMismatch
+            );
+            transfer_sol(
+                escrow_vault,
+                payee_account,
+                system_program,
+                treasury_shares[i],
+                signer,
+            )?;
+        } else {
+            let j = i - global_authority.team_count as usize;
+            // Assert Remaining Account is the Creator in the Metadata order
+            require!(
+                payee_account.key().eq(&creators[j].address),
+                MarketplaceError::CreatorAddressMismatch
+            );
+            transfer_sol(
+                escrow_vault,
+                payee_account,
+                system_program,
+                royalty_shares[j],
+                signer,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+// Verify a bundle item is a programmable NFT held by the seller
+pub fn load_bundle_item(
+    mint: &AccountInfo,
+    mint_metadata: &AccountInfo,
+    token: &AccountInfo,
+    seller: &Pubkey,
+) -> Result<Metadata> {
+    let (metadata, _) = Metadata::find_pda(mint.key);
+    require!(
+        metadata == mint_metadata.key(),
+        MarketplaceError::InvaliedMetadata
+    );
+    let (nft_metadata, nft_standard) = load_metadata(mint_metadata, mint)?;
+    require!(
+        token.owner.eq(&token::ID),
+        MarketplaceError::InvalidBundleItem
+    );
+    let token_account = token::TokenAccount::try_deserialize(&mut &token.data.borrow()[..])?;
+    require!(
+        matches!(nft_standard, NftStandard::Programmable)
+            && token_account.mint.eq(mint.key)
+            && token_account.owner.eq(seller)
+            && token_account.amount == 1,
+        MarketplaceError::InvalidBundleItem
+    );
+    Ok(nft_metadata)
+}
+
+// Resolve the NFT collection and its source
+// Verified metadata collection first, falls back to the first verified creator
+pub fn get_collection(metadata: &Metadata) -> Result<(Pubkey, u64)> {
+    if let Some(collection) = &metadata.collection {
+        if collection.verified {
+            return Ok((collection.key, 1));
+        }
+    }
+    if let Some(creators) = &metadata.creators {
+        for creator in creators {
+            if creator.verified {
+                return Ok((creator.address, 2));
+            }
+        }
+        Ok((Pubkey::default(), 0))
+    } else {
+        Err(error!(MarketplaceError::MetadataCreatorParseError))
+    }
+}
+
+pub enum NftStandard {
+    // Token Record & Auth Rules based lock through token metadata program
+    Programmable,
+    // SPL delegate & freeze through the master edition
+    Legacy,
+    // Token-2022 mint without Metaplex metadata, SPL delegate of the global authority without freeze
+    Token2022,
+}
+
+impl NftStandard {
+    // Fungible & fungible asset standards are rejected
+    // Metadata created before the token standard field is a legacy NFT
+    pub fn from_metadata(metadata: &Metadata) -> Result<Self> {
+        match metadata.token_standard {
+            Some(TokenStandard::ProgrammableNonFungible)
+            | Some(TokenStandard::ProgrammableNonFungibleEdition) => Ok(NftStandard::Programmable),
+            Some(TokenStandard::NonFungible) | Some(TokenStandard::NonFungibleEdition) | None => {
+                Ok(NftStandard::Legacy)
+            }
+            _ => Err(error!(MarketplaceError::InvalidTokenStandard)),
+        }
+    }
+}
+
+// Token-2022 token-metadata extension layout
+#[derive(AnchorDeserialize)]
+pub struct TokenMetadataExtension {
+    pub update_authority: Pubkey,
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub additional_metadata: Vec<(String, String)>,
+}
+
+// Find the Token-2022 mint extension value by type
+// Parsed by hand since the bundled spl-token-2022 doesn't know the group extensions
+pub fn get_mint_extension(data: &[u8], extension_type: u16) -> Option<&[u8]> {
+    if data.len() <= MINT_EXTENSIONS_START || data[MINT_EXTENSIONS_START - 1] != MINT_ACCOUNT_TYPE {
+        return None;
+    }
+    let mut index = MINT_EXTENSIONS_START;
+    while index + 4 <= data.len() {
+        let value_type = u16::from_le_bytes([data[index], data[index + 1]]);
+        let length = u16::from_le_bytes([data[index + 2], data[index + 3]]) as usize;
+        let start = index + 4;
+        // Nothing is written after an uninitialized entry
+        if value_type == 0 || start + length > data.len() {
+            return None;
+        }
+        if value_type == extension_type {
+            return Some(&data[start..start + length]);
+        }
+        index = start + length;
+    }
+    None
+}
+
+// Pointer extensions are (authority, address), only the data stored in the mint itself is read
+fn points_to_mint(data: &[u8], pointer_type: u16, mint: &Pubkey) -> bool {
+    match get_mint_extension(data, pointer_type) {
+        Some(pointer) => pointer.len() == 64 && pointer[32..64] == mint.to_bytes(),
+        None => false,
+    }
+}
+
+// Single token mint with no decimals
+fn is_nft_mint(data: &[u8]) -> bool {
+    data.len() > MINT_DECIMALS_OFFSET
+        && data[MINT_SUPPLY_OFFSET..MINT_DECIMALS_OFFSET] == 1u64.to_le_bytes()
+        && data[MINT_DECIMALS_OFFSET] == 0
+}
+
+// NFT is still in the holder token account under the global authority delegate
+// Token-2022 NFTs aren't frozen, so the holder can move the NFT or revoke the delegate meanwhile
+pub fn is_nft_locked(token: &TokenAccount, delegate: &Pubkey) -> bool {
+    token.amount == 1 && token.delegate == COption::Some(*delegate)
+}
+
+// Load the NFT metadata and its token standard
+// Token-2022 mints without Metaplex metadata are read from the token-metadata extension
+// and the token group membership is treated as the verified collection
+pub fn load_metadata(
+    mint_metadata: &AccountInfo,
+    nft_mint: &AccountInfo,
+) -> Result<(Metadata, NftStandard)> {
+    // Assert NFT Mint with the single token supply
+    require!(
+        (nft_mint.owner.eq(&token::ID) || nft_mint.owner.eq(&anchor_spl::token_2022::ID))
+            && is_nft_mint(&nft_mint.data.borrow()),
+        MarketplaceError::InvaliedMetadata
+    );
+    if mint_metadata.owner.eq(&mpl_token_metadata::ID) && !mint_metadata.data_is_empty() {
+        let metadata = Metadata::safe_deserialize(&mint_metadata.data.borrow())
+            .map_err(|_| error!(MarketplaceError::InvaliedMetadata))?;
+        let standard = NftStandard::from_metadata(&metadata)?;
+        return Ok((metadata, standard));
+    }
+
+    // Assert Token-2022 Mint with the Metadata Pointer to itself
+    require!(
+        nft_mint.owner.eq(&anchor_spl::token_2022::ID),
+        MarketplaceError::InvaliedMetadata
+    );
+    let data = nft_mint.data.borrow();
+    require!(
+        points_to_mint(&data, EXTENSION_METADATA_POINTER, nft_mint.key),
+        MarketplaceError::InvaliedMetadata
+    );
+    let token_metadata = match get_mint_extension(&data, EXTENSION_TOKEN_METADATA) {
+        Some(value) => TokenMetadataExtension::try_from_slice(value)
+            .map_err(|_| error!(MarketplaceError::InvaliedMetadata))?,
+        None => return Err(error!(MarketplaceError::InvaliedMetadata)),
+    };
+
+    // Group member is (mint, group, member number)
+    let mut collection = None;
+    if points_to_mint(&data, EXTENSION_GROUP_MEMBER_POINTER, nft_mint.key) {
+        if let Some(member) = get_mint_extension(&data, EXTENSION_GROUP_MEMBER) {
+            if member.len() >= 64 {
+                collection = Some(Collection {
+                    verified: true,
+                    key: Pubkey::try_from(&member[32..64]).unwrap(),
+                });
+            }
+        }
+    }
+
+    let metadata = Metadata {
+        key: MetadataKey::MetadataV1,
+        update_authority: token_metadata.update_authority,
+        mint: token_metadata.mint,
+        name: token_metadata.name,
+        symbol: token_metadata.symbol,
+        uri: token_metadata.uri,
+        // Token-2022 metadata has no royalty info
+        seller_fee_basis_points: 0,
+        creators: Some(vec![]),
+        primary_sale_happened: true,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: None,
+        collection,
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
+    };
+    Ok((metadata, NftStandard::Token2022))
+}
+
+// Accounts for locking the NFT in the holder wallet and transferring it with the delegate
+pub struct NftContext<'info> {
+    pub standard: NftStandard,
+    pub token_metadata_program: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub sysvar_instructions: AccountInfo<'info>,
+    pub auth_rules_program: AccountInfo<'info>,
+    pub auth_rules: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub edition: AccountInfo<'info>,
+    // Current holder token account, owner & token record
+    pub token: AccountInfo<'info>,
+    pub token_owner: AccountInfo<'info>,
+    pub token_record: AccountInfo<'info>,
+    // Global authority PDA
+    pub delegate: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+}
+
+impl<'info> NftContext<'info> {
+    // Delegate the NFT to the global authority and lock it, token owner should sign
+    pub fn lock(&self, signer: &[&[&[u8]]]) -> Result<()> {
+        match self.standard {
+            NftStandard::Programmable => {
+                DelegateLockedTransferV1CpiBuilder::new(&self.token_metadata_program)
+                    .master_edition(Some(&self.edition))
+                    .spl_token_program(Some(&self.token_program))
+                    .authority(&self.token_owner)
+                    .delegate(&self.delegate)
+                    .payer(&self.payer)
+                    .mint(&self.mint)
+                    .metadata(&self.metadata)
+                    .token(&self.token)
+                    .token_record(Some(&self.token_record))
+                    .amount(1)
+                    .authorization_rules(Some(&self.auth_rules))
+                    .sysvar_instructions(&self.sysvar_instructions)
+                    .authorization_rules_program(Some(&self.auth_rules_program))
+                    .system_program(&self.system_program)
+                    .locked_address(self.token.key())
+                    .invoke_signed(signer)?;
+                LockV1CpiBuilder::new(&self.token_metadata_program)
+                    .edition(Some(&self.edition))
+                    .spl_token_program(Some(&self.token_program))
+                    .authority(&self.delegate)
+                    .payer(&self.payer)
+                    .mint(&self.mint)
+                    .metadata(&self.metadata)
+                    .token(&self.token)
+                    .token_owner(Some(&self.token_owner))
+                    .token_record(Some(&self.token_record))
+                    .authorization_rules(Some(&self.auth_rules))
+                    .sysvar_instructions(&self.sysvar_instructions)
+                    .authorization_rules_program(Some(&self.auth_rules_program))
+                    .system_program(&self.system_program)
+                    .invoke_signed(signer)?;
+            }
+            NftStandard::Legacy => {
+                self.approve()?;
+                FreezeDelegatedAccountCpiBuilder::new(&self.token_metadata_program)
+                    .delegate(&self.delegate)
+                    .token_account(&self.token)
+                    .edition(&self.edition)
+                    .mint(&self.mint)
+                    .token_program(&self.token_program)
+                    .invoke_signed(signer)?;
+            }
+            // Token-2022 mint freeze authority is the collection's, the delegate is not frozen
+            NftStandard::Token2022 => self.approve()?,
+        }
+        Ok(())
+    }
+
+    // Approve the global authority as the SPL delegate, token owner should sign
+    fn approve(&self) -> Result<()> {
+        let cpi_accounts = Approve {
+            to: self.token.clone(),
+            delegate: self.delegate.clone(),
+            authority: self.token_owner.clone(),
+        };
+        token_interface::approve(CpiContext::new(self.token_program.clone(), cpi_accounts), 1)
+    }
+
+    // Unlock the NFT with the global authority, delegate is kept
+    pub fn unlock(&self, signer: &[&[&[u8]]]) -> Result<()> {
+        match self.standard {
+            NftStandard::Programmable => {
+                UnlockV1CpiBuilder::new(&self.token_metadata_program)
+                    .edition(Some(&self.edition))
+                    .spl_token_program(Some(&self.token_program))
+                    .authority(&self.delegate)
+                    .payer(&self.payer)
+                    .mint(&self.mint)
+                    .metadata(&self.metadata)
+                    .token(&self.token)
+                    .token_owner(Some(&self.token_owner))
+                    .token_record(Some(&self.token_record))
+                    .authorization_rules(Some(&self.auth_rules))
+                    .sysvar_instructions(&self.sysvar_instructions)
+                    .authorization_rules_program(Some(&self.auth_rules_program))
+                    .system_program(&self.system_program)
+                    .invoke_signed(signer)?;
+            }
+            NftStandard::Legacy => {
+                ThawDelegatedAccountCpiBuilder::new(&self.token_metadata_program)
+                    .delegate(&self.delegate)
+                    .token_account(&self.token)
+                    .edition(&self.edition)
+                    .mint(&self.mint)
+                    .token_program(&self.token_program)
+                    .invoke_signed(signer)?;
+            }
+            NftStandard::Token2022 => {}
+        }
+        Ok(())
+    }
+
+    // Unlock the NFT and revoke the global authority delegate, authority is the signing token owner
+    pub fn unlock_and_revoke(
+        &self,
+        authority: &AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        self.unlock(signer)?;
+        self.revoke(authority, signer)
+    }
+
+    // Unlock the NFT and clear the global authority delegate while the token owner is not signing
+    pub fn unlock_and_revoke_by_delegate(
+        &self,
+        delegate_token: &AccountInfo<'info>,
+        associated_token_program: &AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        self.unlock(signer)?;
+        match self.standard {
+            NftStandard::Programmable => self.revoke(&self.delegate, signer),
+            NftStandard::Legacy | NftStandard::Token2022 => {
+                // Nothing to clear when the Token-2022 holder moved the NFT or revoked the delegate
+                let token = TokenAccount::try_deserialize(&mut &self.token.data.borrow()[..])?;
+                if !is_nft_locked(&token, &self.delegate.key()) {
+                    return Ok(());
+                }
+                // SPL delegate can't revoke itself, but the delegated transfer of the whole
+                // approved amount clears it, so the NFT moves to the global authority ATA and back
+                let created = delegate_token.data_is_empty();
+                let cpi_accounts = associated_token::Create {
+                    payer: self.payer.clone(),
+                    associated_token: delegate_token.clone(),
+                    authority: self.delegate.clone(),
+                    mint: self.mint.clone(),
+                    system_program: self.system_program.clone(),
+                    token_program: self.token_program.clone(),
+                };
+                associated_token::create_idempotent(CpiContext::new(
+                    associated_token_program.clone(),
+                    cpi_accounts,
+                ))?;
+                for (from, to) in [(&self.token, delegate_token), (delegate_token, &self.token)] {
+                    let cpi_accounts = TransferChecked {
+                        from: from.clone(),
+                        mint: self.mint.clone(),
+                        to: to.clone(),
+                        authority: self.delegate.clone(),
+                    };
+                    token_interface::transfer_checked(
+                        CpiContext::new_with_signer(
+                            self.token_program.clone(),
+                            cpi_accounts,
+                            signer,
+                        ),
+                        1,
+                        0,
+                    )?;
+                }
+                // Rent of the temporary ATA goes back to the payer
+                if created {
+                    let cpi_accounts = CloseAccount {
+                        account: delegate_token.clone(),
+                        destination: self.payer.clone(),
+                        authority: self.delegate.clone(),
+                    };
+                    token_interface::close_account(CpiContext::new_with_signer(
+                        self.token_program.clone(),
+                        cpi_accounts,
+                        signer,
+                    ))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn revoke(&self, authority: &AccountInfo<'info>, signer: &[&[&[u8]]]) -> Result<()> {
+        match self.standard {
+            NftStandard::Programmable => {
+                RevokeLockedTransferV1CpiBuilder::new(&self.token_metadata_program)
+                    .master_edition(Some(&self.edition))
+                    .spl_token_program(Some(&self.token_program))
+                    .system_program(&self.system_program)
+                    .authority(authority)
+                    .delegate(&self.delegate)
+                    .payer(&self.payer)
+                    .mint(&self.mint)
+                    .metadata(&self.metadata)
+                    .token_record(Some(&self.token_record))
+                    .token(&self.token)
+                    .authorization_rules(Some(&self.auth_rules))
+                    .sysvar_instructions(&self.sysvar_instructions)
+                    .authorization_rules_program(Some(&self.auth_rules_program))
+                    .invoke_signed(signer)?;
+            }
+            NftStandard::Legacy | NftStandard::Token2022 => {
+                let cpi_accounts = Revoke {
+                    source: self.token.clone(),
+                    authority: self.token_owner.clone(),
+                };
+                token_interface::revoke(CpiContext::new(self.token_program.clone(), cpi_accounts))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Unlock the NFT and transfer it to the destination with the global authority
+    pub fn transfer(
+        &self,
+        destination_token: &AccountInfo<'info>,
+        destination_owner: &AccountInfo<'info>,
+        destination_token_record: &AccountInfo<'info>,
+        associated_token_program: &AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        self.unlock(signer)?;
+        self.transfer_with_authority(
+            &self.delegate,
+            destination_token,
+            destination_owner,
+            destination_token_record,
+            associated_token_program,
+            signer,
+        )
+    }
+
+    // Transfer the unlocked NFT to the destination with the token owner signature
+    pub fn transfer_by_owner(
+        &self,
+        destination_token: &AccountInfo<'info>,
+        destination_owner: &AccountInfo<'info>,
+        destination_token_record: &AccountInfo<'info>,
+        associated_token_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        self.transfer_with_authority(
+            &self.token_owner,
+            destination_token,
+            destination_owner,
+            destination_token_record,
+            associated_token_program,
+            &[],
+        )
+    }
+
+    fn transfer_with_authority(
+        &self,
+        authority: &AccountInfo<'info>,
+        destination_token: &AccountInfo<'info>,
+        destination_owner: &AccountInfo<'info>,
+        destination_token_record: &AccountInfo<'info>,
+        associated_token_program: &AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        match self.standard {
+            NftStandard::Programmable => {
+                TransferV1CpiBuilder::new(&self.token_metadata_program)
+                    .authority(authority)
+                    .payer(&self.payer)
+                    .mint(&self.mint)
+                    .metadata(&self.metadata)
+                    .edition(Some(&self.edition))
+                    .destination_token(destination_token)
+                    .destination_owner(destination_owner)
+                    .destination_token_record(Some(destination_token_record))
+                    .token_record(Some(&self.token_record))
+                    .token_owner(&self.token_owner)
+                    .token(&self.token)
+                    .amount(1)
+                    .authorization_rules(Some(&self.auth_rules))
+                    .sysvar_instructions(&self.sysvar_instructions)
+                    .authorization_rules_program(Some(&self.auth_rules_program))
+                    .spl_ata_program(associated_token_program)
+                    .spl_token_program(&self.token_program)
+                    .system_program(&self.system_program)
+                    .invoke_signed(signer)?;
+            }
+            NftStandard::Legacy | NftStandard::Token2022 => {
+                let cpi_accounts = TransferChecked {
+                    from: self.token.clone(),
+                    mint: self.mint.clone(),
+                    to: destination_token.clone(),
+                    authority: authority.clone(),
+                };
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(self.token_program.clone(), cpi_accounts, signer),
+                    1,
+                    0,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Resolve the compressed NFT collection and its source, same order as get_collection
+pub fn get_cnft_collection(metadata: &MetadataArgs) -> (Pubkey, u64) {
+    if let Some(collection) = &metadata.collection {
+        if collection.verified {
+            return (collection.key, 1);
+        }
+    }
+    for creator in &metadata.creators {
+        if creator.verified {
+            return (creator.address, 2);
+        }
+    }
+    (Pubkey::default(), 0)
+}
+
+// Accounts for delegating & transferring the compressed NFT leaf through Bubblegum
+// Leaf hashes are built from the metadata args, so Bubblegum rejects the faked metadata
+pub struct CNftContext<'info> {
+    pub bubblegum_program: AccountInfo<'info>,
+    pub tree_config: AccountInfo<'info>,
+    pub merkle_tree: AccountInfo<'info>,
+    pub log_wrapper: AccountInfo<'info>,
+    pub compression_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    // Merkle proof nodes
+    pub proof: Vec<AccountInfo<'info>>,
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+impl<'info> CNftContext<'info> {
+    pub fn hash_leaf(metadata: &MetadataArgs) -> Result<([u8; 32], [u8; 32])> {
+        let data_hash =
+            hash_metadata(metadata).map_err(|_| error!(MarketplaceError::InvaliedMetadata))?;
+        Ok((data_hash, hash_creators(&metadata.creators)))
+    }
+
+    fn proof_accounts(&self) -> Vec<(&AccountInfo<'info>, bool, bool)> {
+        self.proof.iter().map(|node| (node, false, false)).collect()
+    }
+
+    // Set the leaf delegate, the leaf owner should sign
+    pub fn delegate(
+        &self,
+        leaf_owner: &AccountInfo<'info>,
+        previous_leaf_delegate: &AccountInfo<'info>,
+        new_leaf_delegate: &AccountInfo<'info>,
+    ) -> Result<()> {
+        DelegateCpiBuilder::new(&self.bubblegum_program)
+            .tree_config(&self.tree_config)
+            .leaf_owner(leaf_owner)
+            .previous_leaf_delegate(previous_leaf_delegate)
+            .new_leaf_delegate(new_leaf_delegate)
+            .merkle_tree(&self.merkle_tree)
+            .log_wrapper(&self.log_wrapper)
+            .compression_program(&self.compression_program)
+            .system_program(&self.system_program)
+            .root(self.root)
+            .data_hash(self.data_hash)
+            .creator_hash(self.creator_hash)
+            .nonce(self.nonce)
+            .index(self.index)
+            .add_remaining_accounts(&self.proof_accounts())
+            .invoke()?;
+        Ok(())
+    }
+
+    // Transfer the leaf with the global authority as the leaf delegate
+    pub fn transfer(
+        &self,
+        leaf_owner: &AccountInfo<'info>,
+        leaf_delegate: &AccountInfo<'info>,
+        new_leaf_owner: &AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+    ) -> Result<()> {
+        LeafTransferCpiBuilder::new(&self.bubblegum_program)
+            .tree_config(&self.tree_config)
+            .leaf_owner(leaf_owner, false)
+            .leaf_delegate(leaf_delegate, true)
+            .new_leaf_owner(new_leaf_owner)
+            .merkle_tree(&self.merkle_tree)
+            .log_wrapper(&self.log_wrapper)
+            .compression_program(&self.compression_program)
+            .system_program(&self.system_program)
+            .root(self.root)
+            .data_hash(self.data_hash)
+            .creator_hash(self.creator_hash)
+            .nonce(self.nonce)
+            .index(self.index)
+            .add_remaining_accounts(&self.proof_accounts())
+            .invoke_signed(signer)?;
+        Ok(())
+    }
+}
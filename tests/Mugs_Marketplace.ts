@@ -4,33 +4,89 @@ import { Token, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { assert } from "chai";
 import fs from "fs";
 import {
+  createAcceptCounterOfferTx,
+  createAcceptStandingOfferTx,
   createAddTreasuryTx,
+  createAuditEscrowTx,
+  createCancelAuctionPnftTx,
+  createCancelBidTx,
+  createCancelCollectionOfferTx,
+  createClaimAuctionPnftTx,
+  createCloseAuctionDataTx,
+  createCloseSellDataTx,
+  createCommitSealedBidTx,
+  createCounterOfferTx,
+  createCreateAuctionPnftTx,
+  createCreateSealedAuctionPnftTx,
+  createDelistPNftTx,
+  createDepositTx,
+  createInitAuctionDataTx,
   createInitializeTx,
+  createInitOfferDataTx,
   createInitSellDataTx,
   createInitUserTx,
+  createListBundleForSaleTx,
   createListForSellNftTx,
+  createListForSellPNftTx,
+  createMakeCollectionOfferTx,
+  createMakeOfferTx,
+  createMigrateAuctionDataTx,
+  createMigrateGlobalPoolTx,
+  createMigrateOfferDataTx,
+  createMigrateSellDataTx,
+  createMigrateUserDataTx,
+  createPlaceBidTx,
+  createPurchaseBundleTx,
+  createPurchasePNftTx,
   createPurchaseTx,
+  createReconcileEscrowBalanceTx,
+  createRefundOfferTx,
+  createRejectOfferTx,
+  createRevealReserveTx,
+  createRevealSealedBidTx,
   createRemoveTreasuryTx,
   createUpdateFeeTx,
+  createUpdatePricingModeTx,
+  createWithdrawTx,
+  getAuctionDataState,
+  getBundleDataState,
+  getCollectionOfferState,
+  getCurrentPrice,
   getGlobalState,
   getNFTPoolState,
+  getOfferDataState,
+  getPaymentAddress,
+  getReserveHash,
+  getSealedAuctionState,
+  getSealedBidHash,
+  getSaleQuote,
   getUserPoolState,
+  resolvePricingMode,
 } from "../lib/scripts";
 import {
   ABB_TOKEN_DECIMAL,
   ABB_TOKEN_MINT,
+  AUCTION_DATA_SEED,
+  GLOBAL_AUTHORITY_SEED,
   MARKETPLACE_PROGRAM_ID,
+  PRICING_MODE_DEFAULT,
+  PRICING_MODE_FEE_INCLUSIVE,
+  PRICING_MODE_FEE_ON_TOP,
   SELL_DATA_SEED,
+  SellData,
   USER_DATA_SEED,
 } from "../lib/types";
 import {
   airdropSOL,
+  createTestNft,
   createTokenMint,
   getAssociatedTokenAccount,
   getATokenAccountsNeedCreate,
   getEscrowBalance,
   getTokenAccountBalance,
   isExistAccount,
+  TOKEN_STANDARD_NON_FUNGIBLE,
+  TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE,
 } from "../lib/utils";
 import { MugsMarketplace } from "../target/types/mugs_marketplace";
 
@@ -48,6 +104,54 @@ let user1 = null;
 let reward = null;
 let nft = null;
 
+// Parsed token account of the owner ATA, the listed legacy NFT is frozen & delegated
+const getNftTokenState = async (owner: anchor.web3.PublicKey, mint) => {
+  const tokenAccount = await getAssociatedTokenAccount(owner, mint);
+  const info = await provider.connection.getParsedAccountInfo(tokenAccount);
+  return (info.value.data as anchor.web3.ParsedAccountData).parsed.info;
+};
+
+// New legacy NFT of the user listed for sale with the Sell & Auction Data PDAs
+const listTestNft = async (priceSol: number) => {
+  const mint = await createTestNft(
+    provider.connection,
+    user,
+    user.publicKey,
+    TOKEN_STANDARD_NON_FUNGIBLE
+  );
+  let tx = await createInitSellDataTx(
+    mint,
+    user.publicKey,
+    program as unknown as anchor.Program
+  );
+  let txId = await provider.connection.sendTransaction(tx, [user]);
+  await provider.connection.confirmTransaction(txId, "confirmed");
+
+  tx = await createInitAuctionDataTx(
+    mint,
+    user.publicKey,
+    program as unknown as anchor.Program
+  );
+  txId = await provider.connection.sendTransaction(tx, [user]);
+  await provider.connection.confirmTransaction(txId, "confirmed");
+
+  tx = await createListForSellPNftTx(
+    mint,
+    user.publicKey,
+    program as unknown as anchor.Program,
+    provider.connection,
+    priceSol
+  );
+  txId = await provider.connection.sendTransaction(tx, [user]);
+  await provider.connection.confirmTransaction(txId, "confirmed");
+  return mint;
+};
+
+const getClusterTime = async () => {
+  const slot = await provider.connection.getSlot("confirmed");
+  return await provider.connection.getBlockTime(slot);
+};
+
 describe("Mugs_Marketplace Load Program Object & Prepare testers", () => {
   assert(
     program.programId.toBase58() == MARKETPLACE_PROGRAM_ID.toBase58(),
@@ -585,6 +689,1714 @@ describe("user1 Can Purchase NFT", async () => {
     assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
   });
 });
+
+describe("Marketplace Pricing Mode", async () => {
+  it("Admin can change the Marketplace Pricing Mode", async () => {
+    let tx = await createUpdatePricingModeTx(
+      superOwner.publicKey,
+      program as unknown as anchor.Program,
+      PRICING_MODE_FEE_INCLUSIVE
+    );
+    let txId = await provider.connection.sendTransaction(tx, [superOwner]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    let globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(
+      globalInfo.pricingMode.toNumber() == PRICING_MODE_FEE_INCLUSIVE,
+      "Pricing Mode is not Fee Inclusive"
+    );
+
+    tx = await createUpdatePricingModeTx(
+      superOwner.publicKey,
+      program as unknown as anchor.Program,
+      PRICING_MODE_FEE_ON_TOP
+    );
+    txId = await provider.connection.sendTransaction(tx, [superOwner]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    globalInfo = await getGlobalState(program as unknown as anchor.Program);
+    assert(
+      globalInfo.pricingMode.toNumber() == PRICING_MODE_FEE_ON_TOP,
+      "Pricing Mode is not Fee On Top"
+    );
+  });
+  it("Admin can not set the unknown Pricing Mode", async () => {
+    let failed = false;
+    try {
+      const tx = await createUpdatePricingModeTx(
+        superOwner.publicKey,
+        program as unknown as anchor.Program,
+        3
+      );
+      const txId = await provider.connection.sendTransaction(tx, [
+        superOwner,
+      ]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Unknown Pricing Mode is accepted");
+  });
+  it("Sale quote splits the fees by the Pricing Mode", async () => {
+    const price = new anchor.BN(2 * 1e9);
+    const marketFee = new anchor.BN(100);
+
+    let quote = getSaleQuote(price, marketFee, 500, PRICING_MODE_FEE_ON_TOP);
+    assert(quote.marketFee.toNumber() == 0.02 * 1e9, "Market fee is not 1%");
+    assert(quote.royalty.toNumber() == 0.1 * 1e9, "Royalty is not 5%");
+    assert(
+      quote.buyerTotal.toNumber() == 2.12 * 1e9,
+      "Buyer does not pay the fees on top"
+    );
+    assert(
+      quote.sellerProceeds.toNumber() == 2 * 1e9,
+      "Seller does not receive the full price"
+    );
+
+    quote = getSaleQuote(price, marketFee, 500, PRICING_MODE_FEE_INCLUSIVE);
+    assert(
+      quote.buyerTotal.toNumber() == 2 * 1e9,
+      "Buyer does not pay the listed price"
+    );
+    assert(
+      quote.sellerProceeds.toNumber() == 1.88 * 1e9,
+      "Seller does not pay the fees from the price"
+    );
+  });
+  it("Unset Pricing Mode keeps the legacy mode of the settlement path", async () => {
+    assert(
+      resolvePricingMode(
+        PRICING_MODE_DEFAULT,
+        PRICING_MODE_DEFAULT,
+        PRICING_MODE_FEE_INCLUSIVE
+      ) == PRICING_MODE_FEE_INCLUSIVE,
+      "Offers & auctions are not fee inclusive by default"
+    );
+    assert(
+      resolvePricingMode(
+        PRICING_MODE_DEFAULT,
+        PRICING_MODE_FEE_ON_TOP,
+        PRICING_MODE_FEE_INCLUSIVE
+      ) == PRICING_MODE_FEE_ON_TOP,
+      "Global Pricing Mode is not applied"
+    );
+    assert(
+      resolvePricingMode(
+        PRICING_MODE_FEE_INCLUSIVE,
+        PRICING_MODE_FEE_ON_TOP,
+        PRICING_MODE_FEE_ON_TOP
+      ) == PRICING_MODE_FEE_INCLUSIVE,
+      "Listing Pricing Mode does not take precedence"
+    );
+  });
+});
+
+describe("SPL Token Priced Listing", async () => {
+  it("SOL payments are sent to the wallet", async () => {
+    const address = await getPaymentAddress(
+      user.publicKey,
+      anchor.web3.PublicKey.default
+    );
+    assert(
+      address.toBase58() == user.publicKey.toBase58(),
+      "SOL payment address is not the wallet"
+    );
+  });
+  it("SPL Token payments are sent to the wallet ATA", async () => {
+    const address = await getPaymentAddress(user.publicKey, reward.publicKey);
+    const userATA = await getAssociatedTokenAccount(
+      user.publicKey,
+      reward.publicKey
+    );
+    assert(
+      address.toBase58() == userATA.toBase58(),
+      "SPL Token payment address is not the payment mint ATA"
+    );
+  });
+  it("User can not list for a payment mint that is not a SPL Token mint", async () => {
+    const mint = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      mint,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      mint,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    let failed = false;
+    try {
+      tx = await createListForSellPNftTx(
+        mint,
+        user.publicKey,
+        program as unknown as anchor.Program,
+        provider.connection,
+        1e9,
+        user1.publicKey
+      );
+      txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Wallet is accepted as the payment mint");
+  });
+});
+
+describe("Legacy NFT Listing", async () => {
+  let legacyNft = null;
+
+  it("Create one legacy NFT for testing", async () => {
+    legacyNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    console.log("NFT Address:", legacyNft.toBase58());
+  });
+  it("User can list the legacy NFT for sale", async () => {
+    let tx = await createInitSellDataTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createListForSellPNftTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1e9
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const nftInfo = await getNFTPoolState(
+      legacyNft,
+      program as unknown as anchor.Program
+    );
+    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
+    assert(
+      nftInfo.priceSol.toNumber() == 1e9,
+      "NFT SellData solPrice is not 1"
+    );
+
+    const [globalAuthority] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+      MARKETPLACE_PROGRAM_ID
+    );
+    const tokenState = await getNftTokenState(user.publicKey, legacyNft);
+    assert(tokenState.state == "frozen", "Listed NFT is not frozen");
+    assert(
+      tokenState.delegate == globalAuthority.toBase58(),
+      "Listed NFT is not delegated to the Global PDA"
+    );
+  });
+  it("User can cancel the legacy NFT listing", async () => {
+    const tx = await createDelistPNftTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const nftInfo = await getNFTPoolState(
+      legacyNft,
+      program as unknown as anchor.Program
+    );
+    assert(nftInfo.active.toNumber() == 0, "NFT SellData is still actived");
+
+    const tokenState = await getNftTokenState(user.publicKey, legacyNft);
+    assert(tokenState.state == "initialized", "Delisted NFT is still frozen");
+    assert(
+      tokenState.delegate === undefined,
+      "Delisted NFT is still delegated"
+    );
+  });
+  it("User can close the inactive Sell Data & Auction Data PDAs", async () => {
+    let tx = await createCloseSellDataTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCloseAuctionDataTx(
+      legacyNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const [sellData] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(SELL_DATA_SEED), legacyNft.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+    const [auctionData] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(AUCTION_DATA_SEED), legacyNft.toBuffer()],
+      MARKETPLACE_PROGRAM_ID
+    );
+    assert(
+      !(await isExistAccount(sellData, provider.connection)),
+      "Sell Data PDA is not closed"
+    );
+    assert(
+      !(await isExistAccount(auctionData, provider.connection)),
+      "Auction Data PDA is not closed"
+    );
+  });
+});
+
+describe("Private Listing", async () => {
+  let privateNft = null;
+
+  it("User can list an NFT reserved for the admin", async () => {
+    privateNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      privateNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      privateNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createListForSellPNftTx(
+      privateNft,
+      user.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection,
+      1e9,
+      anchor.web3.PublicKey.default,
+      null,
+      superOwner.publicKey
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    const nftInfo = await getNFTPoolState(
+      privateNft,
+      program as unknown as anchor.Program
+    );
+    assert(
+      nftInfo.reservedBuyer.toBase58() == superOwner.publicKey.toBase58(),
+      "NFT SellData is not reserved for the admin"
+    );
+  });
+  it("User1 can not purchase the NFT reserved for the admin", async () => {
+    let failed = false;
+    try {
+      const tx = await createPurchasePNftTx(
+        privateNft,
+        user1.publicKey,
+        [],
+        program as unknown as anchor.Program,
+        provider.connection
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user1]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Reserved NFT is purchased by another buyer");
+
+    const nftInfo = await getNFTPoolState(
+      privateNft,
+      program as unknown as anchor.Program
+    );
+    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
+  });
+  it("User1 can not purchase the reserved NFT as a gift for the admin", async () => {
+    let failed = false;
+    try {
+      const tx = await createPurchasePNftTx(
+        privateNft,
+        user1.publicKey,
+        [],
+        program as unknown as anchor.Program,
+        provider.connection,
+        false,
+        superOwner.publicKey
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user1]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Reserved NFT is purchased as a gift by user1");
+
+    const nftInfo = await getNFTPoolState(
+      privateNft,
+      program as unknown as anchor.Program
+    );
+    assert(nftInfo.active.toNumber() == 1, "NFT SellData is not actived");
+  });
+});
+
+describe("Escrow Purchase", async () => {
+  let escrowNft = null;
+
+  it("User lists an NFT for the escrow purchase", async () => {
+    escrowNft = await listTestNft(1e9);
+  });
+  it("User1 can purchase the NFT from the escrow balance", async () => {
+    let tx = await createDepositTx(
+      user1.publicKey,
+      2e9,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    tx = await createPurchasePNftTx(
+      escrowNft,
+      user1.publicKey,
+      [],
+      program as unknown as anchor.Program,
+      provider.connection,
+      true
+    );
+    txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const userAfter = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userBefore.escrowSolBalance.sub(userAfter.escrowSolBalance).gten(1e9),
+      "Purchase is not paid from the escrow balance"
+    );
+
+    const tokenState = await getNftTokenState(user1.publicKey, escrowNft);
+    assert(
+      tokenState.tokenAmount.amount == "1",
+      "Purchased NFT is not owned by user1"
+    );
+  });
+  it("User can not purchase the own listing from the escrow balance", async () => {
+    const ownNft = await listTestNft(1e9);
+    let tx = await createDepositTx(
+      user.publicKey,
+      2e9,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    const userBefore = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    let failed = false;
+    try {
+      tx = await createPurchasePNftTx(
+        ownNft,
+        user.publicKey,
+        [],
+        program as unknown as anchor.Program,
+        provider.connection,
+        true
+      );
+      txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Own listing is purchased from the escrow balance");
+
+    const userAfter = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userAfter.escrowSolBalance.eq(userBefore.escrowSolBalance),
+      "Escrow balance is changed by the self purchase"
+    );
+  });
+});
+
+describe("Gift Purchase", async () => {
+  it("User1 can purchase an NFT for the admin wallet", async () => {
+    const giftNft = await listTestNft(1e9);
+
+    const tx = await createPurchasePNftTx(
+      giftNft,
+      user1.publicKey,
+      [],
+      program as unknown as anchor.Program,
+      provider.connection,
+      false,
+      superOwner.publicKey
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const tokenState = await getNftTokenState(superOwner.publicKey, giftNft);
+    assert(
+      tokenState.tokenAmount.amount == "1",
+      "Gift NFT is not delivered to the recipient"
+    );
+  });
+  it("User1 can not credit the volume to the buyer as the recipient", async () => {
+    const giftNft = await listTestNft(1e9);
+
+    let failed = false;
+    try {
+      const tx = await createPurchasePNftTx(
+        giftNft,
+        user1.publicKey,
+        [],
+        program as unknown as anchor.Program,
+        provider.connection,
+        false,
+        user1.publicKey,
+        true
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user1]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Volume is credited to the buyer as the recipient");
+  });
+});
+
+describe("Bundle Listing", async () => {
+  let bundleNfts = [];
+
+  it("User can list two pNFTs as one bundle", async () => {
+    for (let k = 0; k < 2; k++) {
+      bundleNfts.push(
+        await createTestNft(
+          provider.connection,
+          user,
+          user.publicKey,
+          TOKEN_STANDARD_PROGRAMMABLE_NON_FUNGIBLE
+        )
+      );
+    }
+
+    const tx = await createListBundleForSaleTx(
+      bundleNfts,
+      user.publicKey,
+      1,
+      2e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const bundleInfo = await getBundleDataState(
+      user.publicKey,
+      1,
+      program as unknown as anchor.Program
+    );
+    assert(bundleInfo.itemCount.toNumber() == 2, "Bundle has not two items");
+    assert(
+      bundleInfo.priceSol.toNumber() == 2e9,
+      "Bundle price is not 2 SOL"
+    );
+  });
+  it("User1 can purchase the bundle as one lot", async () => {
+    const tx = await createPurchaseBundleTx(
+      user.publicKey,
+      1,
+      user1.publicKey,
+      [],
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    for (const mint of bundleNfts) {
+      const tokenState = await getNftTokenState(user1.publicKey, mint);
+      assert(
+        tokenState.tokenAmount.amount == "1",
+        "Bundle item is not delivered to user1"
+      );
+    }
+  });
+});
+
+describe("Collection Offer", async () => {
+  const collection = anchor.web3.Keypair.generate().publicKey;
+  let lockedAmount = null;
+
+  it("User1 can make offer for the collection", async () => {
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    const tx = await createMakeCollectionOfferTx(
+      collection,
+      user1.publicKey,
+      0.1 * 1e9,
+      2,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    // Fees on top are locked with the price for each NFT
+    const offerInfo = await getCollectionOfferState(
+      collection,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const quote = getSaleQuote(
+      offerInfo.price,
+      offerInfo.marketFeeSol,
+      offerInfo.royaltyBasisPoints.toNumber(),
+      offerInfo.pricingMode.toNumber()
+    );
+    lockedAmount = quote.buyerTotal.muln(2);
+
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userInfo.lockedSolBalance
+        .sub(userBefore.lockedSolBalance)
+        .eq(lockedAmount),
+      "Collection offer deposit is not locked in the escrow"
+    );
+  });
+  it("User1 can cancel the collection offer", async () => {
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    const tx = await createCancelCollectionOfferTx(
+      collection,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userBefore.lockedSolBalance
+        .sub(userInfo.lockedSolBalance)
+        .eq(lockedAmount),
+      "Collection offer deposit is not released from the escrow"
+    );
+  });
+});
+
+describe("Standing Offer", async () => {
+  let unlistedNft = null;
+
+  it("User1 can make an offer for an unlisted NFT", async () => {
+    unlistedNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+
+    let tx = await createInitOfferDataTx(
+      unlistedNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createMakeOfferTx(
+      unlistedNft,
+      user1.publicKey,
+      0.1 * 1e9,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const offerInfo = await getOfferDataState(
+      unlistedNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      offerInfo.active.toNumber() == 1 &&
+        offerInfo.offerListingDate.toNumber() == 0,
+      "Standing offer is not made"
+    );
+  });
+  it("User1 can not make an offer over the live offer", async () => {
+    let failed = false;
+    try {
+      const tx = await createMakeOfferTx(
+        unlistedNft,
+        user1.publicKey,
+        0.2 * 1e9,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user1]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Live offer is replaced without releasing its escrow");
+  });
+  it("User can accept the standing offer without listing", async () => {
+    const tx = await createAcceptStandingOfferTx(
+      unlistedNft,
+      user.publicKey,
+      user1.publicKey,
+      [],
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const tokenState = await getNftTokenState(user1.publicKey, unlistedNft);
+    assert(
+      tokenState.tokenAmount.amount == "1",
+      "Accepted NFT is not owned by user1"
+    );
+  });
+});
+
+describe("Offer Expiry", async () => {
+  let unlistedNft = null;
+
+  it("User1 can make an offer with expiry", async () => {
+    unlistedNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+
+    let tx = await createInitOfferDataTx(
+      unlistedNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    const expiresAt = (await getClusterTime()) + 10;
+    tx = await createMakeOfferTx(
+      unlistedNft,
+      user1.publicKey,
+      0.1 * 1e9,
+      program as unknown as anchor.Program,
+      expiresAt
+    );
+    txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const offerInfo = await getOfferDataState(
+      unlistedNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      offerInfo.expiresAt.toNumber() == expiresAt,
+      "Offer expiry is not saved"
+    );
+  });
+  it("Offer Data PDA with the current layout can not be migrated", async () => {
+    let failed = false;
+    try {
+      const tx = await createMigrateOfferDataTx(
+        user.publicKey,
+        unlistedNft,
+        user1.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Offer Data PDA is migrated twice");
+  });
+  it("User can not refund the live offer", async () => {
+    let failed = false;
+    try {
+      const tx = await createRefundOfferTx(
+        unlistedNft,
+        user.publicKey,
+        user1.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Live offer is refunded");
+  });
+  it("User can refund the expired offer to User1", async () => {
+    const offerInfo = await getOfferDataState(
+      unlistedNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    while ((await getClusterTime()) <= offerInfo.expiresAt.toNumber()) {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+    }
+
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    const tx = await createRefundOfferTx(
+      unlistedNft,
+      user.publicKey,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userBefore.lockedSolBalance.sub(userInfo.lockedSolBalance).toNumber() ==
+        0.1 * 1e9,
+      "Offer price is not released from the escrow"
+    );
+    assert(
+      (await getOfferDataState(
+        unlistedNft,
+        user1.publicKey,
+        program as unknown as anchor.Program
+      )) == null,
+      "Refunded Offer Data PDA is not closed"
+    );
+  });
+});
+
+describe("Escrow Ledger", async () => {
+  it("User escrow deposit is counted in the escrow liabilities", async () => {
+    const globalBefore = await getGlobalState(
+      program as unknown as anchor.Program
+    );
+    const userBefore = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    const tx = await createDepositTx(
+      user.publicKey,
+      1e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const globalInfo = await getGlobalState(
+      program as unknown as anchor.Program
+    );
+    const userInfo = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userInfo.escrowSolBalance.sub(userBefore.escrowSolBalance).toNumber() ==
+        1e9,
+      "User escrow balance is not increased by 1 SOL"
+    );
+    assert(
+      globalInfo.escrowLiabilities
+        .sub(globalBefore.escrowLiabilities)
+        .toNumber() == 1e9,
+      "Escrow liabilities are not increased by 1 SOL"
+    );
+    assert(
+      (await getEscrowBalance(provider.connection)).sol >=
+        globalInfo.escrowLiabilities.toNumber(),
+      "Escrow vault does not cover the escrow liabilities"
+    );
+  });
+  it("Anyone can audit the escrow vault", async () => {
+    const tx = await createAuditEscrowTx(program as unknown as anchor.Program);
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+  });
+  it("User can not withdraw more than the available escrow", async () => {
+    const userInfo = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    let failed = false;
+    try {
+      const tx = await createWithdrawTx(
+        user.publicKey,
+        userInfo.escrowSolBalance.toNumber() + 1,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "User withdrawed more than the escrow balance");
+  });
+  it("User can withdraw the escrow deposit", async () => {
+    const tx = await createWithdrawTx(
+      user.publicKey,
+      1e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+  });
+});
+
+describe("Reject / Counter Offer", async () => {
+  let offerNft = null;
+
+  const makeOffer = async (mint, price: number) => {
+    let tx = await createInitOfferDataTx(
+      mint,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createMakeOfferTx(
+      mint,
+      user1.publicKey,
+      price,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+  };
+
+  it("User can reject the offer of User1", async () => {
+    const rejectedNft = await listTestNft(1e9);
+    await makeOffer(rejectedNft, 0.6 * 1e9);
+
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+
+    const tx = await createRejectOfferTx(
+      rejectedNft,
+      user.publicKey,
+      user1.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      userBefore.lockedSolBalance.sub(userInfo.lockedSolBalance).toNumber() ==
+        0.6 * 1e9,
+      "Rejected offer is not released from the escrow"
+    );
+    assert(
+      (await getOfferDataState(
+        rejectedNft,
+        user1.publicKey,
+        program as unknown as anchor.Program
+      )) == null,
+      "Rejected Offer Data PDA is not closed"
+    );
+  });
+  it("User can counter the offer of User1", async () => {
+    offerNft = await listTestNft(1e9);
+    await makeOffer(offerNft, 0.6 * 1e9);
+
+    const tx = await createCounterOfferTx(
+      offerNft,
+      user.publicKey,
+      user1.publicKey,
+      0.8 * 1e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const offerInfo = await getOfferDataState(
+      offerNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      offerInfo.counterPrice.toNumber() == 0.8 * 1e9,
+      "Counter price is not saved"
+    );
+  });
+  it("User1 can accept the counter offer", async () => {
+    const tx = await createAcceptCounterOfferTx(
+      offerNft,
+      user1.publicKey,
+      [],
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const tokenState = await getNftTokenState(user1.publicKey, offerNft);
+    assert(
+      tokenState.tokenAmount.amount == "1",
+      "Countered NFT is not owned by user1"
+    );
+  });
+});
+
+describe("Auction Bid Extension", async () => {
+  let auctionNft = null;
+
+  it("User can create an auction with the bid extension", async () => {
+    auctionNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCreateAuctionPnftTx(
+      auctionNft,
+      user.publicKey,
+      0.1 * 1e9,
+      0.01 * 1e9,
+      20,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+      PRICING_MODE_DEFAULT,
+      30,
+      60
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.extensionWindow.toNumber() == 30 &&
+        auctionInfo.extensionLength.toNumber() == 60,
+      "Bid extension is not saved"
+    );
+  });
+  it("Bid within the extension window extends the auction", async () => {
+    const tx = await createPlaceBidTx(
+      auctionNft,
+      user1.publicKey,
+      0.1 * 1e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.endDate.sub(auctionInfo.lastBidDate).toNumber() == 60,
+      "Auction is not extended by the bid"
+    );
+  });
+  it("Auction can not be settled before the end", async () => {
+    let failed = false;
+    try {
+      const tx = await createClaimAuctionPnftTx(
+        auctionNft,
+        user.publicKey,
+        [],
+        program as unknown as anchor.Program,
+        provider.connection
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Live auction is settled");
+  });
+});
+
+describe("Auction Crank Cancel", async () => {
+  let auctionNft = null;
+
+  it("User creates an auction ended without bids", async () => {
+    auctionNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCreateAuctionPnftTx(
+      auctionNft,
+      user.publicKey,
+      0.1 * 1e9,
+      0.01 * 1e9,
+      5,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+  });
+  it("Anyone can cancel the ended auction back to the creator", async () => {
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    while ((await getClusterTime()) <= auctionInfo.endDate.toNumber()) {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+    }
+
+    const tx = await createCancelAuctionPnftTx(
+      auctionNft,
+      user1.publicKey,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionAfter = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    assert(auctionAfter.status.toNumber() == 0, "Auction is not canceled");
+
+    const tokenState = await getNftTokenState(user.publicKey, auctionNft);
+    assert(
+      tokenState.tokenAmount.amount == "1",
+      "Canceled NFT is not owned by the creator"
+    );
+    assert(tokenState.state == "initialized", "Canceled NFT is still frozen");
+    assert(
+      tokenState.delegate === undefined,
+      "Canceled NFT is still delegated"
+    );
+
+    // Temporary Global PDA ATA clearing the delegate is closed
+    const [globalAuthority] = await anchor.web3.PublicKey.findProgramAddress(
+      [Buffer.from(GLOBAL_AUTHORITY_SEED)],
+      MARKETPLACE_PROGRAM_ID
+    );
+    const globalNftTokenAccount = await getAssociatedTokenAccount(
+      globalAuthority,
+      auctionNft
+    );
+    assert(
+      (await provider.connection.getAccountInfo(globalNftTokenAccount)) ===
+        null,
+      "Global PDA NFT account is not closed"
+    );
+  });
+});
+
+describe("Auction Hidden Reserve", async () => {
+  let auctionNft = null;
+  const salt = Buffer.alloc(32, 3);
+
+  it("User can create an auction with the hidden reserve", async () => {
+    auctionNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCreateAuctionPnftTx(
+      auctionNft,
+      user.publicKey,
+      0.1 * 1e9,
+      0.01 * 1e9,
+      60,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection,
+      PRICING_MODE_DEFAULT,
+      0,
+      0,
+      0,
+      getReserveHash(auctionNft, user.publicKey, 0.5 * 1e9, salt)
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.reservePrice.toNumber() == 0 &&
+        Buffer.from(auctionInfo.reserveHash).equals(
+          getReserveHash(auctionNft, user.publicKey, 0.5 * 1e9, salt)
+        ),
+      "Hidden reserve is not committed"
+    );
+  });
+  it("Hidden reserve hash is bound to the mint & creator", async () => {
+    const mint = anchor.web3.Keypair.generate().publicKey;
+
+    const hash = getReserveHash(mint, user.publicKey, 0.5 * 1e9, salt);
+    assert(
+      !hash.equals(getReserveHash(auctionNft, user.publicKey, 0.5 * 1e9, salt)),
+      "Hidden reserve hash is not bound to the mint"
+    );
+    assert(
+      !hash.equals(getReserveHash(mint, user1.publicKey, 0.5 * 1e9, salt)),
+      "Hidden reserve hash is not bound to the creator"
+    );
+  });
+  it("User can not reveal a different reserve price", async () => {
+    let failed = false;
+    try {
+      const tx = await createRevealReserveTx(
+        auctionNft,
+        user.publicKey,
+        0.4 * 1e9,
+        salt,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Reserve is revealed with a different price");
+  });
+  it("User can reveal the committed reserve price", async () => {
+    const tx = await createRevealReserveTx(
+      auctionNft,
+      user.publicKey,
+      0.5 * 1e9,
+      salt,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.reservePrice.toNumber() == 0.5 * 1e9 &&
+        auctionInfo.reserveHash.every((byte) => byte == 0),
+      "Reserve price is not revealed"
+    );
+  });
+});
+
+describe("Bid Retraction", async () => {
+  let auctionNft = null;
+
+  it("user1 can bid on a long auction", async () => {
+    auctionNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createInitAuctionDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCreateAuctionPnftTx(
+      auctionNft,
+      user.publicKey,
+      0.1 * 1e9,
+      0.01 * 1e9,
+      7200,
+      false,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createPlaceBidTx(
+      auctionNft,
+      user1.publicKey,
+      0.1 * 1e9,
+      program as unknown as anchor.Program
+    );
+    txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    const globalInfo = await getGlobalState(
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.lastBidder.toBase58() == user1.publicKey.toBase58(),
+      "Bid is not placed"
+    );
+    assert(
+      auctionInfo.marketFeeSol.eq(globalInfo.marketFeeSol) &&
+        auctionInfo.pricingMode.toNumber() != PRICING_MODE_DEFAULT,
+      "Fee rates are not fixed on the auction creation"
+    );
+  });
+  it("Seller can not retract the bid of user1", async () => {
+    let failed = false;
+    try {
+      const tx = await createCancelBidTx(
+        auctionNft,
+        user.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Bid is retracted by a different user");
+  });
+  it("user1 can retract the bid shortly after bidding", async () => {
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const tx = await createCancelBidTx(
+      auctionNft,
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getAuctionDataState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.lastBidder.toBase58() ==
+        anchor.web3.PublicKey.default.toBase58() &&
+        auctionInfo.highestBid.toNumber() == 0,
+      "Auction is not reverted to the previous bid"
+    );
+    // The bid was locked with the fees on top at the rates fixed on the creation
+    const quote = getSaleQuote(
+      new anchor.BN(0.1 * 1e9),
+      auctionInfo.marketFeeSol,
+      auctionInfo.royaltyBasisPoints.toNumber(),
+      auctionInfo.pricingMode.toNumber()
+    );
+    assert(
+      userBefore.lockedSolBalance
+        .sub(userInfo.lockedSolBalance)
+        .eq(quote.buyerTotal),
+      "Retracted bid is still locked"
+    );
+  });
+});
+
+describe("Sealed Bid Commitment", async () => {
+  it("Sealed bid hash is bound to the mint, bidder & amount", async () => {
+    const mint = anchor.web3.Keypair.generate().publicKey;
+    const salt = Buffer.alloc(32, 7);
+
+    const hash = getSealedBidHash(mint, user.publicKey, 1e9, salt);
+    assert(hash.length == 32, "Sealed bid hash is not sha256");
+    assert(
+      hash.equals(getSealedBidHash(mint, user.publicKey, 1e9, salt)),
+      "Sealed bid hash is not deterministic"
+    );
+    assert(
+      !hash.equals(getSealedBidHash(mint, user1.publicKey, 1e9, salt)),
+      "Sealed bid hash is not bound to the bidder"
+    );
+    assert(
+      !hash.equals(getSealedBidHash(mint, user.publicKey, 2e9, salt)),
+      "Sealed bid hash is not bound to the amount"
+    );
+  });
+});
+
+describe("Sealed Bid Auction", async () => {
+  let auctionNft = null;
+  const salt = Buffer.alloc(32, 3);
+
+  it("User can create a second price sealed auction", async () => {
+    auctionNft = await createTestNft(
+      provider.connection,
+      user,
+      user.publicKey,
+      TOKEN_STANDARD_NON_FUNGIBLE
+    );
+    let tx = await createInitSellDataTx(
+      auctionNft,
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    let txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+
+    tx = await createCreateSealedAuctionPnftTx(
+      auctionNft,
+      user.publicKey,
+      0.1 * 1e9,
+      60,
+      60,
+      2,
+      PRICING_MODE_DEFAULT,
+      program as unknown as anchor.Program,
+      provider.connection
+    );
+    txId = await provider.connection.sendTransaction(tx, [user]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getSealedAuctionState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    const globalInfo = await getGlobalState(
+      program as unknown as anchor.Program
+    );
+    assert(
+      auctionInfo.status.toNumber() == 1 &&
+        auctionInfo.priceRule.toNumber() == 2,
+      "Sealed auction is not started"
+    );
+    assert(
+      auctionInfo.marketFeeSol.eq(globalInfo.marketFeeSol) &&
+        auctionInfo.pricingMode.toNumber() != PRICING_MODE_DEFAULT,
+      "Fee rates are not fixed on the sealed auction creation"
+    );
+  });
+  it("user1 can commit a sealed bid with the deposit", async () => {
+    const userBefore = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    const tx = await createCommitSealedBidTx(
+      auctionNft,
+      user1.publicKey,
+      getSealedBidHash(auctionNft, user1.publicKey, 0.2 * 1e9, salt),
+      0.3 * 1e9,
+      program as unknown as anchor.Program
+    );
+    const txId = await provider.connection.sendTransaction(tx, [user1]);
+    await provider.connection.confirmTransaction(txId, "confirmed");
+    console.log("TxHash=", txId);
+
+    const auctionInfo = await getSealedAuctionState(
+      auctionNft,
+      program as unknown as anchor.Program
+    );
+    const userInfo = await getUserPoolState(
+      user1.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(auctionInfo.openBids.toNumber() == 1, "Sealed bid is not counted");
+    assert(
+      userInfo.lockedSolBalance.sub(userBefore.lockedSolBalance).toNumber() ==
+        0.3 * 1e9,
+      "Sealed bid deposit is not locked"
+    );
+  });
+  it("user1 can not reveal the bid in the commit phase", async () => {
+    let failed = false;
+    try {
+      const tx = await createRevealSealedBidTx(
+        auctionNft,
+        user1.publicKey,
+        0.2 * 1e9,
+        salt,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user1]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Sealed bid is revealed in the commit phase");
+  });
+});
+
+describe("Dutch Auction Listing Price", async () => {
+  it("Current price declines by step to the floor price", async () => {
+    const sellData = {
+      priceSol: new anchor.BN(10 * 1e9),
+      listedDate: new anchor.BN(1_000),
+      dutchFloorPrice: new anchor.BN(4 * 1e9),
+      dutchDuration: new anchor.BN(600),
+      dutchStep: new anchor.BN(60),
+    } as unknown as SellData;
+
+    assert(
+      getCurrentPrice(sellData, 1_000).toNumber() == 10 * 1e9,
+      "Dutch price is not the start price at listing"
+    );
+    assert(
+      getCurrentPrice(sellData, 1_119).toNumber() == 9.4 * 1e9,
+      "Dutch price does not decline by step"
+    );
+    assert(
+      getCurrentPrice(sellData, 1_300).toNumber() == 7 * 1e9,
+      "Dutch price is not linear"
+    );
+    assert(
+      getCurrentPrice(sellData, 5_000).toNumber() == 4 * 1e9,
+      "Dutch price is not the floor price after the duration"
+    );
+  });
+});
+
+describe("Upgrade Migration", async () => {
+  it("Migrated Global PDA can not be migrated again", async () => {
+    let failed = false;
+    try {
+      const tx = await createMigrateGlobalPoolTx(
+        superOwner.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [
+        superOwner,
+      ]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Global PDA is migrated twice");
+  });
+  it("User PDA with the current layout can not be migrated", async () => {
+    let failed = false;
+    try {
+      const tx = await createMigrateUserDataTx(
+        user.publicKey,
+        user.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "User PDA is migrated twice");
+  });
+  it("User PDA is initialized with the current layout version", async () => {
+    const userInfo = await getUserPoolState(
+      user.publicKey,
+      program as unknown as anchor.Program
+    );
+    assert(userInfo.version.toNumber() == 1, "User PDA version is not set");
+    assert(
+      userInfo.legacyBalance.toNumber() == 0,
+      "User PDA has a migrated escrow balance"
+    );
+  });
+  it("Admin can not deduct the escrow balance not carried over by the migration", async () => {
+    let failed = false;
+    try {
+      const tx = await createReconcileEscrowBalanceTx(
+        superOwner.publicKey,
+        user.publicKey,
+        1,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [
+        superOwner,
+      ]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Escrow balance of the current user is deducted");
+  });
+  it("Sell Data PDA with the current layout can not be migrated", async () => {
+    let failed = false;
+    try {
+      const tx = await createMigrateSellDataTx(
+        user.publicKey,
+        nft.publicKey,
+        program as unknown as anchor.Program
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Sell Data PDA is migrated twice");
+  });
+  it("Auction Data PDA with the current layout can not be migrated", async () => {
+    let failed = false;
+    try {
+      const tx = await createMigrateAuctionDataTx(
+        user.publicKey,
+        nft.publicKey,
+        program as unknown as anchor.Program,
+        provider.connection
+      );
+      const txId = await provider.connection.sendTransaction(tx, [user]);
+      await provider.connection.confirmTransaction(txId, "confirmed");
+    } catch {
+      failed = true;
+    }
+    assert(failed, "Auction Data PDA is migrated twice");
+  });
+});
 /*
 describe('Deposit / Withdraw Escrow Balance', async () => {
   it('User1 can deposit sol', async () => {    